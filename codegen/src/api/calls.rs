@@ -25,6 +25,9 @@ use scale_info::form::PortableForm;
 /// Generate calls from the provided pallet's metadata. Each call returns a `StaticTxPayload`
 /// that can be passed to the subxt client to submit/sign/encode.
 ///
+/// The call structures also implement the `subxt::blocks::StaticExtrinsic` trait, so that
+/// extrinsics found in a block can be decoded into them.
+///
 /// # Arguments
 ///
 /// - `metadata` - Runtime metadata from which the calls are generated.
@@ -97,6 +100,11 @@ pub fn generate_calls(
             // The call structure's documentation was stripped above.
             let call_struct = quote! {
                 #struct_def
+
+                impl ::subxt::blocks::StaticExtrinsic for #struct_name {
+                    const PALLET: &'static str = #pallet_name;
+                    const CALL: &'static str = #call_name;
+                }
             };
             let client_fn = quote! {
                 #docs
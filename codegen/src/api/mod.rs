@@ -127,6 +127,16 @@ impl RuntimeGenerator {
                 "sp_runtime::multiaddress::MultiAddress",
                 parse_quote!(::subxt::ext::sp_runtime::MultiAddress),
             ),
+            // Ethereum-compatible chains (such as Moonbeam) use these account
+            // and signature types.
+            (
+                "account::AccountId20",
+                parse_quote!(::subxt::utils::AccountId20),
+            ),
+            (
+                "account::EthereumSignature",
+                parse_quote!(::subxt::utils::EthereumSignature),
+            ),
             (
                 "frame_support::traits::misc::WrapperKeepOpaque",
                 parse_quote!(::subxt::utils::WrapperKeepOpaque),
@@ -69,8 +69,10 @@ fn generate_storage_entry_fns(
     pallet: &PalletMetadata<PortableForm>,
     storage_entry: &StorageEntryMetadata<PortableForm>,
 ) -> TokenStream2 {
-    let (fields, key_impl) = match storage_entry.ty {
-        StorageEntryType::Plain(_) => (vec![], quote!(vec![])),
+    // The keys of an N-map can be given one at a time, so we also note each of them down in
+    // order to generate addresses to iterate over the entries which share some leading keys.
+    let (fields, key_impl, partial_keys) = match storage_entry.ty {
+        StorageEntryType::Plain(_) => (vec![], quote!(vec![]), vec![]),
         StorageEntryType::Map {
             ref key,
             ref hashers,
@@ -106,7 +108,7 @@ fn generate_storage_entry_fns(
                         })
                         .collect::<Vec<_>>();
 
-                    let key_impl = if hashers.len() == fields.len() {
+                    let (key_impl, partial_keys) = if hashers.len() == fields.len() {
                         // If the number of hashers matches the number of fields, we're dealing with
                         // something shaped like a StorageNMap, and each field should be hashed separately
                         // according to the corresponding hasher.
@@ -115,10 +117,12 @@ fn generate_storage_entry_fns(
                             .zip(&fields)
                             .map(|(hasher, (field_name, _))| {
                                 quote!( ::subxt::storage::address::StorageMapKey::new(#field_name.borrow(), #hasher) )
-                            });
-                        quote! {
+                            })
+                            .collect::<Vec<_>>();
+                        let key_impl = quote! {
                             vec![ #( #keys ),* ]
-                        }
+                        };
+                        (key_impl, keys)
                     } else if hashers.len() == 1 {
                         // If there is one hasher, then however many fields we have, we want to hash a
                         // tuple of them using the one hasher we're told about. This corresponds to a
@@ -126,9 +130,10 @@ fn generate_storage_entry_fns(
                         let hasher = hashers.get(0).expect("checked for 1 hasher");
                         let items =
                             fields.iter().map(|(field_name, _)| quote!( #field_name ));
-                        quote! {
+                        let key_impl = quote! {
                             vec![ ::subxt::storage::address::StorageMapKey::new(&(#( #items.borrow() ),*), #hasher) ]
-                        }
+                        };
+                        (key_impl, vec![])
                     } else {
                         // If we hit this condition, we don't know how to handle the number of hashes vs fields
                         // that we've been handed, so abort.
@@ -139,7 +144,7 @@ fn generate_storage_entry_fns(
                         )
                     };
 
-                    (fields, key_impl)
+                    (fields, key_impl, partial_keys)
                 }
                 _ => {
                     let ty_path = type_gen.resolve_type_path(key.id(), &[]);
@@ -150,7 +155,7 @@ fn generate_storage_entry_fns(
                     let key_impl = quote! {
                         vec![ ::subxt::storage::address::StorageMapKey::new(_0.borrow(), #hasher) ]
                     };
-                    (fields, key_impl)
+                    (fields, key_impl, vec![])
                 }
             }
         }
@@ -178,18 +183,25 @@ fn generate_storage_entry_fns(
     let docs = &storage_entry.docs;
     let docs_token = quote! { #( #[doc = #docs ] )* };
 
-    let key_args = fields.iter().map(|(field_name, field_type)| {
-        // The field type is translated from `std::vec::Vec<T>` to `[T]`. We apply
-        // AsRef to all types, so this just makes it a little more ergonomic.
-        //
-        // TODO [jsdw]: Support mappings like `String -> str` too for better borrow
-        // ergonomics.
-        let field_ty = match field_type.vec_type_param() {
-            Some(ty) => quote!([#ty]),
-            _ => quote!(#field_type),
-        };
-        quote!( #field_name: impl ::std::borrow::Borrow<#field_ty> )
-    });
+    let key_args = fields
+        .iter()
+        .map(|(field_name, field_type)| {
+            // The field type is translated from `std::vec::Vec<T>` to `[T]`. We apply
+            // AsRef to all types, so this just makes it a little more ergonomic.
+            //
+            // TODO [jsdw]: Support mappings like `String -> str` too for better borrow
+            // ergonomics.
+            let field_ty = match field_type.vec_type_param() {
+                Some(ty) => quote!([#ty]),
+                _ => quote!(#field_type),
+            };
+            quote!( #field_name: impl ::std::borrow::Borrow<#field_ty> )
+        })
+        .collect::<Vec<_>>();
+
+    // The types that the keys of the entry can be decoded back into.
+    let key_types = fields.iter().map(|(_, field_type)| field_type);
+    let keys_type = quote!( ( #( #key_types, )* ) );
 
     let is_map_type = matches!(storage_entry.ty, StorageEntryType::Map { .. });
 
@@ -220,7 +232,7 @@ fn generate_storage_entry_fns(
             #docs_token
             pub fn #fn_name_root(
                 &self,
-            ) -> ::subxt::storage::address::StaticStorageAddress::<::subxt::metadata::DecodeStaticType<#storage_entry_value_ty>, (), #is_defaultable_type, #is_iterable_type> {
+            ) -> ::subxt::storage::address::StaticStorageAddress::<::subxt::metadata::DecodeStaticType<#storage_entry_value_ty>, (), #is_defaultable_type, #is_iterable_type, #keys_type> {
                 ::subxt::storage::address::StaticStorageAddress::new(
                     #pallet_name,
                     #storage_name,
@@ -233,13 +245,35 @@ fn generate_storage_entry_fns(
         quote!()
     };
 
+    // If the item is an N-map, expose functions to create entries which fix the first
+    // few keys, too, so that the entries sharing those keys can be iterated over:
+    let partial_entry_fns = (1..partial_keys.len()).map(|num_keys| {
+        let fn_name_iter = format_ident!("{}_iter{}", fn_name, num_keys);
+        let key_args = &key_args[..num_keys];
+        let keys = &partial_keys[..num_keys];
+        quote! (
+            #docs_token
+            pub fn #fn_name_iter(
+                &self,
+                #( #key_args, )*
+            ) -> ::subxt::storage::address::StaticStorageAddress::<::subxt::metadata::DecodeStaticType<#storage_entry_value_ty>, (), #is_defaultable_type, #is_iterable_type, #keys_type> {
+                ::subxt::storage::address::StaticStorageAddress::new(
+                    #pallet_name,
+                    #storage_name,
+                    vec![ #( #keys ),* ],
+                    [#(#storage_hash,)*]
+                )
+            }
+        )
+    });
+
     quote! {
         // Access a specific value from a storage entry
         #docs_token
         pub fn #fn_name(
             &self,
             #( #key_args, )*
-        ) -> ::subxt::storage::address::StaticStorageAddress::<::subxt::metadata::DecodeStaticType<#storage_entry_value_ty>, ::subxt::storage::address::Yes, #is_defaultable_type, #is_iterable_type> {
+        ) -> ::subxt::storage::address::StaticStorageAddress::<::subxt::metadata::DecodeStaticType<#storage_entry_value_ty>, ::subxt::storage::address::Yes, #is_defaultable_type, #is_iterable_type, #keys_type> {
             ::subxt::storage::address::StaticStorageAddress::new(
                 #pallet_name,
                 #storage_name,
@@ -249,5 +283,7 @@ fn generate_storage_entry_fns(
         }
 
         #root_entry_fn
+
+        #( #partial_entry_fns )*
     }
 }
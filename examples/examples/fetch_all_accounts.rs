@@ -26,7 +26,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let address = polkadot::storage().system().account_root();
 
-    let mut iter = api.storage().iter(address, 10, None).await?;
+    // Fetch the values for up to 4 pages of accounts at once. They're still handed
+    // back in the order of their keys.
+    let mut iter = api
+        .storage()
+        .iter(address, 100, None)
+        .await?
+        .concurrency(4);
 
     while let Some((key, account)) = iter.next().await? {
         println!("{}: {}", hex::encode(key), account.data.free);
@@ -0,0 +1,69 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! To run this example, a local polkadot node should be running. Example verified against polkadot polkadot 0.9.25-5174e9ae75b.
+//!
+//! E.g.
+//! ```bash
+//! curl "https://github.com/paritytech/polkadot/releases/download/v0.9.25/polkadot" --output /usr/local/bin/polkadot --location
+//! polkadot --dev --tmp
+//! ```
+
+use subxt::{
+    OnlineClient,
+    PolkadotConfig,
+};
+
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata.scale")]
+pub mod polkadot {}
+
+/// Fetch the latest block, and decode the extrinsics and associated events within it.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt::init();
+
+    // Create a client to use:
+    let api = OnlineClient::<PolkadotConfig>::new().await?;
+
+    // Fetch the latest block:
+    let block = api.blocks().at(None).await?;
+    println!("Block #{} ({:?}):", block.number(), block.hash());
+
+    // Dynamically decode each of the extrinsics in it:
+    let extrinsics = block.extrinsics()?;
+    for ext in extrinsics.iter() {
+        let ext = ext?;
+        println!(
+            "  {}::{} (signed: {})",
+            ext.pallet_name(),
+            ext.variant_name(),
+            ext.is_signed()
+        );
+        if let Some(address) = ext.address()? {
+            println!("    Signer: {address:?}");
+        }
+        for (name, value) in ext.signed_extensions()?.unwrap_or_default() {
+            println!("    {name}: {value}");
+        }
+        println!("    Args: {}", ext.field_values()?);
+
+        // The events emitted by each extrinsic tell us whether it succeeded:
+        let events = ext.events().await?;
+        match events.dispatch_error()? {
+            Some(err) => println!("    Failed: {err}"),
+            None => println!("    Succeeded: {}", events.is_success()?),
+        }
+        for ev in events.iter() {
+            let ev = ev?;
+            println!("    Event: {}::{}", ev.pallet_name(), ev.variant_name());
+        }
+    }
+
+    // Or look for the extrinsics that we're interested in, decoding them statically:
+    if let Some(timestamp) = extrinsics.find_first::<polkadot::timestamp::calls::Set>()? {
+        println!("Timestamp set to {}", timestamp.now);
+    }
+
+    Ok(())
+}
@@ -103,5 +103,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // Example 4. Iterate over double maps given the first key, as in Example 3, but using
+    // the storage client. The generated `_iter1` function takes the first key.
+    {
+        let key_addr = polkadot::storage().xcm_pallet().version_notifiers_iter1(2);
+
+        let mut iter = api.storage().iter(key_addr, 10, None).await?;
+
+        println!("\nExample 4. Obtained keys:");
+        while let Some((key, value)) = iter.next().await? {
+            println!("Key: 0x{}", hex::encode(&key));
+            println!("  Value: {}", value);
+        }
+    }
+
     Ok(())
 }
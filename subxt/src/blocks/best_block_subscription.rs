@@ -0,0 +1,470 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Following the best block, taking note of any re-orgs along the way.
+
+use crate::{
+    client::OnlineClientT,
+    error::{
+        BlockError,
+        Error,
+    },
+    Config,
+};
+use futures::{
+    stream::{
+        self,
+        BoxStream,
+    },
+    Stream,
+    StreamExt,
+};
+use sp_runtime::traits::Header;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    pin::Pin,
+    task::Poll,
+};
+
+/// A notification handed back from a [`BestBlockSubscription`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BestBlockEvent<H> {
+    /// This block is now a part of the best chain. Blocks are handed back
+    /// in order, and every block will have been preceded by its parent.
+    NewBlock(H),
+    /// This block, which was previously handed back in [`BestBlockEvent::NewBlock`],
+    /// is no longer a part of the best chain. When a re-org happens, the retracted
+    /// blocks are handed back newest first, ahead of the blocks in the new best chain.
+    Retracted(H),
+    /// This block, which was previously handed back in [`BestBlockEvent::NewBlock`],
+    /// has been finalized and so will never be retracted.
+    Finalized(H),
+}
+
+/// A subscription which follows the best chain, obtained via
+/// [`crate::blocks::BlocksClient::subscribe_best()`]. This implements [`Stream`].
+///
+/// Unlike following `chain_subscribeNewHeads` directly, every block that is handed
+/// back is accounted for; it will later be either retracted or finalized.
+pub struct BestBlockSubscription<H> {
+    inner: BoxStream<'static, Result<BestBlockEvent<H>, Error>>,
+}
+
+impl<H> std::fmt::Debug for BestBlockSubscription<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BestBlockSubscription").finish()
+    }
+}
+
+impl<H> Stream for BestBlockSubscription<H> {
+    type Item = Result<BestBlockEvent<H>, Error>;
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+// The headers arriving from our two underlying subscriptions.
+enum Input<H> {
+    Best(H),
+    Finalized(H),
+}
+
+pub(crate) async fn subscribe_best<T, Client>(
+    client: Client,
+) -> Result<BestBlockSubscription<T::Header>, Error>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    // Subscribe first, so that we don't miss anything between
+    // fetching the finalized block and the subscriptions starting.
+    let best = client.rpc().subscribe_blocks().await?;
+    let finalized = client.rpc().subscribe_finalized_blocks().await?;
+
+    let finalized_hash = client.rpc().finalized_head().await?;
+    let finalized_header = fetch_header(&client, finalized_hash).await?;
+
+    let inputs = stream::select(
+        best.map(|res| res.map(Input::Best)),
+        finalized.map(|res| res.map(Input::Finalized)),
+    );
+
+    let state = (
+        inputs,
+        ForkTracker::new(finalized_header),
+        client,
+        VecDeque::new(),
+    );
+    let inner =
+        stream::unfold(state, |(mut inputs, mut tracker, client, mut pending)| {
+            async move {
+                loop {
+                    // Hand back anything that we've already worked out first.
+                    if let Some(ev) = pending.pop_front() {
+                        return Some((Ok(ev), (inputs, tracker, client, pending)))
+                    }
+
+                    let res = match inputs.next().await? {
+                        Ok(input) => handle_input(&client, &mut tracker, input).await,
+                        Err(e) => Err(e),
+                    };
+                    match res {
+                        Ok(events) => pending.extend(events),
+                        Err(e) => {
+                            return Some((Err(e), (inputs, tracker, client, pending)))
+                        }
+                    }
+                }
+            }
+        });
+
+    Ok(BestBlockSubscription {
+        inner: inner.boxed(),
+    })
+}
+
+async fn handle_input<T, Client>(
+    client: &Client,
+    tracker: &mut ForkTracker<T::Header>,
+    input: Input<T::Header>,
+) -> Result<Vec<BestBlockEvent<T::Header>>, Error>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    let header = match &input {
+        Input::Best(header) | Input::Finalized(header) => header.clone(),
+    };
+
+    // Make sure that we know how this block links back to the blocks we've seen
+    // already, fetching any headers that we've not been told about.
+    tracker.insert(header.clone());
+    let mut current = header;
+    while tracker.is_missing_parent(&current) {
+        let parent = fetch_header(client, *current.parent_hash()).await?;
+        tracker.insert(parent.clone());
+        current = parent;
+    }
+
+    let events = match input {
+        Input::Best(header) => tracker.set_best(header.hash()),
+        Input::Finalized(header) => tracker.finalize(header.hash()),
+    };
+    Ok(events)
+}
+
+async fn fetch_header<T, Client>(
+    client: &Client,
+    hash: T::Hash,
+) -> Result<T::Header, Error>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    client
+        .backend()
+        .header_at(Some(hash))
+        .await?
+        .ok_or_else(|| BlockError::BlockHashNotFound(format!("{:?}", hash)).into())
+}
+
+/// Keep track of the best chain of blocks above the last finalized block,
+/// working out which blocks to report as new, retracted or finalized as the
+/// best and finalized blocks change.
+struct ForkTracker<H: Header> {
+    // Every block above the last finalized block that we know about, and the
+    // last finalized block itself.
+    headers: HashMap<H::Hash, H>,
+    finalized: H::Hash,
+    // The best chain of blocks, oldest first, not including the finalized block.
+    best: Vec<H::Hash>,
+}
+
+impl<H: Header> ForkTracker<H> {
+    fn new(finalized: H) -> Self {
+        let finalized_hash = finalized.hash();
+        let mut headers = HashMap::new();
+        headers.insert(finalized_hash, finalized);
+        ForkTracker {
+            headers,
+            finalized: finalized_hash,
+            best: Vec::new(),
+        }
+    }
+
+    fn finalized_number(&self) -> H::Number {
+        *self.headers[&self.finalized].number()
+    }
+
+    fn contains(&self, hash: &H::Hash) -> bool {
+        self.headers.contains_key(hash)
+    }
+
+    // Is this block above the finalized block, but with a parent that we don't know about?
+    fn is_missing_parent(&self, header: &H) -> bool {
+        *header.number() > self.finalized_number() && !self.contains(header.parent_hash())
+    }
+
+    fn insert(&mut self, header: H) {
+        if *header.number() > self.finalized_number() {
+            self.headers.insert(header.hash(), header);
+        }
+    }
+
+    // Make the given block the new best block, returning the blocks that were
+    // retracted from and added to the best chain as a result.
+    fn set_best(&mut self, hash: H::Hash) -> Vec<BestBlockEvent<H>> {
+        // Walk back from the new best block until we find a block on the current
+        // best chain (or the finalized block), which is the common ancestor.
+        let mut route = Vec::new();
+        let mut current = hash;
+        let ancestor_pos = loop {
+            if current == self.finalized {
+                break 0
+            }
+            if let Some(pos) = self.best.iter().position(|h| *h == current) {
+                break pos + 1
+            }
+            match self.headers.get(&current) {
+                Some(header) => {
+                    route.push(current);
+                    current = *header.parent_hash();
+                }
+                // This block doesn't descend from the finalized block, so it
+                // can never be a part of the best chain; ignore it.
+                None => return Vec::new(),
+            }
+        };
+
+        let retracted = self.best.split_off(ancestor_pos);
+        let mut events: Vec<_> = retracted
+            .into_iter()
+            .rev()
+            .map(|h| BestBlockEvent::Retracted(self.headers[&h].clone()))
+            .collect();
+
+        for h in route.into_iter().rev() {
+            events.push(BestBlockEvent::NewBlock(self.headers[&h].clone()));
+            self.best.push(h);
+        }
+        events
+    }
+
+    // Finalize the given block, returning the events that this leads to.
+    fn finalize(&mut self, hash: H::Hash) -> Vec<BestBlockEvent<H>> {
+        // Nothing to do if the block is already finalized, or if we
+        // don't know how it links back to the last finalized block.
+        if hash == self.finalized || !self.contains(&hash) {
+            return Vec::new()
+        }
+
+        // A block which isn't on the best chain must become a part of it first.
+        let mut events = if self.best.contains(&hash) {
+            Vec::new()
+        } else {
+            self.set_best(hash)
+        };
+
+        let pos = match self.best.iter().position(|h| *h == hash) {
+            Some(pos) => pos,
+            None => return events,
+        };
+        for h in self.best.drain(..=pos) {
+            events.push(BestBlockEvent::Finalized(self.headers[&h].clone()));
+        }
+
+        // Forget about anything that can no longer be a part of the best chain,
+        // which is anything that doesn't descend from the finalized block.
+        self.finalized = hash;
+        let mut by_number: Vec<_> = self.headers.values().collect();
+        by_number.sort_by_key(|header| *header.number());
+        let mut descendants = HashSet::new();
+        descendants.insert(hash);
+        for header in by_number {
+            if descendants.contains(header.parent_hash()) {
+                descendants.insert(header.hash());
+            }
+        }
+        self.headers.retain(|h, _| descendants.contains(h));
+        events
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        rpc::MockNode,
+        Config,
+        OnlineClient,
+        PolkadotConfig,
+        SubstrateConfig,
+    };
+    use sp_core::H256;
+    use std::sync::Arc;
+
+    type TestHeader = <SubstrateConfig as Config>::Header;
+
+    // Build a header; the `fork` byte distinguishes blocks at the same height.
+    fn header(number: u32, parent: &TestHeader, fork: u8) -> TestHeader {
+        TestHeader::new(
+            number,
+            Default::default(),
+            H256::repeat_byte(fork),
+            parent.hash(),
+            Default::default(),
+        )
+    }
+
+    fn genesis() -> TestHeader {
+        TestHeader::new(
+            0,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    fn add_best(
+        tracker: &mut ForkTracker<TestHeader>,
+        header: &TestHeader,
+    ) -> Vec<BestBlockEvent<TestHeader>> {
+        tracker.insert(header.clone());
+        tracker.set_best(header.hash())
+    }
+
+    #[test]
+    fn new_blocks_extend_the_best_chain() {
+        let genesis = genesis();
+        let a1 = header(1, &genesis, 0);
+        let a2 = header(2, &a1, 0);
+        let mut tracker = ForkTracker::new(genesis);
+
+        assert_eq!(
+            add_best(&mut tracker, &a1),
+            vec![BestBlockEvent::NewBlock(a1.clone())]
+        );
+        assert_eq!(
+            add_best(&mut tracker, &a2),
+            vec![BestBlockEvent::NewBlock(a2.clone())]
+        );
+        // Seeing the same best block again changes nothing.
+        assert_eq!(add_best(&mut tracker, &a2), vec![]);
+    }
+
+    #[test]
+    fn reorgs_retract_blocks() {
+        let genesis = genesis();
+        let a1 = header(1, &genesis, 0);
+        let a2 = header(2, &a1, 0);
+        let b2 = header(2, &a1, 1);
+        let b3 = header(3, &b2, 1);
+        let mut tracker = ForkTracker::new(genesis);
+
+        add_best(&mut tracker, &a1);
+        add_best(&mut tracker, &a2);
+        tracker.insert(b2.clone());
+
+        assert_eq!(
+            add_best(&mut tracker, &b3),
+            vec![
+                BestBlockEvent::Retracted(a2),
+                BestBlockEvent::NewBlock(b2),
+                BestBlockEvent::NewBlock(b3),
+            ]
+        );
+    }
+
+    #[test]
+    fn finalizing_a_fork_reorgs_to_it() {
+        let genesis = genesis();
+        let a1 = header(1, &genesis, 0);
+        let a2 = header(2, &a1, 0);
+        let b1 = header(1, &genesis, 1);
+        let mut tracker = ForkTracker::new(genesis);
+
+        add_best(&mut tracker, &a1);
+        add_best(&mut tracker, &a2);
+        tracker.insert(b1.clone());
+
+        assert_eq!(
+            tracker.finalize(b1.hash()),
+            vec![
+                BestBlockEvent::Retracted(a2.clone()),
+                BestBlockEvent::Retracted(a1),
+                BestBlockEvent::NewBlock(b1.clone()),
+                BestBlockEvent::Finalized(b1),
+            ]
+        );
+
+        // Blocks on the old fork are forgotten about and won't become best.
+        assert!(!tracker.contains(&a2.hash()));
+        assert_eq!(tracker.set_best(a2.hash()), vec![]);
+    }
+
+    #[test]
+    fn finalizing_blocks_reports_each_of_them() {
+        let genesis = genesis();
+        let a1 = header(1, &genesis, 0);
+        let a2 = header(2, &a1, 0);
+        let a3 = header(3, &a2, 0);
+        let mut tracker = ForkTracker::new(genesis);
+
+        add_best(&mut tracker, &a1);
+        add_best(&mut tracker, &a2);
+        add_best(&mut tracker, &a3);
+
+        assert_eq!(
+            tracker.finalize(a2.hash()),
+            vec![
+                BestBlockEvent::Finalized(a1),
+                BestBlockEvent::Finalized(a2.clone())
+            ]
+        );
+        // Finalizing the same block again changes nothing.
+        assert_eq!(tracker.finalize(a2.hash()), vec![]);
+        assert_eq!(tracker.best, vec![a3.hash()]);
+    }
+
+    #[tokio::test]
+    async fn follows_blocks_from_a_node() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        let mut sub = api.blocks().subscribe_best().await.unwrap();
+        let first = node.produce_block();
+        let second = node.produce_block();
+
+        // The mock node finalizes every block immediately.
+        let mut events = vec![];
+        for _ in 0..4 {
+            let ev = sub.next().await.unwrap().unwrap();
+            events.push(match ev {
+                BestBlockEvent::NewBlock(h) => ("new", h.hash()),
+                BestBlockEvent::Retracted(h) => ("retracted", h.hash()),
+                BestBlockEvent::Finalized(h) => ("finalized", h.hash()),
+            });
+        }
+        assert_eq!(
+            events,
+            vec![
+                ("new", first),
+                ("finalized", first),
+                ("new", second),
+                ("finalized", second)
+            ]
+        );
+    }
+}
@@ -0,0 +1,929 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! A representation of a block and the extrinsics within it.
+
+use super::StaticExtrinsic;
+use crate::{
+    client::{
+        OfflineClientT,
+        OnlineClientT,
+    },
+    dynamic::DecodedValue,
+    error::{
+        BlockError,
+        DispatchError,
+        Error,
+    },
+    events::{
+        EventDetails,
+        Events,
+        EventsClient,
+        Phase,
+        StaticEvent,
+    },
+    metadata::CallMetadata,
+    Config,
+    Metadata,
+};
+use codec::{
+    Compact,
+    Decode,
+    Error as CodecError,
+};
+use derivative::Derivative;
+use futures::lock::Mutex as AsyncMutex;
+use sp_runtime::traits::{
+    Hash,
+    Header,
+};
+use std::sync::Arc;
+
+// The events for a block are fetched at most once, and then shared between
+// the block and each of the extrinsics decoded from it.
+type CachedEvents<T> = Arc<AsyncMutex<Option<Events<T>>>>;
+
+/// A block, obtained via [`crate::blocks::BlocksClient::at()`].
+#[derive(Derivative)]
+#[derivative(Debug(bound = "Client: std::fmt::Debug"))]
+pub struct Block<T: Config, Client> {
+    header: T::Header,
+    extrinsics: Arc<[Arc<[u8]>]>,
+    client: Client,
+    cached_events: CachedEvents<T>,
+}
+
+impl<T, Client> Block<T, Client>
+where
+    T: Config,
+    Client: OfflineClientT<T>,
+{
+    pub(crate) fn new(
+        header: T::Header,
+        extrinsics: Vec<Arc<[u8]>>,
+        client: Client,
+    ) -> Self {
+        Block {
+            header,
+            extrinsics: extrinsics.into(),
+            client,
+            cached_events: Default::default(),
+        }
+    }
+
+    /// Return the block hash.
+    pub fn hash(&self) -> T::Hash {
+        self.header.hash()
+    }
+
+    /// Return the block number.
+    pub fn number(&self) -> T::BlockNumber {
+        *self.header.number()
+    }
+
+    /// Return the block header.
+    pub fn header(&self) -> &T::Header {
+        &self.header
+    }
+
+    /// Return the extrinsics in this block, ready to be decoded using the
+    /// metadata of the client that fetched the block.
+    pub fn extrinsics(&self) -> Result<Extrinsics<T, Client>, Error> {
+        Extrinsics::new(
+            self.client.clone(),
+            self.hash(),
+            self.extrinsics.clone(),
+            self.cached_events.clone(),
+        )
+    }
+}
+
+impl<T, Client> Block<T, Client>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    /// Fetch all of the events emitted in this block. These are fetched at most once,
+    /// and shared with the extrinsics obtained from [`Block::extrinsics()`].
+    pub async fn events(&self) -> Result<Events<T>, Error> {
+        get_events(&self.client, self.hash(), &self.cached_events).await
+    }
+}
+
+/// The extrinsics obtained from a block, bundled with the necessary
+/// information needed to decode and iterate over them.
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "Client: std::fmt::Debug"),
+    Clone(bound = "Client: Clone")
+)]
+pub struct Extrinsics<T: Config, Client> {
+    client: Client,
+    metadata: Metadata,
+    block_hash: T::Hash,
+    // Each extrinsic is stored as its SCALE encoded bytes, which
+    // includes the Compact<u32> length on the front.
+    extrinsics: Arc<[Arc<[u8]>]>,
+    type_ids: ExtrinsicTypeIds,
+    cached_events: CachedEvents<T>,
+}
+
+impl<T, Client> Extrinsics<T, Client>
+where
+    T: Config,
+    Client: OfflineClientT<T>,
+{
+    fn new(
+        client: Client,
+        block_hash: T::Hash,
+        extrinsics: Arc<[Arc<[u8]>]>,
+        cached_events: CachedEvents<T>,
+    ) -> Result<Self, Error> {
+        let metadata = client.metadata();
+        let type_ids = ExtrinsicTypeIds::new(&metadata)?;
+        Ok(Self {
+            client,
+            metadata,
+            block_hash,
+            extrinsics,
+            type_ids,
+            cached_events,
+        })
+    }
+
+    /// The number of extrinsics.
+    pub fn len(&self) -> usize {
+        self.extrinsics.len()
+    }
+
+    /// Are there no extrinsics in this block?
+    // Note: mainly here to satisfy clippy.
+    pub fn is_empty(&self) -> bool {
+        self.extrinsics.is_empty()
+    }
+
+    /// Return the block hash that these extrinsics are from.
+    pub fn block_hash(&self) -> T::Hash {
+        self.block_hash
+    }
+
+    /// Iterate over all of the extrinsics, using metadata to dynamically
+    /// decode them as we go, and returning the raw bytes and other associated
+    /// details. Each extrinsic is decoded independently of the others, so an
+    /// error decoding one does not prevent the rest from being decoded.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = Result<ExtrinsicDetails<T, Client>, Error>> + Send + Sync + 'static
+    {
+        let this = self.clone();
+        (0..self.extrinsics.len()).map(move |index| {
+            ExtrinsicDetails::decode_from(
+                this.extrinsics[index].clone(),
+                index as u32,
+                &this,
+            )
+        })
+    }
+
+    /// Iterate through the extrinsics using metadata to dynamically decode them,
+    /// and return only those which should decode to the provided `E` type.
+    pub fn find<E: StaticExtrinsic>(
+        &self,
+    ) -> impl Iterator<Item = Result<E, Error>> + '_ {
+        self.iter().filter_map(|ext| {
+            ext.and_then(|ext| ext.as_extrinsic::<E>().map_err(Into::into))
+                .transpose()
+        })
+    }
+
+    /// Iterate through the extrinsics using metadata to dynamically decode them,
+    /// and return the first extrinsic found which decodes to the provided `E` type.
+    pub fn find_first<E: StaticExtrinsic>(&self) -> Result<Option<E>, Error> {
+        self.find::<E>().next().transpose()
+    }
+
+    /// Find an extrinsic that decodes to the type provided. Returns true if it was found.
+    pub fn has<E: StaticExtrinsic>(&self) -> Result<bool, Error> {
+        Ok(self.find::<E>().next().transpose()?.is_some())
+    }
+}
+
+/// The type IDs, from the metadata, of the parts of an extrinsic that
+/// we need to know about in order to decode it.
+#[derive(Clone, Debug)]
+pub(crate) struct ExtrinsicTypeIds {
+    address: u32,
+    signature: u32,
+    // The name and type of each signed extension, in the order they are encoded.
+    signed_extensions: Arc<[(String, u32)]>,
+}
+
+impl ExtrinsicTypeIds {
+    pub(crate) fn new(metadata: &Metadata) -> Result<Self, Error> {
+        let extrinsic = &metadata.runtime_metadata().extrinsic;
+        let extrinsic_ty_id = extrinsic.ty.id();
+        let extrinsic_ty = metadata.resolve_type(extrinsic_ty_id).ok_or(
+            crate::metadata::MetadataError::TypeNotFound(extrinsic_ty_id),
+        )?;
+
+        // The address and signature types are given as type parameters
+        // to the `UncheckedExtrinsic` type.
+        let type_param = |name: &'static str| {
+            extrinsic_ty
+                .type_params()
+                .iter()
+                .find(|param| param.name() == name)
+                .and_then(|param| param.ty())
+                .map(|ty| ty.id())
+                .ok_or(BlockError::MissingTypeParameter(name))
+        };
+
+        Ok(ExtrinsicTypeIds {
+            address: type_param("Address")?,
+            signature: type_param("Signature")?,
+            signed_extensions: extrinsic
+                .signed_extensions
+                .iter()
+                .map(|ext| (ext.identifier.clone(), ext.ty.id()))
+                .collect(),
+        })
+    }
+}
+
+/// The extrinsic details.
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "Client: std::fmt::Debug"),
+    Clone(bound = "Client: Clone")
+)]
+pub struct ExtrinsicDetails<T: Config, Client> {
+    client: Client,
+    block_hash: T::Hash,
+    cached_events: CachedEvents<T>,
+    index: u32,
+    bytes: Arc<[u8]>,
+    // Details about the signature, if the extrinsic is signed.
+    signed: Option<SignedDetails>,
+    signed_extensions: Arc<[(String, u32)]>,
+    // start of the call (ie the pallet/variant index and then fields).
+    call_start_idx: usize,
+    metadata: Metadata,
+}
+
+// Offsets into the extrinsic bytes for each part of the signature.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SignedDetails {
+    pub(crate) address_start_idx: usize,
+    pub(crate) address_end_idx: usize,
+    pub(crate) signature_end_idx: usize,
+    pub(crate) extensions_end_idx: usize,
+}
+
+// Work out where each part of a SCALE encoded extrinsic (including its compact encoded
+// length) starts and ends, returning the signature details if it's signed, and the index
+// that the call data starts at. The index is only used to report errors.
+pub(crate) fn decode_extrinsic_parts(
+    bytes: &[u8],
+    index: u32,
+    metadata: &Metadata,
+    type_ids: &ExtrinsicTypeIds,
+) -> Result<(Option<SignedDetails>, usize), Error> {
+    const SIGNED_MASK: u8 = 0b1000_0000;
+    const VERSION_MASK: u8 = 0b0111_1111;
+    const SUPPORTED_VERSION: u8 = 4;
+
+    let types = &metadata.runtime_metadata().types;
+    let input = &mut &bytes[..];
+    let offset = |input: &[u8]| bytes.len() - input.len();
+
+    // The length prefix should account for every byte which follows it. We also
+    // check that decoding everything else uses up exactly these bytes.
+    let len = <Compact<u32>>::decode(input)?.0 as usize;
+    if len != input.len() {
+        return Err(BlockError::WrongLength {
+            index,
+            expected: len,
+            actual: input.len(),
+        }
+        .into())
+    }
+
+    let version = u8::decode(input)?;
+    if version & VERSION_MASK != SUPPORTED_VERSION {
+        return Err(BlockError::UnsupportedVersion(version & VERSION_MASK).into())
+    }
+
+    let signed = if version & SIGNED_MASK != 0 {
+        let address_start_idx = offset(input);
+        scale_decode::decode(
+            input,
+            type_ids.address,
+            types,
+            scale_decode::visitor::IgnoreVisitor,
+        )?;
+        let address_end_idx = offset(input);
+        scale_decode::decode(
+            input,
+            type_ids.signature,
+            types,
+            scale_decode::visitor::IgnoreVisitor,
+        )?;
+        let signature_end_idx = offset(input);
+        for (_name, type_id) in type_ids.signed_extensions.iter() {
+            scale_decode::decode(
+                input,
+                *type_id,
+                types,
+                scale_decode::visitor::IgnoreVisitor,
+            )?;
+        }
+        let extensions_end_idx = offset(input);
+        Some(SignedDetails {
+            address_start_idx,
+            address_end_idx,
+            signature_end_idx,
+            extensions_end_idx,
+        })
+    } else {
+        None
+    };
+
+    let call_start_idx = offset(input);
+    let pallet_index = u8::decode(input)?;
+    let variant_index = u8::decode(input)?;
+
+    // Get metadata for the call:
+    let call_metadata = metadata.call(pallet_index, variant_index)?;
+    tracing::debug!(
+        "Decoding Extrinsic '{}::{}'",
+        call_metadata.pallet(),
+        call_metadata.call()
+    );
+
+    // Skip over the bytes belonging to the call arguments.
+    for (_name, type_id) in call_metadata.fields() {
+        scale_decode::decode(
+            input,
+            *type_id,
+            types,
+            scale_decode::visitor::IgnoreVisitor,
+        )?;
+    }
+
+    if !input.is_empty() {
+        return Err(BlockError::LeftoverBytes {
+            index,
+            leftover: input.len(),
+            len: bytes.len(),
+        }
+        .into())
+    }
+
+    Ok((signed, call_start_idx))
+}
+
+impl<T, Client> ExtrinsicDetails<T, Client>
+where
+    T: Config,
+    Client: OfflineClientT<T>,
+{
+    // Attempt to dynamically decode a single extrinsic from the given block extrinsics.
+    fn decode_from(
+        bytes: Arc<[u8]>,
+        index: u32,
+        extrinsics: &Extrinsics<T, Client>,
+    ) -> Result<ExtrinsicDetails<T, Client>, Error> {
+        let metadata = &extrinsics.metadata;
+        let type_ids = &extrinsics.type_ids;
+        let (signed, call_start_idx) =
+            decode_extrinsic_parts(&bytes, index, metadata, type_ids)?;
+
+        Ok(ExtrinsicDetails {
+            client: extrinsics.client.clone(),
+            block_hash: extrinsics.block_hash,
+            cached_events: extrinsics.cached_events.clone(),
+            index,
+            signed,
+            signed_extensions: type_ids.signed_extensions.clone(),
+            call_start_idx,
+            bytes,
+            metadata: metadata.clone(),
+        })
+    }
+
+    /// What index is this extrinsic in the block.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Return the hash of this extrinsic.
+    pub fn hash(&self) -> T::Hash {
+        T::Hashing::hash(&self.bytes)
+    }
+
+    /// Return _all_ of the SCALE encoded bytes representing this extrinsic, which
+    /// include, in order:
+    /// - The compact encoded length of the rest of the bytes.
+    /// - The version and signed flag.
+    /// - The signature details, if the extrinsic is signed.
+    /// - The pallet and call index.
+    /// - The call arguments.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Is this extrinsic signed?
+    pub fn is_signed(&self) -> bool {
+        self.signed.is_some()
+    }
+
+    /// Return the bytes representing the address of the signer, if the
+    /// extrinsic is signed.
+    pub fn address_bytes(&self) -> Option<&[u8]> {
+        self.signed
+            .map(|s| &self.bytes[s.address_start_idx..s.address_end_idx])
+    }
+
+    /// Decode and return the address of the signer, if the extrinsic is signed.
+    pub fn address(&self) -> Result<Option<T::Address>, CodecError> {
+        self.address_bytes()
+            .map(|mut bytes| T::Address::decode(&mut bytes))
+            .transpose()
+    }
+
+    /// Return the bytes representing the signature, if the extrinsic is signed.
+    pub fn signature_bytes(&self) -> Option<&[u8]> {
+        self.signed
+            .map(|s| &self.bytes[s.address_end_idx..s.signature_end_idx])
+    }
+
+    /// Return the bytes representing the signed extension values (sometimes known
+    /// as the "extra" parameters), if the extrinsic is signed.
+    pub fn signed_extensions_bytes(&self) -> Option<&[u8]> {
+        self.signed
+            .map(|s| &self.bytes[s.signature_end_idx..s.extensions_end_idx])
+    }
+
+    /// Decode and return the name and value of each signed extension, in the order
+    /// that they are given in the metadata, if the extrinsic is signed.
+    pub fn signed_extensions(&self) -> Result<Option<Vec<(&str, DecodedValue)>>, Error> {
+        let bytes = &mut match self.signed_extensions_bytes() {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+
+        let mut values = vec![];
+        for (name, type_id) in self.signed_extensions.iter() {
+            let value = scale_value::scale::decode_as_type(
+                bytes,
+                *type_id,
+                &self.metadata.runtime_metadata().types,
+            )?;
+            values.push((name.as_str(), value));
+        }
+        Ok(Some(values))
+    }
+
+    /// The index of the pallet that the call belongs to.
+    pub fn pallet_index(&self) -> u8 {
+        // Note: never panics; we expect these bytes to exist
+        // in order that the ExtrinsicDetails could be created.
+        self.bytes[self.call_start_idx]
+    }
+
+    /// The index of the call variant.
+    pub fn variant_index(&self) -> u8 {
+        // Note: never panics; we expect these bytes to exist
+        // in order that the ExtrinsicDetails could be created.
+        self.bytes[self.call_start_idx + 1]
+    }
+
+    /// The name of the pallet that the call belongs to.
+    pub fn pallet_name(&self) -> &str {
+        self.call_metadata().pallet()
+    }
+
+    /// The name of the call (ie the name of the variant that it corresponds to).
+    pub fn variant_name(&self) -> &str {
+        self.call_metadata().call()
+    }
+
+    /// Fetch the metadata for this extrinsic's call.
+    pub fn call_metadata(&self) -> &CallMetadata {
+        self.metadata
+            .call(self.pallet_index(), self.variant_index())
+            .expect("this must exist in order to have produced the ExtrinsicDetails")
+    }
+
+    /// Return the bytes representing the call, which include the pallet and
+    /// call index followed by the call arguments.
+    pub fn call_bytes(&self) -> &[u8] {
+        &self.bytes[self.call_start_idx..]
+    }
+
+    /// Return the bytes representing the arguments given to the call.
+    pub fn field_bytes(&self) -> &[u8] {
+        &self.bytes[self.call_start_idx + 2..]
+    }
+
+    /// Decode and provide the call arguments back in the form of a
+    /// [`scale_value::Composite`] type which represents the named or
+    /// unnamed fields that were present in the call.
+    pub fn field_values(
+        &self,
+    ) -> Result<scale_value::Composite<scale_value::scale::TypeId>, Error> {
+        let bytes = &mut self.field_bytes();
+        let call_metadata = self.call_metadata();
+
+        // If the first field has a name, we assume that the rest do too (it'll either
+        // be a named struct or a tuple type). If no fields, assume unnamed.
+        let is_named = call_metadata
+            .fields()
+            .first()
+            .map(|(n, _)| n.is_some())
+            .unwrap_or(false);
+
+        let mut values = vec![];
+        for (name, type_id) in call_metadata.fields() {
+            let value = scale_value::scale::decode_as_type(
+                bytes,
+                *type_id,
+                &self.metadata.runtime_metadata().types,
+            )?;
+            values.push((name.clone().unwrap_or_default(), value));
+        }
+
+        if is_named {
+            Ok(scale_value::Composite::Named(values))
+        } else {
+            Ok(scale_value::Composite::Unnamed(
+                values.into_iter().map(|(_, value)| value).collect(),
+            ))
+        }
+    }
+
+    /// Attempt to decode these [`ExtrinsicDetails`] into a specific static call.
+    /// This targets the call arguments directly. You can also attempt to decode the
+    /// entirety of the call type (including the pallet and call variants) using
+    /// [`ExtrinsicDetails::as_root_extrinsic()`].
+    pub fn as_extrinsic<E: StaticExtrinsic>(&self) -> Result<Option<E>, CodecError> {
+        let call_metadata = self.call_metadata();
+        if call_metadata.pallet() == E::PALLET && call_metadata.call() == E::CALL {
+            Ok(Some(E::decode(&mut self.field_bytes())?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Attempt to decode these [`ExtrinsicDetails`] into a root call type (which
+    /// includes the pallet and call enum variants as well as the call arguments).
+    /// A compatible type for this is exposed via static codegen as the runtime's
+    /// `Call` type.
+    pub fn as_root_extrinsic<E: Decode>(&self) -> Result<E, CodecError> {
+        E::decode(&mut self.call_bytes())
+    }
+}
+
+impl<T, Client> ExtrinsicDetails<T, Client>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    /// Fetch the events associated with this extrinsic (ie those emitted in the
+    /// [`Phase::ApplyExtrinsic`] phase with this extrinsic's index).
+    ///
+    /// **Note:** The events for the block are downloaded the first time that this
+    /// is called on any of its extrinsics, and then reused.
+    pub async fn events(&self) -> Result<ExtrinsicEvents<T>, Error> {
+        let events =
+            get_events(&self.client, self.block_hash, &self.cached_events).await?;
+        Ok(ExtrinsicEvents::new(self.hash(), self.index, events))
+    }
+}
+
+/// The events associated with a given extrinsic.
+/// We can iterate over the events, or look for a specific one.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""))]
+pub struct ExtrinsicEvents<T: Config> {
+    ext_hash: T::Hash,
+    ext_idx: u32,
+    events: Events<T>,
+}
+
+impl<T: Config> ExtrinsicEvents<T> {
+    pub(crate) fn new(ext_hash: T::Hash, ext_idx: u32, events: Events<T>) -> Self {
+        Self {
+            ext_hash,
+            ext_idx,
+            events,
+        }
+    }
+
+    /// Return the hash of the block that the extrinsic is in.
+    pub fn block_hash(&self) -> T::Hash {
+        self.events.block_hash()
+    }
+
+    /// Return the hash of the extrinsic.
+    pub fn extrinsic_hash(&self) -> T::Hash {
+        self.ext_hash
+    }
+
+    /// Return the index of the extrinsic in the block.
+    pub fn extrinsic_index(&self) -> u32 {
+        self.ext_idx
+    }
+
+    /// Return all of the events in the block that the extrinsic is in.
+    pub fn all_events_in_block(&self) -> &Events<T> {
+        &self.events
+    }
+
+    /// Iterate over all of the raw events associated with this extrinsic.
+    ///
+    /// This works in the same way that [`Events::iter()`] does, with the
+    /// exception that it filters out events not related to this extrinsic.
+    pub fn iter(&self) -> impl Iterator<Item = Result<EventDetails, Error>> + '_ {
+        self.events.iter().filter(|ev| {
+            ev.as_ref()
+                .map(|ev| ev.phase() == Phase::ApplyExtrinsic(self.ext_idx))
+                .unwrap_or(true) // Keep any errors.
+        })
+    }
+
+    /// Find all of the extrinsic events matching the event type provided as a generic parameter.
+    ///
+    /// This works in the same way that [`Events::find()`] does, with the
+    /// exception that it filters out events not related to this extrinsic.
+    pub fn find<Ev: StaticEvent>(&self) -> impl Iterator<Item = Result<Ev, Error>> + '_ {
+        self.iter().filter_map(|ev| {
+            ev.and_then(|ev| ev.as_event::<Ev>().map_err(Into::into))
+                .transpose()
+        })
+    }
+
+    /// Iterate through the extrinsic events using metadata to dynamically decode and skip
+    /// them, and return the first event found which decodes to the provided `Ev` type.
+    ///
+    /// This works in the same way that [`Events::find_first()`] does, with the
+    /// exception that it ignores events not related to this extrinsic.
+    pub fn find_first<Ev: StaticEvent>(&self) -> Result<Option<Ev>, Error> {
+        self.find::<Ev>().next().transpose()
+    }
+
+    /// Find an event in those associated with this extrinsic. Returns true if it was found.
+    ///
+    /// This works in the same way that [`Events::has()`] does, with the
+    /// exception that it ignores events not related to this extrinsic.
+    pub fn has<Ev: StaticEvent>(&self) -> Result<bool, Error> {
+        Ok(self.find::<Ev>().next().transpose()?.is_some())
+    }
+
+    /// Did the extrinsic succeed? This is true if a `System.ExtrinsicSuccess` event
+    /// was emitted for it.
+    pub fn is_success(&self) -> Result<bool, Error> {
+        for ev in self.iter() {
+            let ev = ev?;
+            if ev.pallet_name() == "System" && ev.variant_name() == "ExtrinsicSuccess" {
+                return Ok(true)
+            }
+        }
+        Ok(false)
+    }
+
+    /// If the extrinsic failed, return the [`DispatchError`] decoded from the
+    /// `System.ExtrinsicFailed` event that was emitted for it.
+    ///
+    /// **Note:** If multiple `ExtrinsicFailed` events were emitted (for instance
+    /// because a pallet chooses to emit one as an event, which is considered
+    /// abnormal behaviour), it is not specified which of the errors is returned here.
+    pub fn dispatch_error(&self) -> Result<Option<DispatchError>, Error> {
+        for ev in self.iter() {
+            let ev = ev?;
+            if ev.pallet_name() == "System" && ev.variant_name() == "ExtrinsicFailed" {
+                // The dispatch error is the first field of the event.
+                let dispatch_error =
+                    DispatchError::decode_from(ev.field_bytes(), self.events.metadata());
+                return Ok(Some(dispatch_error))
+            }
+        }
+        Ok(None)
+    }
+}
+
+// Return the events for a block, fetching them if they haven't been already.
+async fn get_events<T, Client>(
+    client: &Client,
+    block_hash: T::Hash,
+    cached_events: &AsyncMutex<Option<Events<T>>>,
+) -> Result<Events<T>, Error>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    let mut cached_events = cached_events.lock().await;
+    let events = match &*cached_events {
+        Some(events) => events.clone(),
+        None => {
+            let events = EventsClient::new(client.clone())
+                .at(Some(block_hash))
+                .await?;
+            cached_events.insert(events).clone()
+        }
+    };
+    Ok(events)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        dynamic::Value,
+        rpc::{
+            MockNode,
+            RuntimeVersion,
+        },
+        tx::PairSigner,
+        OfflineClient,
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use codec::Encode;
+    use sp_core::{
+        sr25519,
+        twox_128,
+        Pair,
+        H256,
+    };
+    use sp_runtime::MultiAddress;
+
+    type TestClient = OnlineClient<PolkadotConfig>;
+
+    #[derive(Decode, Debug, PartialEq)]
+    struct Remark {
+        remark: Vec<u8>,
+    }
+
+    impl StaticExtrinsic for Remark {
+        const PALLET: &'static str = "System";
+        const CALL: &'static str = "remark";
+    }
+
+    fn metadata_bytes() -> Vec<u8> {
+        include_bytes!("../../../artifacts/polkadot_metadata.scale").to_vec()
+    }
+
+    // Submit a remark for each of the given messages, and produce a block containing them.
+    async fn block_with_remarks(
+        messages: &[&[u8]],
+        events: Option<Vec<u8>>,
+    ) -> (sr25519::Pair, Block<PolkadotConfig, TestClient>) {
+        let node = MockNode::<PolkadotConfig>::new(metadata_bytes());
+        let api = TestClient::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        let alice = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let signer = PairSigner::new(alice.clone());
+        for message in messages {
+            let tx =
+                crate::dynamic::tx("System", "remark", vec![Value::from_bytes(message)]);
+            api.tx()
+                .sign_and_submit_default(&tx, &signer)
+                .await
+                .unwrap();
+        }
+        if let Some(events) = events {
+            let key = [twox_128(b"System"), twox_128(b"Events")].concat();
+            node.set_storage(key, events);
+        }
+        let block_hash = node.produce_block();
+
+        let block = api.blocks().at(Some(block_hash)).await.unwrap();
+        assert_eq!(block.hash(), block_hash);
+        (alice, block)
+    }
+
+    // Encode an event record, given the bytes for the event itself.
+    fn event_record(phase: Phase, event: &[u8]) -> Vec<u8> {
+        let mut bytes = phase.encode();
+        bytes.extend(event);
+        bytes.extend(Vec::<H256>::new().encode());
+        bytes
+    }
+
+    #[tokio::test]
+    async fn decodes_signed_extrinsics_in_a_block() {
+        let (alice, block) = block_with_remarks(&[b"hi"], None).await;
+        assert_eq!(block.number(), 1);
+
+        let extrinsics = block.extrinsics().unwrap();
+        assert_eq!(extrinsics.len(), 1);
+
+        let ext = extrinsics.iter().next().unwrap().unwrap();
+        assert!(ext.is_signed());
+        assert_eq!(ext.pallet_name(), "System");
+        assert_eq!(ext.variant_name(), "remark");
+        assert_eq!(
+            ext.address().unwrap(),
+            Some(MultiAddress::Id(alice.public().into()))
+        );
+
+        let extension_names: Vec<_> = ext
+            .signed_extensions()
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert!(extension_names.contains(&"CheckNonce"));
+
+        let fields = ext.field_values().unwrap();
+        assert_eq!(fields.into_values().count(), 1);
+
+        assert_eq!(
+            ext.as_extrinsic::<Remark>().unwrap(),
+            Some(Remark {
+                remark: b"hi".to_vec()
+            })
+        );
+        assert!(extrinsics.has::<Remark>().unwrap());
+    }
+
+    #[tokio::test]
+    async fn associates_events_with_extrinsics() {
+        // System.ExtrinsicSuccess, with a weight of 10, a "Normal" class and "Yes" to paying fees.
+        let success = [&[0u8, 0][..], &10u64.encode(), &[0, 0]].concat();
+        // System.ExtrinsicFailed, with the error Balances.InsufficientBalance, and the same info.
+        let failed =
+            [&[0u8, 1][..], &[3, 5, 2, 0, 0, 0], &10u64.encode(), &[0, 0]].concat();
+        let records = [
+            event_record(Phase::ApplyExtrinsic(0), &success),
+            event_record(Phase::ApplyExtrinsic(1), &failed),
+        ];
+        let events = [Compact(records.len() as u32).encode(), records.concat()].concat();
+
+        let (_, block) = block_with_remarks(&[b"first", b"second"], Some(events)).await;
+        assert_eq!(block.events().await.unwrap().len(), 2);
+
+        let extrinsics: Vec<_> = block
+            .extrinsics()
+            .unwrap()
+            .iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let first = extrinsics[0].events().await.unwrap();
+        assert_eq!(first.extrinsic_hash(), extrinsics[0].hash());
+        assert_eq!(first.iter().count(), 1);
+        assert!(first.is_success().unwrap());
+        assert!(first.dispatch_error().unwrap().is_none());
+
+        let second = extrinsics[1].events().await.unwrap();
+        assert_eq!(second.iter().count(), 1);
+        assert!(!second.is_success().unwrap());
+        match second.dispatch_error().unwrap() {
+            Some(DispatchError::Module(e)) => {
+                assert_eq!(e.pallet, "Balances");
+                assert_eq!(e.error, "InsufficientBalance");
+            }
+            other => panic!("expected a module error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_unsupported_versions() {
+        let metadata = Metadata::try_from(
+            <frame_metadata::RuntimeMetadataPrefixed>::decode(&mut &*metadata_bytes())
+                .unwrap(),
+        )
+        .unwrap();
+        let runtime_version = RuntimeVersion {
+            spec_version: 0,
+            transaction_version: 0,
+            other: Default::default(),
+        };
+        let client = OfflineClient::<PolkadotConfig>::new(
+            Default::default(),
+            runtime_version,
+            metadata,
+        );
+
+        // A compact length of 3, then version 3, then a call to System.remark.
+        let bytes: Arc<[u8]> = [3 << 2, 3, 0, 1].into();
+        let extrinsics = Extrinsics::new(
+            client,
+            Default::default(),
+            [bytes].into(),
+            Default::default(),
+        )
+        .unwrap();
+        let res = extrinsics.iter().next().unwrap();
+        assert!(matches!(
+            res,
+            Err(Error::Block(BlockError::UnsupportedVersion(3)))
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{
+    best_block_subscription::subscribe_best,
+    BestBlockSubscription,
+    Block,
+};
+use crate::{
+    client::OnlineClientT,
+    error::{
+        BlockError,
+        Error,
+    },
+    Config,
+};
+use derivative::Derivative;
+use futures::future;
+use std::future::Future;
+
+/// A client for working with blocks.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "Client: Clone"))]
+pub struct BlocksClient<T, Client> {
+    client: Client,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T, Client> BlocksClient<T, Client> {
+    /// Create a new [`BlocksClient`].
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, Client> BlocksClient<T, Client>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    /// Obtain the block at some block hash. If no block hash is provided,
+    /// the latest block is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use subxt::{ OnlineClient, PolkadotConfig };
+    ///
+    /// let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+    ///
+    /// let block = api.blocks().at(None).await.unwrap();
+    /// for ext in block.extrinsics().unwrap().iter() {
+    ///     let ext = ext.unwrap();
+    ///     println!("{}::{}", ext.pallet_name(), ext.variant_name());
+    /// }
+    /// # }
+    /// ```
+    pub fn at(
+        &self,
+        block_hash: Option<T::Hash>,
+    ) -> impl Future<Output = Result<Block<T, Client>, Error>> + Send + 'static {
+        // Clone and pass the client in like this so that we can explicitly
+        // return a Future that's Send + 'static, rather than tied to &self.
+        let client = self.client.clone();
+        async move { at(client, block_hash).await }
+    }
+
+    /// Follow the best chain, being told about each block that is added to it, and
+    /// when blocks are retracted from it (because of a re-org) or finalized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use futures::StreamExt;
+    /// use subxt::{
+    ///     blocks::BestBlockEvent,
+    ///     OnlineClient,
+    ///     PolkadotConfig,
+    /// };
+    ///
+    /// let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+    ///
+    /// let mut blocks = api.blocks().subscribe_best().await.unwrap();
+    /// while let Some(ev) = blocks.next().await {
+    ///     match ev.unwrap() {
+    ///         BestBlockEvent::NewBlock(header) => println!("New: #{}", header.number),
+    ///         BestBlockEvent::Retracted(header) => println!("Retracted: #{}", header.number),
+    ///         BestBlockEvent::Finalized(header) => println!("Finalized: #{}", header.number),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe_best(
+        &self,
+    ) -> impl Future<Output = Result<BestBlockSubscription<T::Header>, Error>> + Send + 'static
+    {
+        let client = self.client.clone();
+        async move { subscribe_best(client).await }
+    }
+}
+
+async fn at<T, Client>(
+    client: Client,
+    block_hash: Option<T::Hash>,
+) -> Result<Block<T, Client>, Error>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    // If block hash is not provided, get the hash
+    // for the latest block and use that.
+    let backend = client.backend();
+    let block_hash = match block_hash {
+        Some(hash) => hash,
+        None => backend.best_block_hash().await?,
+    };
+
+    let (header, body) = future::join(
+        backend.header_at(Some(block_hash)),
+        backend.block_body_at(block_hash),
+    )
+    .await;
+    let not_found = || BlockError::BlockHashNotFound(format!("{:?}", block_hash));
+    let header = header?.ok_or_else(not_found)?;
+    let body = body?.ok_or_else(not_found)?;
+
+    // Whatever the configured extrinsic type, its SCALE encoding is the
+    // compact length prefixed bytes that we know how to decode.
+    let extrinsics = body.into_iter().map(Into::into).collect();
+
+    Ok(Block::new(header, extrinsics, client))
+}
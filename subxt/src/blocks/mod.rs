@@ -0,0 +1,49 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! This module exposes the types and such necessary for working with blocks.
+//! The main entry point into blocks is [`crate::OnlineClient::blocks()`], which
+//! allows a block to be fetched, and its extrinsics decoded using the metadata.
+
+mod best_block_subscription;
+mod block_types;
+mod blocks_client;
+
+pub use best_block_subscription::{
+    BestBlockEvent,
+    BestBlockSubscription,
+};
+pub use block_types::{
+    Block,
+    ExtrinsicDetails,
+    ExtrinsicEvents,
+    Extrinsics,
+};
+pub use blocks_client::BlocksClient;
+
+pub(crate) use block_types::{
+    decode_extrinsic_parts,
+    ExtrinsicTypeIds,
+    SignedDetails,
+};
+
+use codec::Decode;
+
+/// Trait to uniquely identify the extrinsic's identity from the runtime metadata.
+///
+/// Generated API structures that represent a call implement this trait.
+///
+/// The trait is utilized to decode the calls found in a block's extrinsics, via
+/// obtaining the form of the `Call` from the metadata.
+pub trait StaticExtrinsic: Decode {
+    /// Pallet name.
+    const PALLET: &'static str;
+    /// Call name.
+    const CALL: &'static str;
+
+    /// Returns true if the given pallet and call names match this extrinsic.
+    fn is_extrinsic(pallet: &str, call: &str) -> bool {
+        Self::PALLET == pallet && Self::CALL == call
+    }
+}
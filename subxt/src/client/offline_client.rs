@@ -3,11 +3,16 @@
 // see LICENSE for license details.
 
 use crate::{
+    blocks::BlocksClient,
     constants::ConstantsClient,
+    error::Error,
     events::EventsClient,
     rpc::RuntimeVersion,
     storage::StorageClient,
-    tx::TxClient,
+    tx::{
+        validate_extrinsic_params,
+        TxClient,
+    },
     Config,
     Metadata,
 };
@@ -43,6 +48,11 @@ pub trait OfflineClientT<T: Config>: Clone + Send + Sync + 'static {
     fn constants(&self) -> ConstantsClient<T, Self> {
         ConstantsClient::new(self.clone())
     }
+
+    /// Work with blocks.
+    fn blocks(&self) -> BlocksClient<T, Self> {
+        BlocksClient::new(self.clone())
+    }
 }
 
 /// A client that is capable of performing offline-only operations.
@@ -78,6 +88,19 @@ impl<T: Config> OfflineClient<T> {
         }
     }
 
+    /// Construct a new [`OfflineClient`], as with [`OfflineClient::new()`], but first check
+    /// that the [`Config::ExtrinsicParams`] are compatible with the signed extensions that
+    /// the metadata declares (see [`crate::tx::validate_extrinsic_params()`]). A descriptive
+    /// error is returned if they are not.
+    pub fn new_validated(
+        genesis_hash: T::Hash,
+        runtime_version: RuntimeVersion,
+        metadata: Metadata,
+    ) -> Result<OfflineClient<T>, Error> {
+        validate_extrinsic_params::<T>(&metadata)?;
+        Ok(OfflineClient::new(genesis_hash, runtime_version, metadata))
+    }
+
     /// Return the genesis hash.
     pub fn genesis_hash(&self) -> T::Hash {
         self.inner.genesis_hash
@@ -115,6 +138,11 @@ impl<T: Config> OfflineClient<T> {
     pub fn constants(&self) -> ConstantsClient<T, Self> {
         <Self as OfflineClientT<T>>::constants(self)
     }
+
+    /// Work with blocks.
+    pub fn blocks(&self) -> BlocksClient<T, Self> {
+        <Self as OfflineClientT<T>>::blocks(self)
+    }
 }
 
 impl<T: Config> OfflineClientT<T> for OfflineClient<T> {
@@ -7,16 +7,23 @@ use super::{
     OfflineClientT,
 };
 use crate::{
+    blocks::BlocksClient,
     constants::ConstantsClient,
     error::Error,
     events::EventsClient,
     rpc::{
+        ChainHeadBackend,
+        ChainHeadFollower,
+        FollowSubscription,
         Rpc,
-        RpcClient,
+        RpcClientT,
         RuntimeVersion,
     },
     storage::StorageClient,
-    tx::TxClient,
+    tx::{
+        validate_extrinsic_params,
+        TxClient,
+    },
     Config,
     Metadata,
 };
@@ -30,6 +37,12 @@ use std::sync::Arc;
 pub trait OnlineClientT<T: Config>: OfflineClientT<T> {
     /// Return an RPC client that can be used to communicate with a node.
     fn rpc(&self) -> &Rpc<T>;
+
+    /// Return the backend used to fetch storage entries and block details. By default,
+    /// this only uses the legacy RPC methods.
+    fn backend(&self) -> ChainHeadBackend<T> {
+        ChainHeadBackend::legacy(self.rpc().clone())
+    }
 }
 
 /// A client that can be used to perform API calls (that is, either those
@@ -39,6 +52,7 @@ pub trait OnlineClientT<T: Config>: OfflineClientT<T> {
 pub struct OnlineClient<T: Config> {
     inner: Arc<RwLock<Inner<T>>>,
     rpc: Rpc<T>,
+    backend: ChainHeadBackend<T>,
 }
 
 #[derive(Derivative)]
@@ -58,27 +72,131 @@ impl<T: Config> std::fmt::Debug for OnlineClient<T> {
     }
 }
 
+/// The URL schemes that [`OnlineClient::from_url`] knows how to connect to.
+#[cfg(any(feature = "jsonrpsee-ws", feature = "jsonrpsee-http"))]
+const SUPPORTED_URL_SCHEMES: &[&str] = &[
+    #[cfg(feature = "jsonrpsee-ws")]
+    "'ws'",
+    #[cfg(feature = "jsonrpsee-ws")]
+    "'wss'",
+    #[cfg(feature = "jsonrpsee-http")]
+    "'http'",
+    #[cfg(feature = "jsonrpsee-http")]
+    "'https'",
+];
+
 impl<T: Config> OnlineClient<T> {
     /// Construct a new [`OnlineClient`] using default settings which
     /// point to a locally running node on `ws://127.0.0.1:9944`.
+    #[cfg(feature = "jsonrpsee-ws")]
     pub async fn new() -> Result<OnlineClient<T>, Error> {
         let url = "ws://127.0.0.1:9944";
         OnlineClient::from_url(url).await
     }
 
     /// Construct a new [`OnlineClient`], providing a URL to connect to.
+    ///
+    /// `ws://` and `wss://` URLs connect over WebSocket, and `http://` and `https://`
+    /// URLs connect over HTTP. Subscription based APIs (for instance, watching the
+    /// progress of a transaction, or subscribing to events) are not available over HTTP,
+    /// and will return [`crate::error::RpcError::SubscriptionsNotSupported`] if used.
+    #[cfg(any(feature = "jsonrpsee-ws", feature = "jsonrpsee-http"))]
     pub async fn from_url(url: impl AsRef<str>) -> Result<OnlineClient<T>, Error> {
-        let client = crate::rpc::ws_client(url.as_ref()).await?;
-        OnlineClient::from_rpc_client(client).await
+        let url = url.as_ref();
+        match url.split_once("://").map(|(scheme, _)| scheme) {
+            #[cfg(feature = "jsonrpsee-ws")]
+            Some("ws") | Some("wss") => {
+                let client = crate::rpc::ws_client(url).await?;
+                OnlineClient::from_rpc_client(Arc::new(client)).await
+            }
+            #[cfg(feature = "jsonrpsee-http")]
+            Some("http") | Some("https") => {
+                let client = crate::rpc::http_client(url)?;
+                OnlineClient::from_rpc_client(Arc::new(client)).await
+            }
+            invalid_scheme => {
+                let scheme = invalid_scheme.unwrap_or("no scheme");
+                Err(Error::Other(format!(
+                    "`{}` not supported, expects one of: {}",
+                    scheme,
+                    SUPPORTED_URL_SCHEMES.join(", ")
+                )))
+            }
+        }
     }
 
-    /// Construct a new [`OnlineClient`] by providing the underlying [`RpcClient`]
-    /// to use to drive the connection.
-    pub async fn from_rpc_client(
-        rpc_client: impl Into<RpcClient>,
+    /// Construct a new [`OnlineClient`], as with [`OnlineClient::from_url()`], but check that
+    /// the [`Config::ExtrinsicParams`] are compatible with the signed extensions that the node
+    /// declares (see [`crate::tx::validate_extrinsic_params()`]). A descriptive error is
+    /// returned if they are not.
+    #[cfg(any(feature = "jsonrpsee-ws", feature = "jsonrpsee-http"))]
+    pub async fn from_url_validated(
+        url: impl AsRef<str>,
     ) -> Result<OnlineClient<T>, Error> {
-        let rpc = Rpc::new(rpc_client.into());
+        let client = OnlineClient::from_url(url).await?;
+        validate_extrinsic_params::<T>(&client.metadata())?;
+        Ok(client)
+    }
 
+    /// Construct a new [`OnlineClient`], as with [`OnlineClient::from_rpc_client()`], but
+    /// check that the [`Config::ExtrinsicParams`] are compatible with the signed extensions
+    /// that the node declares (see [`crate::tx::validate_extrinsic_params()`]). A descriptive
+    /// error is returned if they are not.
+    pub async fn from_rpc_client_validated<R: RpcClientT>(
+        rpc_client: Arc<R>,
+    ) -> Result<OnlineClient<T>, Error> {
+        let client = OnlineClient::from_rpc_client(rpc_client).await?;
+        validate_extrinsic_params::<T>(&client.metadata())?;
+        Ok(client)
+    }
+
+    /// Construct a new [`OnlineClient`] by providing an underlying [`RpcClientT`]
+    /// implementation to drive the connection.
+    pub async fn from_rpc_client<R: RpcClientT>(
+        rpc_client: Arc<R>,
+    ) -> Result<OnlineClient<T>, Error> {
+        let rpc = Rpc::new(rpc_client);
+        let backend = ChainHeadBackend::legacy(rpc.clone());
+        OnlineClient::from_rpc_and_backend(rpc, backend).await
+    }
+
+    /// Construct a new [`OnlineClient`], as with [`OnlineClient::from_rpc_client()`], but
+    /// fetch storage entries and block details using the `chainHead_unstable_*` RPC methods
+    /// if the node supports them (falling back to the legacy methods otherwise).
+    ///
+    /// The [`ChainHeadFollower`] handed back keeps track of the blocks that the node has
+    /// pinned for us, and must be run in a separate task for as long as the client is used.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use std::sync::Arc;
+    /// use subxt::{ OnlineClient, PolkadotConfig };
+    ///
+    /// let rpc_client = subxt::rpc::ws_client("ws://127.0.0.1:9944").await.unwrap();
+    /// let (client, follower) =
+    ///     OnlineClient::<PolkadotConfig>::from_rpc_client_with_chain_head(Arc::new(rpc_client))
+    ///         .await
+    ///         .unwrap();
+    ///
+    /// tokio::spawn(follower.follow());
+    /// # }
+    /// ```
+    pub async fn from_rpc_client_with_chain_head<R: RpcClientT>(
+        rpc_client: Arc<R>,
+    ) -> Result<(OnlineClient<T>, ChainHeadFollower<T>), Error> {
+        let rpc = Rpc::new(rpc_client);
+        let (backend, follower) = ChainHeadBackend::following(rpc.clone()).await;
+        let client = OnlineClient::from_rpc_and_backend(rpc, backend).await?;
+        Ok((client, follower))
+    }
+
+    async fn from_rpc_and_backend(
+        rpc: Rpc<T>,
+        backend: ChainHeadBackend<T>,
+    ) -> Result<OnlineClient<T>, Error> {
         let (genesis_hash, runtime_version, metadata) = future::join3(
             rpc.genesis_hash(),
             rpc.runtime_version(None),
@@ -93,6 +211,7 @@ impl<T: Config> OnlineClient<T> {
                 metadata: metadata?,
             })),
             rpc,
+            backend,
         })
     }
 
@@ -141,6 +260,19 @@ impl<T: Config> OnlineClient<T> {
         &self.rpc
     }
 
+    /// Create a [`ChainHeadBackend`] which follows the head of the chain, and can be used
+    /// to fetch the details of the blocks it reports. This uses the `chainHead_unstable_*`
+    /// RPC methods (which pin blocks until they are unpinned) if the node supports them,
+    /// and falls back to the legacy RPC methods otherwise.
+    ///
+    /// The [`FollowSubscription`] handed back reports the blocks being followed, and
+    /// should be polled continuously.
+    pub async fn chain_head_backend(
+        &self,
+    ) -> Result<(ChainHeadBackend<T>, FollowSubscription<T::Hash>), Error> {
+        ChainHeadBackend::new(self.rpc.clone()).await
+    }
+
     /// Return an offline client with the same configuration as this.
     pub fn offline(&self) -> OfflineClient<T> {
         let inner = self.inner.read();
@@ -173,6 +305,11 @@ impl<T: Config> OnlineClient<T> {
     pub fn constants(&self) -> ConstantsClient<T, Self> {
         <Self as OfflineClientT<T>>::constants(self)
     }
+
+    /// Work with blocks.
+    pub fn blocks(&self) -> BlocksClient<T, Self> {
+        <Self as OfflineClientT<T>>::blocks(self)
+    }
 }
 
 impl<T: Config> OfflineClientT<T> for OnlineClient<T> {
@@ -191,6 +328,9 @@ impl<T: Config> OnlineClientT<T> for OnlineClient<T> {
     fn rpc(&self) -> &Rpc<T> {
         &self.rpc
     }
+    fn backend(&self) -> ChainHeadBackend<T> {
+        self.backend.clone()
+    }
 }
 
 /// Client wrapper for performing runtime updates. See [`OnlineClient::subscribe_to_updates()`]
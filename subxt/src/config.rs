@@ -6,7 +6,8 @@
 //! types that are important in order to speak to a particular chain.
 //! [`SubstrateConfig`] provides a default set of these types suitable for the
 //! default Substrate node implementation, and [`PolkadotConfig`] for a
-//! Polkadot node.
+//! Polkadot node. With the `ethereum` feature enabled, `EthereumConfig`
+//! is also provided for Ethereum-compatible nodes.
 
 use codec::{
     Codec,
@@ -114,6 +115,29 @@ pub type PolkadotConfig = WithExtrinsicParams<
     crate::tx::PolkadotExtrinsicParams<SubstrateConfig>,
 >;
 
+/// Default set of commonly used types by Ethereum-compatible nodes, such as those built
+/// using Frontier, which identify accounts by 20 byte addresses and sign transactions with
+/// secp256k1 keys. Transactions can be signed for these using [`crate::tx::EthereumSigner`].
+// Note: We only use this at the type level, so it should be impossible to
+// create an instance of it.
+#[cfg(feature = "ethereum")]
+pub enum EthereumConfig {}
+
+#[cfg(feature = "ethereum")]
+impl Config for EthereumConfig {
+    type Index = u32;
+    type BlockNumber = u32;
+    type Hash = sp_core::H256;
+    type Hashing = sp_runtime::traits::BlakeTwo256;
+    type AccountId = crate::utils::AccountId20;
+    type Address = crate::utils::AccountId20;
+    type Header =
+        sp_runtime::generic::Header<Self::BlockNumber, sp_runtime::traits::BlakeTwo256>;
+    type Signature = crate::utils::EthereumSignature;
+    type Extrinsic = sp_runtime::OpaqueExtrinsic;
+    type ExtrinsicParams = crate::tx::PolkadotExtrinsicParams<Self>;
+}
+
 /// Take a type implementing [`Config`] (eg [`SubstrateConfig`]), and some type which describes the
 /// additional and extra parameters to pass to an extrinsic (see [`crate::tx::ExtrinsicParams`]),
 /// and returns a type implementing [`Config`] with those new `ExtrinsicParams`.
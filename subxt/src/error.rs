@@ -15,7 +15,6 @@ pub use crate::metadata::{
     InvalidMetadataError,
     MetadataError,
 };
-pub use jsonrpsee::core::error::Error as RequestError;
 pub use scale_value::scale::{
     DecodeError,
     EncodeError,
@@ -36,7 +35,7 @@ pub enum Error {
     Codec(#[from] codec::Error),
     /// Rpc error.
     #[error("Rpc error: {0}")]
-    Rpc(#[from] RequestError),
+    Rpc(#[from] RpcError),
     /// Serde serialization error
     #[error("Serde json error: {0}")]
     Serialization(#[from] serde_json::error::Error),
@@ -64,9 +63,27 @@ pub enum Error {
     /// Transaction progress error.
     #[error("Transaction error: {0}")]
     Transaction(#[from] TransactionError),
+    /// An error verifying an extrinsic.
+    #[error("Extrinsic verification error: {0}")]
+    ExtrinsicVerification(#[from] ExtrinsicVerificationError),
     /// An error encoding a storage address.
     #[error("Error encoding storage address: {0}")]
     StorageAddress(#[from] StorageAddressError),
+    /// An error verifying a storage read proof.
+    #[error("Read proof error: {0}")]
+    ReadProof(#[from] ReadProofError),
+    /// An error working with a block or its extrinsics.
+    #[error("Block error: {0}")]
+    Block(#[from] BlockError),
+    /// An error constructing the signed extensions of an extrinsic.
+    #[error("Extrinsic params error: {0}")]
+    ExtrinsicParams(#[from] ExtrinsicParamsError),
+    /// An error loading a key for a `tx::KeystoreSigner`.
+    #[error("Keystore error: {0}")]
+    Keystore(#[from] KeystoreError),
+    /// An [`crate::tx::AsyncSigner`] was unable to sign a payload.
+    #[error("Signer error: {0}")]
+    Signer(String),
     /// Other error.
     #[error("Other error: {0}")]
     Other(String),
@@ -102,6 +119,30 @@ impl From<DispatchError> for Error {
     }
 }
 
+/// An RPC error. Since we are generic over the RPC client that is used,
+/// the error is boxed and could be casted.
+#[derive(Debug, thiserror::Error)]
+pub enum RpcError {
+    /// Error related to the RPC client.
+    #[error("{0}")]
+    ClientError(Box<dyn std::error::Error + Send + Sync + 'static>),
+    /// The RPC subscription dropped.
+    #[error("subscription dropped.")]
+    SubscriptionDropped,
+    /// The connection to the node was lost. Clients which are able to reconnect
+    /// (see [`crate::rpc::ReconnectingRpcClient`]) use this to know when to do so.
+    #[error("connection lost: {0}")]
+    ConnectionLost(String),
+    /// The RPC client does not support subscriptions (for instance, because it
+    /// talks to the node over HTTP). Use a WebSocket connection instead.
+    #[error("subscription to `{0}` is not supported by this RPC client; connect over WebSocket to use subscriptions.")]
+    SubscriptionsNotSupported(String),
+    /// A `chainHead_unstable_*` operation did not succeed. This can happen if the block
+    /// was unpinned, or if the node was unable to obtain the information asked for.
+    #[error("chainHead operation failed: {0}")]
+    ChainHeadOperationFailed(String),
+}
+
 /// This is our attempt to decode a runtime DispatchError. We either
 /// successfully decode it into a [`ModuleError`], or we fail and keep
 /// hold of the bytes, which we can attempt to decode if we have an
@@ -238,6 +279,122 @@ pub enum TransactionError {
     BlockHashNotFound,
 }
 
+/// Something went wrong verifying an extrinsic.
+#[derive(Clone, Debug, Eq, thiserror::Error, PartialEq)]
+pub enum ExtrinsicVerificationError {
+    /// The extrinsic is not signed, and so its signature can't be verified.
+    #[error("The extrinsic is not signed")]
+    NotSigned,
+    /// The account which signed the extrinsic can't be determined from its address.
+    #[error(
+        "The account which signed the extrinsic can't be determined from its address"
+    )]
+    UnknownSigner,
+    /// The signature of the extrinsic is not valid.
+    #[error("The signature of the extrinsic is not valid")]
+    InvalidSignature,
+    /// The hash of the block that a mortal extrinsic was created at could not be found.
+    #[error("Could not find the hash of block {0}, which the extrinsic's mortality is relative to")]
+    MortalityCheckpointNotFound(u64),
+}
+
+/// Something went wrong fetching a block or decoding its extrinsics.
+#[derive(Clone, Debug, Eq, thiserror::Error, PartialEq)]
+pub enum BlockError {
+    /// No block could be found with the given hash.
+    #[error(
+        "Could not find a block with hash {0} (perhaps it was on a non-finalized fork?)"
+    )]
+    BlockHashNotFound(String),
+    /// Only version 4 extrinsics can be decoded.
+    #[error(
+        "Unsupported extrinsic version {0}; only version 4 extrinsics can be decoded"
+    )]
+    UnsupportedVersion(u8),
+    /// The extrinsic type in the metadata lacks a type parameter that we need.
+    #[error("The extrinsic type in the metadata has no '{0}' type parameter")]
+    MissingTypeParameter(&'static str),
+    /// The compact length prefix of an extrinsic does not match the number of bytes
+    /// which follow it.
+    #[error(
+        "Extrinsic {index} has a length prefix of {expected} bytes, but {actual} bytes follow it"
+    )]
+    WrongLength {
+        /// The index of the extrinsic in the block.
+        index: u32,
+        /// The length given by the extrinsic's length prefix.
+        expected: usize,
+        /// The number of bytes following the length prefix.
+        actual: usize,
+    },
+    /// Decoding the extrinsic did not consume all of its bytes.
+    #[error(
+        "Extrinsic {index} was decoded, but {leftover} of its {len} bytes were not used"
+    )]
+    LeftoverBytes {
+        /// The index of the extrinsic in the block.
+        index: u32,
+        /// The number of bytes left over after decoding.
+        leftover: usize,
+        /// The total length of the extrinsic in bytes.
+        len: usize,
+    },
+}
+
+/// Something went wrong loading a key for a `tx::KeystoreSigner`.
+#[derive(Debug, thiserror::Error)]
+pub enum KeystoreError {
+    /// The key file could not be read or written.
+    #[error("Io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The key file is not valid JSON, or is missing some fields.
+    #[error("Invalid key file: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The key file is not in a format that we understand.
+    #[error("Unsupported key file: {0}")]
+    Unsupported(String),
+    /// Some part of the key file could not be decoded.
+    #[error("Invalid key file: {0}")]
+    Invalid(String),
+    /// The key could not be decrypted, most likely because the password is wrong.
+    #[error("Unable to decrypt the key; is the password correct?")]
+    DecryptionFailed,
+    /// A secret URI (for instance a mnemonic phrase and derivation path) is not valid.
+    #[error("Invalid secret URI: {0:?}")]
+    InvalidSecretUri(SecretStringError),
+}
+
+/// Something went wrong constructing the "signed extra" and "additional" parameters of
+/// an extrinsic (see [`crate::tx::ExtrinsicParams`]).
+#[derive(Clone, Debug, Eq, thiserror::Error, PartialEq)]
+pub enum ExtrinsicParamsError {
+    /// The node requires a signed extension which carries some data, but we don't
+    /// know how to encode it.
+    #[error(
+        "The node requires the '{0}' signed extension, which we don't know how to encode"
+    )]
+    UnknownSignedExtension(String),
+    /// The configured [`crate::tx::ExtrinsicParams`] are not able to encode every signed
+    /// extension that the node asks for.
+    #[error(
+        "The node requires the signed extensions [{}], which the configured ExtrinsicParams cannot encode (it can encode [{}], which the node does not ask for)",
+        .missing.join(", "),
+        .unused.join(", ")
+    )]
+    IncompatibleSignedExtensions {
+        /// Signed extensions which the node requires, but which can't be encoded.
+        missing: Vec<String>,
+        /// Signed extensions which can be encoded, but which the node doesn't ask for.
+        unused: Vec<String>,
+    },
+    /// A type that a signed extension refers to was not found in the metadata.
+    #[error("Type {0} used by a signed extension was not found in the metadata")]
+    TypeNotFound(u32),
+    /// Some other error, for instance from a custom signed extension.
+    #[error("{0}")]
+    Custom(String),
+}
+
 /// Details about a module error that has occurred.
 #[derive(Clone, Debug, thiserror::Error)]
 #[error("{pallet}: {error}\n\n{}", .description.join("\n"))]
@@ -272,7 +429,8 @@ impl ModuleErrorData {
     }
 }
 
-/// Something went wrong trying to encode a storage address.
+/// Something went wrong trying to encode a storage address, or to decode the keys
+/// from a storage key.
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum StorageAddressError {
     /// Storage map type must be a composite type.
@@ -301,4 +459,38 @@ pub enum StorageAddressError {
         /// The number of fields in the metadata for this storage entry.
         fields: usize,
     },
+    /// The storage key does not belong to the storage entry that its keys are being
+    /// decoded for.
+    #[error(
+        "Storage key does not belong to the storage entry {pallet_name}::{entry_name}"
+    )]
+    UnexpectedKeyPrefix {
+        /// The name of the pallet that the storage entry belongs to.
+        pallet_name: String,
+        /// The name of the storage entry.
+        entry_name: String,
+    },
+    /// A storage key cannot be decoded, because it was hashed using a hasher which
+    /// doesn't keep a copy of the original key.
+    #[error("Storage keys hashed with {0:?} cannot be decoded")]
+    HasherNotReversible(frame_metadata::StorageHasher),
+    /// Some bytes were left over after decoding the keys from a storage key.
+    #[error("{0} bytes were left over after decoding the storage keys")]
+    TooManyKeyBytes(usize),
+}
+
+/// Something went wrong verifying a storage read proof against a state root.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum ReadProofError {
+    /// The proof doesn't contain the root node of the trie, and so doesn't
+    /// belong to the given state root.
+    #[error("Read proof does not contain the state root {0}")]
+    RootNotFound(String),
+    /// The proof doesn't contain all of the trie nodes needed to look up the value
+    /// at some storage key, and so can't prove what the value is (or that there is none).
+    #[error("Read proof does not cover the storage key 0x{0}")]
+    MissingKey(String),
+    /// The proof contains a trie node which can't be decoded.
+    #[error("Read proof is invalid: {0}")]
+    Invalid(String),
 }
@@ -18,7 +18,6 @@ use futures::{
     Stream,
     StreamExt,
 };
-use jsonrpsee::core::client::Subscription;
 use sp_runtime::traits::Header;
 use std::{
     marker::Unpin,
@@ -32,15 +31,15 @@ pub use super::{
     FilterEvents,
 };
 
-/// A `jsonrpsee` Subscription. This forms a part of the `EventSubscription` type handed back
+/// A boxed stream of block headers. This forms a part of the `EventSubscription` type handed back
 /// in codegen from `subscribe_finalized`, and is exposed to be used in codegen.
 #[doc(hidden)]
 pub type FinalizedEventSub<Header> = BoxStream<'static, Result<Header, Error>>;
 
-/// A `jsonrpsee` Subscription. This forms a part of the `EventSubscription` type handed back
+/// A boxed stream of block headers. This forms a part of the `EventSubscription` type handed back
 /// in codegen from `subscribe`, and is exposed to be used in codegen.
 #[doc(hidden)]
-pub type EventSub<Item> = Subscription<Item>;
+pub type EventSub<Header> = BoxStream<'static, Result<Header, Error>>;
 
 /// A subscription to events that implements [`Stream`], and returns [`Events`] objects for each block.
 #[derive(Derivative)]
@@ -11,6 +11,10 @@ use crate::{
         Events,
         FinalizedEventSub,
     },
+    rpc::{
+        rpc_params,
+        BlockNumber,
+    },
     Config,
 };
 use derivative::Derivative;
@@ -19,13 +23,17 @@ use futures::{
     stream,
     Stream,
     StreamExt,
+    TryStreamExt,
 };
 use sp_core::{
     storage::StorageKey,
     twox_128,
 };
 use sp_runtime::traits::Header;
-use std::future::Future;
+use std::{
+    future::Future,
+    ops::Range,
+};
 
 /// A client for working with events.
 #[derive(Derivative)]
@@ -129,22 +137,15 @@ where
 {
     // If block hash is not provided, get the hash
     // for the latest block and use that.
+    let backend = client.backend();
     let block_hash = match block_hash {
         Some(hash) => hash,
-        None => {
-            client
-                .rpc()
-                .block_hash(None)
-                .await?
-                .expect("didn't pass a block number; qed")
-        }
+        None => backend.best_block_hash().await?,
     };
 
-    let event_bytes = client
-        .rpc()
-        .storage(&*system_events_key().0, Some(block_hash))
+    let event_bytes = backend
+        .storage_at(&system_events_key().0, Some(block_hash))
         .await?
-        .map(|e| e.0)
         .unwrap_or_else(Vec::new);
 
     Ok(Events::new(client.metadata(), block_hash, event_bytes))
@@ -157,8 +158,14 @@ where
     T: Config,
     Client: OnlineClientT<T>,
 {
-    let block_subscription = client.rpc().subscribe_blocks().await?;
-    Ok(EventSubscription::new(client, block_subscription))
+    let sub = client.rpc().subscribe_blocks().await?;
+
+    // Fill in any gaps in the blocks reported, for instance if the subscription was
+    // re-established after the connection to the node dropped.
+    let block_subscription =
+        subscribe_to_best_block_headers_filling_in_gaps(client.clone(), sub);
+
+    Ok(EventSubscription::new(client, Box::pin(block_subscription)))
 }
 
 /// Subscribe to events from finalized blocks.
@@ -204,6 +211,11 @@ where
     S: Stream<Item = Result<T::Header, E>> + Send,
     E: Into<Error> + Send + 'static,
 {
+    // Both of these are updated with each header that we hand back, but unlike `last_block_num`,
+    // this doesn't start from the value passed in. That way, the first header is always handed
+    // back, even if it's no newer than the block we were told to start after.
+    let mut last_returned_block_num: Option<u64> = None;
+
     sub.flat_map(move |s| {
         let client = client.clone();
 
@@ -211,39 +223,193 @@ where
         // stream will return `None` as soon as it hits an error like this.
         let header = match s {
             Ok(header) => header,
-            Err(e) => return Either::Left(stream::once(async { Err(e.into()) })),
+            Err(e) => return Either::Left(stream::iter(Some(Err(e.into())))),
         };
 
         // We want all previous details up to, but not including this current block num.
         let end_block_num = (*header.number()).into();
 
+        // We've already returned this block (this can happen if the underlying subscription
+        // was re-established after the connection dropped), so there's nothing to do.
+        if matches!(last_returned_block_num, Some(n) if end_block_num <= n) {
+            return Either::Left(stream::iter(None))
+        }
+
         // This is one after the last block we returned details for last time.
         let start_block_num = last_block_num.map(|n| n + 1).unwrap_or(end_block_num);
 
-        // Iterate over all of the previous blocks we need headers for, ignoring the current block
-        // (which we already have the header info for):
-        let previous_headers = stream::iter(start_block_num..end_block_num)
-            .then(move |n| {
-                let client = client.clone();
-                async move {
-                    let hash = client.rpc().block_hash(Some(n.into())).await?;
-                    let header = client.rpc().header(hash).await?;
-                    Ok::<_, Error>(header)
-                }
-            })
-            .filter_map(|h| async { h.transpose() });
+        // Fetch all of the previous blocks we need headers for, ignoring the current block
+        // (which we already have the header info for).
+        let previous_headers = headers_in_range(client, start_block_num..end_block_num);
 
         // On the next iteration, we'll get details starting just after this end block.
         last_block_num = Some(end_block_num);
+        last_returned_block_num = Some(end_block_num);
 
         // Return a combination of any previous headers plus the new header.
         Either::Right(previous_headers.chain(stream::once(async { Ok(header) })))
     })
 }
 
+/// Note: This is exposed for testing but is not considered stable and may change
+/// without notice in a patch release.
+#[doc(hidden)]
+pub fn subscribe_to_best_block_headers_filling_in_gaps<T, Client, S, E>(
+    client: Client,
+    sub: S,
+) -> impl Stream<Item = Result<T::Header, Error>> + Send
+where
+    T: Config,
+    Client: OnlineClientT<T> + Send + Sync,
+    S: Stream<Item = Result<T::Header, E>> + Send,
+    E: Into<Error> + Send + 'static,
+{
+    // The number and hash of the last header that we handed back.
+    let mut last_returned: Option<(u64, T::Hash)> = None;
+
+    sub.flat_map(move |s| {
+        let client = client.clone();
+
+        // Get the header, or return a stream containing just the error.
+        let header = match s {
+            Ok(header) => header,
+            Err(e) => return Either::Left(stream::iter(Some(Err(e.into())))),
+        };
+
+        let block_num: u64 = (*header.number()).into();
+        let block_hash = header.hash();
+
+        // Unlike the finalized block, the best block can stay at the same height or move
+        // backwards when there's a re-org, and so we hand back any such blocks as normal.
+        // We only skip a block that we've just handed back (as happens when the subscription
+        // is re-established after the connection dropped), and only fill in the blocks
+        // between the last one and this one when the best block has jumped ahead.
+        let start_block_num = match last_returned {
+            Some((_, hash)) if hash == block_hash => {
+                return Either::Left(stream::iter(None))
+            }
+            Some((n, _)) if block_num > n + 1 => n + 1,
+            _ => block_num,
+        };
+        last_returned = Some((block_num, block_hash));
+
+        let previous_headers = headers_in_range(client, start_block_num..block_num);
+        Either::Right(previous_headers.chain(stream::once(async { Ok(header) })))
+    })
+}
+
+// Fetch the headers for a range of blocks in order. These are fetched in batches, so that
+// a large range doesn't cost us a couple of round trips per block.
+fn headers_in_range<T, Client>(
+    client: Client,
+    blocks: Range<u64>,
+) -> impl Stream<Item = Result<T::Header, Error>> + Send
+where
+    T: Config,
+    Client: OnlineClientT<T> + Send + Sync,
+{
+    let end_block_num = blocks.end;
+    stream::iter(blocks.step_by(MAX_HEADERS_PER_BATCH))
+        .then(move |from| {
+            let to = end_block_num.min(from + MAX_HEADERS_PER_BATCH as u64);
+            fetch_headers(client.clone(), from..to)
+        })
+        .map_ok(|headers| stream::iter(headers.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+// The most headers we'll ask for in a single batch when filling in gaps.
+const MAX_HEADERS_PER_BATCH: usize = 256;
+
+// Fetch the headers for a range of blocks, using one batch of requests to obtain the block
+// hashes and another to obtain the headers. Blocks that can't be found are skipped.
+async fn fetch_headers<T, Client>(
+    client: Client,
+    blocks: Range<u64>,
+) -> Result<Vec<T::Header>, Error>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+{
+    let mut batch = client.rpc().batch();
+    let hashes: Vec<_> = blocks
+        .map(|n| {
+            batch.add::<Option<T::Hash>>(
+                "chain_getBlockHash",
+                rpc_params![BlockNumber::from(n)],
+            )
+        })
+        .collect();
+    let hashes_res = batch.send().await?;
+
+    let mut batch = client.rpc().batch();
+    let mut headers = Vec::with_capacity(hashes.len());
+    for hash in hashes {
+        if let Some(hash) = hashes_res.get(hash)? {
+            headers.push(
+                batch.add::<Option<T::Header>>("chain_getHeader", rpc_params![hash]),
+            );
+        }
+    }
+    let headers_res = batch.send().await?;
+
+    let mut out = Vec::with_capacity(headers.len());
+    for header in headers {
+        if let Some(header) = headers_res.get(header)? {
+            out.push(header);
+        }
+    }
+    Ok(out)
+}
+
 // The storage key needed to access events.
 fn system_events_key() -> StorageKey {
     let mut storage_key = twox_128(b"System").to_vec();
     storage_key.extend(twox_128(b"Events").to_vec());
     StorageKey(storage_key)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        rpc::MockNode,
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn fills_in_gaps_in_best_blocks() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+        for _ in 0..4 {
+            node.produce_block();
+        }
+
+        let mut headers = Vec::new();
+        for n in [1, 1, 4, 3] {
+            let hash = node.block_hash(n);
+            headers.push(
+                api.rpc()
+                    .header(hash)
+                    .await
+                    .unwrap()
+                    .ok_or(Error::Other("header not found".into())),
+            );
+        }
+
+        // A repeated block is skipped (as happens after re-subscribing), blocks that were
+        // jumped over are filled in, and blocks from re-orgs are handed back as normal.
+        let numbers: Vec<u32> =
+            subscribe_to_best_block_headers_filling_in_gaps(api, stream::iter(headers))
+                .map_ok(|header| header.number)
+                .try_collect()
+                .await
+                .unwrap();
+        assert_eq!(numbers, vec![1, 2, 3, 4, 3]);
+    }
+}
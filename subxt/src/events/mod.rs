@@ -18,6 +18,7 @@ pub use event_subscription::{
 };
 pub use events_client::{
     // Exposed only for testing:
+    subscribe_to_best_block_headers_filling_in_gaps,
     subscribe_to_block_headers_filling_in_gaps,
     EventsClient,
 };
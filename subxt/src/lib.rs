@@ -122,6 +122,7 @@
 
 pub use subxt_macro::subxt;
 
+pub mod blocks;
 pub mod client;
 pub mod config;
 pub mod constants;
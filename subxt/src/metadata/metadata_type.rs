@@ -36,6 +36,9 @@ pub enum MetadataError {
     /// Call is not in metadata.
     #[error("Call not found")]
     CallNotFound,
+    /// Call is not in metadata.
+    #[error("Pallet {0}, Call {1} not found")]
+    CallIndexNotFound(u8, u8),
     /// Event is not in metadata.
     #[error("Pallet {0}, Event {0} not found")]
     EventNotFound(u8, u8),
@@ -74,6 +77,7 @@ struct MetadataInner {
     metadata: RuntimeMetadataV14,
     pallets: HashMap<String, PalletMetadata>,
     events: HashMap<(u8, u8), EventMetadata>,
+    calls: HashMap<(u8, u8), CallMetadata>,
     // Errors are hashed by pallet index.
     errors: HashMap<(u8, u8), ErrorMetadata>,
     // Type of the DispatchError type, which is what comes back if
@@ -117,6 +121,20 @@ impl Metadata {
         Ok(event)
     }
 
+    /// Returns the metadata for the call at the given pallet and call indices.
+    pub fn call(
+        &self,
+        pallet_index: u8,
+        call_index: u8,
+    ) -> Result<&CallMetadata, MetadataError> {
+        let call = self
+            .inner
+            .calls
+            .get(&(pallet_index, call_index))
+            .ok_or(MetadataError::CallIndexNotFound(pallet_index, call_index))?;
+        Ok(call)
+    }
+
     /// Returns the metadata for the error at the given pallet and error indices.
     pub fn error(
         &self,
@@ -324,6 +342,39 @@ impl EventMetadata {
     }
 }
 
+/// Metadata for specific calls.
+#[derive(Clone, Debug)]
+pub struct CallMetadata {
+    // The pallet name is shared across every call, so put it
+    // behind an Arc to avoid lots of needless clones of it existing.
+    pallet: Arc<str>,
+    call: String,
+    fields: Vec<(Option<String>, u32)>,
+    docs: Vec<String>,
+}
+
+impl CallMetadata {
+    /// Get the name of the pallet that the call belongs to.
+    pub fn pallet(&self) -> &str {
+        &self.pallet
+    }
+
+    /// Get the name of the call.
+    pub fn call(&self) -> &str {
+        &self.call
+    }
+
+    /// The names and types of each of the call arguments.
+    pub fn fields(&self) -> &[(Option<String>, u32)] {
+        &self.fields
+    }
+
+    /// Documentation for this call.
+    pub fn docs(&self) -> &[String] {
+        &self.docs
+    }
+}
+
 /// Details about a specific runtime error.
 #[derive(Clone, Debug)]
 pub struct ErrorMetadata {
@@ -460,6 +511,29 @@ impl TryFrom<RuntimeMetadataPrefixed> for Metadata {
             }
         }
 
+        let mut calls = HashMap::<(u8, u8), CallMetadata>::new();
+        for pallet in &metadata.pallets {
+            if let Some(call) = &pallet.calls {
+                let pallet_name: Arc<str> = pallet.name.to_string().into();
+                let call_variant = get_type_def_variant(call.ty.id())?;
+                for variant in call_variant.variants() {
+                    calls.insert(
+                        (pallet.index, variant.index()),
+                        CallMetadata {
+                            pallet: pallet_name.clone(),
+                            call: variant.name().to_owned(),
+                            fields: variant
+                                .fields()
+                                .iter()
+                                .map(|f| (f.name().map(|n| n.to_owned()), f.ty().id()))
+                                .collect(),
+                            docs: variant.docs().to_vec(),
+                        },
+                    );
+                }
+            }
+        }
+
         let mut errors = HashMap::<(u8, u8), ErrorMetadata>::new();
         for pallet in &metadata.pallets {
             if let Some(error) = &pallet.error {
@@ -490,6 +564,7 @@ impl TryFrom<RuntimeMetadataPrefixed> for Metadata {
                 metadata,
                 pallets,
                 events,
+                calls,
                 errors,
                 dispatch_error_ty,
                 cached_metadata_hash: Default::default(),
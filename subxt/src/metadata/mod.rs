@@ -13,6 +13,7 @@ mod metadata_type;
 pub use metadata_location::MetadataLocation;
 
 pub use metadata_type::{
+    CallMetadata,
     ErrorMetadata,
     EventMetadata,
     InvalidMetadataError,
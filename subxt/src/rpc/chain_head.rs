@@ -0,0 +1,257 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Types and methods for the `chainHead_unstable_*` family of RPC methods, which are
+//! a part of the new JSON-RPC interface being standardised at
+//! <https://github.com/paritytech/json-rpc-interface-spec>. These methods are unstable,
+//! and so may change or disappear in future node versions.
+
+use super::{
+    rpc_params,
+    Rpc,
+    RuntimeVersion,
+    Subscription,
+};
+use crate::{
+    error::Error,
+    Config,
+};
+use codec::Decode;
+use serde::Deserialize;
+use sp_core::Bytes;
+
+/// An event emitted from a `chainHead_unstable_follow` subscription.
+///
+/// Every block reported in [`FollowEvent::Initialized`] or [`FollowEvent::NewBlock`] is
+/// pinned by the node, and stays available to query until it's unpinned via
+/// [`Rpc::chainhead_unstable_unpin()`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "event")]
+pub enum FollowEvent<Hash> {
+    /// The first event to be emitted, reporting the current finalized block.
+    Initialized(Initialized<Hash>),
+    /// A new, non-finalized block has been added to the chain.
+    NewBlock(NewBlock<Hash>),
+    /// The best block of the chain has changed.
+    BestBlockChanged(BestBlockChanged<Hash>),
+    /// Some blocks have been finalized, and others pruned.
+    Finalized(Finalized<Hash>),
+    /// The subscription has been stopped by the node, and no more events will be
+    /// emitted. Any pinned blocks are unpinned, and a new subscription must be created
+    /// in order to continue following the chain.
+    Stop,
+}
+
+/// The details of a [`FollowEvent::Initialized`] event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Initialized<Hash> {
+    /// The hash of the current finalized block.
+    pub finalized_block_hash: Hash,
+    /// The runtime of the finalized block. This is only present if runtime updates
+    /// were asked for when subscribing.
+    pub finalized_block_runtime: Option<RuntimeEvent>,
+}
+
+/// The details of a [`FollowEvent::NewBlock`] event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBlock<Hash> {
+    /// The hash of the new block.
+    pub block_hash: Hash,
+    /// The hash of the parent of the new block.
+    pub parent_block_hash: Hash,
+    /// The runtime of the new block, if it differs from that of its parent. This is
+    /// only present if runtime updates were asked for when subscribing.
+    pub new_runtime: Option<RuntimeEvent>,
+}
+
+/// The details of a [`FollowEvent::BestBlockChanged`] event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BestBlockChanged<Hash> {
+    /// The hash of the new best block.
+    pub best_block_hash: Hash,
+}
+
+/// The details of a [`FollowEvent::Finalized`] event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finalized<Hash> {
+    /// The hashes of the newly finalized blocks, oldest first.
+    pub finalized_block_hashes: Vec<Hash>,
+    /// The hashes of blocks which are no longer descendants of the finalized block,
+    /// and so will never be finalized. These remain pinned until they are unpinned.
+    pub pruned_block_hashes: Vec<Hash>,
+}
+
+/// The runtime of a block, as reported in a [`FollowEvent`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum RuntimeEvent {
+    /// The runtime is valid, and has the given version.
+    Valid(RuntimeVersionEvent),
+    /// The runtime is not valid, for the reason given.
+    Invalid(ErrorEvent),
+}
+
+/// The version of a valid runtime.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RuntimeVersionEvent {
+    /// The runtime version.
+    pub spec: RuntimeVersion,
+}
+
+/// An error reported by the node.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ErrorEvent {
+    /// A human readable description of the error.
+    pub error: String,
+}
+
+/// An event emitted from the subscriptions started by `chainHead_unstable_body`,
+/// `chainHead_unstable_storage` and `chainHead_unstable_call`. Exactly one such
+/// event is emitted per subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "event")]
+pub enum ChainHeadEvent<T> {
+    /// The operation succeeded.
+    Done(ChainHeadResult<T>),
+    /// The node was not able to obtain the information requested, but trying again
+    /// may succeed.
+    Inaccessible(ErrorEvent),
+    /// The operation failed, and trying again will not help.
+    Error(ErrorEvent),
+    /// The `chainHead_unstable_follow` subscription that this operation relates to
+    /// has stopped.
+    Disjoint,
+}
+
+/// The result of a successful [`ChainHeadEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ChainHeadResult<T> {
+    /// The result of the operation.
+    pub result: T,
+}
+
+/// The methods which the node claims to support, as returned from `rpc_methods`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RpcMethods {
+    /// The version of this response.
+    pub version: u32,
+    /// The names of the supported methods.
+    pub methods: Vec<String>,
+}
+
+impl<T: Config> Rpc<T> {
+    /// Fetch the list of methods that the node supports.
+    pub async fn rpc_methods(&self) -> Result<RpcMethods, Error> {
+        self.request("rpc_methods", rpc_params![]).await
+    }
+
+    /// Follow the head of the chain. If `runtime_updates` is true, the events emitted
+    /// will report the runtime of each block.
+    ///
+    /// The ID of the returned subscription (see [`Subscription::subscription_id()`]) must
+    /// be handed to the other `chainHead_unstable_*` methods.
+    pub async fn chainhead_unstable_follow(
+        &self,
+        runtime_updates: bool,
+    ) -> Result<Subscription<FollowEvent<T::Hash>>, Error> {
+        let subscription = self
+            .subscribe_with_id(
+                "chainHead_unstable_follow",
+                rpc_params![runtime_updates],
+                "chainHead_unstable_unfollow",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
+    /// Fetch the SCALE encoded extrinsics in the body of a pinned block.
+    pub async fn chainhead_unstable_body(
+        &self,
+        subscription_id: &str,
+        hash: T::Hash,
+    ) -> Result<Subscription<ChainHeadEvent<Vec<Bytes>>>, Error> {
+        let subscription = self
+            .subscribe(
+                "chainHead_unstable_body",
+                rpc_params![subscription_id, hash],
+                "chainHead_unstable_stopBody",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
+    /// Fetch the header of a pinned block. This returns `None` if the block is not pinned.
+    pub async fn chainhead_unstable_header(
+        &self,
+        subscription_id: &str,
+        hash: T::Hash,
+    ) -> Result<Option<T::Header>, Error> {
+        let params = rpc_params![subscription_id, hash];
+        let header: Option<Bytes> =
+            self.request("chainHead_unstable_header", params).await?;
+        let header = header
+            .map(|bytes| T::Header::decode(&mut &*bytes.0))
+            .transpose()?;
+        Ok(header)
+    }
+
+    /// Fetch the value of some storage entry at a pinned block. `child_key` can be
+    /// given in order to look into a child trie.
+    pub async fn chainhead_unstable_storage(
+        &self,
+        subscription_id: &str,
+        hash: T::Hash,
+        key: &[u8],
+        child_key: Option<&[u8]>,
+    ) -> Result<Subscription<ChainHeadEvent<Option<Bytes>>>, Error> {
+        let key = Bytes::from(key.to_vec());
+        let child_key = child_key.map(|k| Bytes::from(k.to_vec()));
+        let subscription = self
+            .subscribe(
+                "chainHead_unstable_storage",
+                rpc_params![subscription_id, hash, key, child_key],
+                "chainHead_unstable_stopStorage",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
+    /// Call a runtime API function at a pinned block, handing back the SCALE
+    /// encoded result.
+    pub async fn chainhead_unstable_call(
+        &self,
+        subscription_id: &str,
+        hash: T::Hash,
+        function: &str,
+        call_parameters: &[u8],
+    ) -> Result<Subscription<ChainHeadEvent<Bytes>>, Error> {
+        let call_parameters = Bytes::from(call_parameters.to_vec());
+        let subscription = self
+            .subscribe(
+                "chainHead_unstable_call",
+                rpc_params![subscription_id, hash, function, call_parameters],
+                "chainHead_unstable_stopCall",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
+    /// Unpin a block reported by a `chainHead_unstable_follow` subscription, allowing the
+    /// node to discard it.
+    pub async fn chainhead_unstable_unpin(
+        &self,
+        subscription_id: &str,
+        hash: T::Hash,
+    ) -> Result<(), Error> {
+        let params = rpc_params![subscription_id, hash];
+        self.request("chainHead_unstable_unpin", params).await
+    }
+}
@@ -0,0 +1,918 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! A backend which talks to the node using the `chainHead_unstable_*` RPC methods
+//! where they are available, and the legacy `chain_*` and `state_*` methods otherwise.
+
+use super::{
+    chain_head::{
+        BestBlockChanged,
+        ChainHeadEvent,
+        Finalized,
+        FollowEvent,
+        Initialized,
+        NewBlock,
+    },
+    rpc_params,
+    Rpc,
+    RpcError,
+    Subscription,
+};
+use crate::{
+    error::Error,
+    Config,
+};
+use codec::Encode;
+use futures::{
+    future,
+    stream::{
+        self,
+        BoxStream,
+    },
+    Stream,
+    StreamExt,
+};
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use sp_core::Bytes;
+use sp_runtime::traits::Header;
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    sync::Arc,
+    task::Poll,
+};
+
+/// The RPC method whose presence tells us that the node supports the
+/// `chainHead_unstable_*` family of methods.
+const CHAIN_HEAD_FOLLOW: &str = "chainHead_unstable_follow";
+
+/// A backend for fetching block details, obtained via [`ChainHeadBackend::new()`] or
+/// [`crate::OnlineClient::chain_head_backend()`].
+///
+/// If the node supports the `chainHead_unstable_*` RPC methods, then every block reported
+/// via the accompanying [`FollowSubscription`] is pinned by the node, and is guaranteed to
+/// remain available until it's handed to [`ChainHeadBackend::unpin()`]. Otherwise, the
+/// legacy RPC methods are used; in this case blocks are not pinned, and so historic blocks
+/// may be pruned by the node at any time.
+///
+/// [`crate::OnlineClient`] also uses one of these to fetch storage entries and block details
+/// (see [`crate::client::OnlineClientT::backend()`]). By default, that backend only uses the
+/// legacy RPC methods; see [`crate::OnlineClient::from_rpc_client_with_chain_head()`] to have
+/// it follow the chain using the new methods instead.
+pub struct ChainHeadBackend<T: Config> {
+    rpc: Rpc<T>,
+    follow: Option<Arc<Mutex<FollowState<T>>>>,
+}
+
+// The details we need to keep hold of when using the `chainHead_unstable_*` methods.
+struct FollowState<T: Config> {
+    // This changes if the node stops the subscription and we follow the chain again.
+    subscription_id: Arc<str>,
+    pinned: HashSet<T::Hash>,
+    best_block: Option<T::Hash>,
+    finalized_block: Option<T::Hash>,
+}
+
+impl<T: Config> FollowState<T> {
+    fn new(subscription_id: &str) -> Self {
+        FollowState {
+            subscription_id: subscription_id.into(),
+            pinned: HashSet::new(),
+            best_block: None,
+            finalized_block: None,
+        }
+    }
+
+    fn handle_event(&mut self, ev: &FollowEvent<T::Hash>) {
+        match ev {
+            FollowEvent::Initialized(ev) => {
+                self.pinned.insert(ev.finalized_block_hash);
+                self.best_block = Some(ev.finalized_block_hash);
+                self.finalized_block = Some(ev.finalized_block_hash);
+            }
+            FollowEvent::NewBlock(ev) => {
+                self.pinned.insert(ev.block_hash);
+            }
+            FollowEvent::BestBlockChanged(ev) => {
+                self.best_block = Some(ev.best_block_hash);
+            }
+            FollowEvent::Finalized(ev) => {
+                if let Some(hash) = ev.finalized_block_hashes.last() {
+                    self.finalized_block = Some(*hash);
+                }
+            }
+            // The node unpins everything when the subscription stops.
+            FollowEvent::Stop => {
+                self.pinned.clear();
+                self.best_block = None;
+                self.finalized_block = None;
+            }
+        }
+    }
+}
+
+impl<T: Config> Clone for ChainHeadBackend<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rpc: self.rpc.clone(),
+            follow: self.follow.clone(),
+        }
+    }
+}
+
+impl<T: Config> std::fmt::Debug for ChainHeadBackend<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChainHeadBackend")
+            .field("subscription_id", &self.subscription_id())
+            .finish()
+    }
+}
+
+impl<T: Config> ChainHeadBackend<T> {
+    /// Create a new [`ChainHeadBackend`], which will use the `chainHead_unstable_*` RPC
+    /// methods if the node reports that it supports them via `rpc_methods`, and fall back
+    /// to the legacy methods otherwise.
+    ///
+    /// Alongside the backend, a [`FollowSubscription`] is handed back, which reports the
+    /// blocks that the backend can be asked about. This should be polled continuously; the
+    /// node may stop the underlying subscription if events aren't being consumed, in which
+    /// case a [`FollowEvent::Stop`] is handed back and the chain is followed again.
+    pub async fn new(rpc: Rpc<T>) -> Result<(Self, FollowSubscription<T::Hash>), Error> {
+        if let Some((follow, events)) = follow_chain_head(&rpc).await? {
+            let backend = ChainHeadBackend {
+                rpc,
+                follow: Some(follow),
+            };
+            return Ok((backend, events))
+        }
+
+        let events = follow_legacy(&rpc).await?;
+        Ok((ChainHeadBackend::legacy(rpc), events))
+    }
+
+    /// Create a new [`ChainHeadBackend`] which only ever uses the legacy RPC methods.
+    pub fn legacy(rpc: Rpc<T>) -> Self {
+        ChainHeadBackend { rpc, follow: None }
+    }
+
+    /// Create a new [`ChainHeadBackend`] for use in [`crate::OnlineClient`], alongside a
+    /// [`ChainHeadFollower`] which must be driven to keep it up to date. If the
+    /// `chainHead_unstable_*` methods can't be used for any reason, the legacy methods are
+    /// used instead.
+    pub(crate) async fn following(rpc: Rpc<T>) -> (Self, ChainHeadFollower<T>) {
+        let (backend, events) = match follow_chain_head(&rpc).await {
+            Ok(Some((follow, events))) => {
+                let backend = ChainHeadBackend {
+                    rpc,
+                    follow: Some(follow),
+                };
+                (backend, Some(events))
+            }
+            Ok(None) => (ChainHeadBackend::legacy(rpc), None),
+            Err(e) => {
+                tracing::debug!("Falling back to the legacy RPC methods: {}", e);
+                (ChainHeadBackend::legacy(rpc), None)
+            }
+        };
+        let follower = ChainHeadFollower {
+            backend: backend.clone(),
+            events,
+        };
+        (backend, follower)
+    }
+
+    /// Is this backend using the `chainHead_unstable_*` RPC methods? If not, it's
+    /// falling back to the legacy methods.
+    pub fn is_chain_head(&self) -> bool {
+        self.follow.is_some()
+    }
+
+    /// The ID of the `chainHead_unstable_follow` subscription in use, if any. This changes
+    /// if the node stops the subscription and the chain is followed again.
+    pub fn subscription_id(&self) -> Option<String> {
+        self.chain_head_subscription().map(|id| id.to_string())
+    }
+
+    /// The hashes of the blocks which are currently pinned, and so will remain
+    /// available until they are unpinned. This is always empty when using the
+    /// legacy methods.
+    pub fn pinned_blocks(&self) -> Vec<T::Hash> {
+        match &self.follow {
+            Some(follow) => follow.lock().pinned.iter().copied().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn chain_head_subscription(&self) -> Option<Arc<str>> {
+        self.follow
+            .as_ref()
+            .map(|follow| follow.lock().subscription_id.clone())
+    }
+
+    /// Fetch the header of some block.
+    pub async fn header(&self, hash: T::Hash) -> Result<Option<T::Header>, Error> {
+        match self.chain_head_subscription() {
+            Some(subscription_id) => {
+                self.rpc
+                    .chainhead_unstable_header(&subscription_id, hash)
+                    .await
+            }
+            None => self.rpc.header(Some(hash)).await,
+        }
+    }
+
+    /// Fetch the SCALE encoded extrinsics in the body of some block. This returns
+    /// `None` if the legacy methods are in use and the block could not be found.
+    pub async fn block_body(&self, hash: T::Hash) -> Result<Option<Vec<Vec<u8>>>, Error> {
+        match self.chain_head_subscription() {
+            Some(subscription_id) => {
+                let sub = self
+                    .rpc
+                    .chainhead_unstable_body(&subscription_id, hash)
+                    .await?;
+                let body = wait_for_result(sub).await?;
+                Ok(Some(body.into_iter().map(|ext| ext.0).collect()))
+            }
+            None => {
+                let block = self.rpc.block(Some(hash)).await?;
+                Ok(block.map(|block| {
+                    block
+                        .block
+                        .extrinsics
+                        .into_iter()
+                        .map(|ext| ext.encode())
+                        .collect()
+                }))
+            }
+        }
+    }
+
+    /// Fetch the value of some storage entry at some block.
+    pub async fn storage(
+        &self,
+        hash: T::Hash,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, Error> {
+        match self.chain_head_subscription() {
+            Some(subscription_id) => {
+                let sub = self
+                    .rpc
+                    .chainhead_unstable_storage(&subscription_id, hash, key, None)
+                    .await?;
+                let value = wait_for_result(sub).await?;
+                Ok(value.map(|v| v.0))
+            }
+            None => {
+                let value = self.rpc.storage(key, Some(hash)).await?;
+                Ok(value.map(|v| v.0))
+            }
+        }
+    }
+
+    /// Call a runtime API function at some block, given the SCALE encoded parameters,
+    /// and hand back the SCALE encoded result.
+    pub async fn call(
+        &self,
+        hash: T::Hash,
+        function: &str,
+        call_parameters: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        match self.chain_head_subscription() {
+            Some(subscription_id) => {
+                let sub = self
+                    .rpc
+                    .chainhead_unstable_call(
+                        &subscription_id,
+                        hash,
+                        function,
+                        call_parameters,
+                    )
+                    .await?;
+                let res = wait_for_result(sub).await?;
+                Ok(res.0)
+            }
+            None => {
+                let call_parameters = Bytes::from(call_parameters.to_vec());
+                let params = rpc_params![function, call_parameters, hash];
+                let res: Bytes = self.rpc.request("state_call", params).await?;
+                Ok(res.0)
+            }
+        }
+    }
+
+    /// Unpin a block, allowing the node to discard it once it's no longer needed. Any
+    /// further requests about this block may fail. This does nothing when the legacy
+    /// methods are in use.
+    pub async fn unpin(&self, hash: T::Hash) -> Result<(), Error> {
+        if let Some(follow) = &self.follow {
+            let subscription_id = follow.lock().subscription_id.clone();
+            self.rpc
+                .chainhead_unstable_unpin(&subscription_id, hash)
+                .await?;
+            follow.lock().pinned.remove(&hash);
+        }
+        Ok(())
+    }
+
+    /// The hash of the block to use when none is given; the current best block.
+    pub(crate) async fn best_block_hash(&self) -> Result<T::Hash, Error> {
+        match self.pinned_block(None) {
+            Some(hash) => Ok(hash),
+            None => {
+                Ok(self
+                    .rpc
+                    .block_hash(None)
+                    .await?
+                    .expect("didn't pass a block number; qed"))
+            }
+        }
+    }
+
+    /// Fetch the header of some block (or of the current best block if none is given),
+    /// using the legacy methods if the block isn't pinned.
+    pub(crate) async fn header_at(
+        &self,
+        hash: Option<T::Hash>,
+    ) -> Result<Option<T::Header>, Error> {
+        match self.pinned_block(hash) {
+            Some(hash) => self.header(hash).await,
+            None => self.rpc.header(hash).await,
+        }
+    }
+
+    /// Fetch the SCALE encoded extrinsics in the body of some block, using the legacy
+    /// methods if the block isn't pinned.
+    pub(crate) async fn block_body_at(
+        &self,
+        hash: T::Hash,
+    ) -> Result<Option<Vec<Vec<u8>>>, Error> {
+        match self.pinned_block(Some(hash)) {
+            Some(hash) => self.block_body(hash).await,
+            None => {
+                ChainHeadBackend::legacy(self.rpc.clone())
+                    .block_body(hash)
+                    .await
+            }
+        }
+    }
+
+    /// Fetch the value of some storage entry at some block (or at the current best block
+    /// if none is given), using the legacy methods if the block isn't pinned.
+    pub(crate) async fn storage_at(
+        &self,
+        key: &[u8],
+        hash: Option<T::Hash>,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        match self.pinned_block(hash) {
+            Some(hash) => self.storage(hash, key).await,
+            None => {
+                let value = self.rpc.storage(key, hash).await?;
+                Ok(value.map(|v| v.0))
+            }
+        }
+    }
+
+    // If the `chainHead_unstable_*` methods can be used to ask about the given block (or the
+    // current best block, if none is given), hand back its hash.
+    fn pinned_block(&self, hash: Option<T::Hash>) -> Option<T::Hash> {
+        let follow = self.follow.as_ref()?.lock();
+        match hash {
+            Some(hash) => follow.pinned.contains(&hash).then_some(hash),
+            None => follow.best_block,
+        }
+    }
+}
+
+/// Keeps the [`ChainHeadBackend`] used by a [`crate::OnlineClient`] up to date, obtained via
+/// [`crate::OnlineClient::from_rpc_client_with_chain_head()`].
+///
+/// [`ChainHeadFollower::follow()`] should be run in a separate task for as long as the client
+/// is in use. It hands each new block to the backend as the node reports it, and unpins blocks
+/// once they've been pruned or a later block has been finalized. If this isn't driven, the
+/// node will keep every new block pinned, and is likely to stop following the chain.
+pub struct ChainHeadFollower<T: Config> {
+    backend: ChainHeadBackend<T>,
+    // `None` if the legacy methods are in use, in which case there's nothing to follow.
+    events: Option<FollowSubscription<T::Hash>>,
+}
+
+impl<T: Config> std::fmt::Debug for ChainHeadFollower<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChainHeadFollower")
+            .field("backend", &self.backend)
+            .finish()
+    }
+}
+
+impl<T: Config> ChainHeadFollower<T> {
+    /// Follow the chain until the node stops reporting new blocks. This returns
+    /// immediately if the legacy RPC methods are in use.
+    pub async fn follow(self) {
+        let ChainHeadFollower { backend, events } = self;
+        let (mut events, follow) = match (events, &backend.follow) {
+            (Some(events), Some(follow)) => (events, follow),
+            _ => return,
+        };
+
+        loop {
+            // The follow state is updated as each event is handed back, so note which
+            // block was finalized before that happens.
+            let last_finalized = follow.lock().finalized_block;
+            let ev = match events.next().await {
+                Some(Ok(FollowEvent::Finalized(ev))) => ev,
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => {
+                    tracing::warn!("Error following the chain: {}", e);
+                    continue
+                }
+                None => return,
+            };
+
+            // Once a later block has been finalized, we no longer need older finalized
+            // blocks, and nobody needs the blocks that have been pruned.
+            let finalized = ev.finalized_block_hashes.last().copied();
+            let to_unpin = last_finalized
+                .into_iter()
+                .chain(ev.finalized_block_hashes)
+                .chain(ev.pruned_block_hashes)
+                .filter(|hash| Some(*hash) != finalized);
+
+            let unpins = to_unpin.map(|hash| backend.unpin(hash));
+            for res in future::join_all(unpins).await {
+                if let Err(e) = res {
+                    tracing::debug!("Failed to unpin block: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// A subscription to the blocks that a [`ChainHeadBackend`] knows about, obtained
+/// via [`ChainHeadBackend::new()`]. This implements [`Stream`].
+///
+/// When the legacy RPC methods are in use, the events handed back are built from the
+/// new and finalized block subscriptions, and no blocks are reported as pruned.
+pub struct FollowSubscription<H> {
+    inner: BoxStream<'static, Result<FollowEvent<H>, Error>>,
+}
+
+impl<H> std::fmt::Debug for FollowSubscription<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FollowSubscription").finish()
+    }
+}
+
+impl<H> Stream for FollowSubscription<H> {
+    type Item = Result<FollowEvent<H>, Error>;
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+// Follow the chain using the `chainHead_unstable_follow` subscription if the node supports
+// it, keeping track of the blocks pinned by the node as the events come in. If the node
+// stops the subscription, we follow the chain again (and hand back the new events).
+async fn follow_chain_head<T: Config>(
+    rpc: &Rpc<T>,
+) -> Result<Option<(Arc<Mutex<FollowState<T>>>, FollowSubscription<T::Hash>)>, Error> {
+    // Any error here (for instance, because `rpc_methods` isn't available)
+    // is taken to mean that the node doesn't support the new methods.
+    let supported = match rpc.rpc_methods().await {
+        Ok(methods) => methods.methods.iter().any(|m| m == CHAIN_HEAD_FOLLOW),
+        Err(_) => false,
+    };
+    if !supported {
+        return Ok(None)
+    }
+
+    let subscription = rpc.chainhead_unstable_follow(false).await?;
+    // We can't make use of the subscription without its ID; our RPC client
+    // might not be able to hand it back, in which case we fall back.
+    let state = match subscription.subscription_id() {
+        Some(id) => Arc::new(Mutex::new(FollowState::<T>::new(id))),
+        None => return Ok(None),
+    };
+
+    let rpc = rpc.clone();
+    let follow = state.clone();
+    let inner = stream::unfold(Some(subscription), move |subscription| {
+        let rpc = rpc.clone();
+        let follow = follow.clone();
+        async move {
+            let mut subscription = subscription?;
+            let ev = match subscription.next().await {
+                Some(Ok(ev)) => ev,
+                Some(Err(e)) => return Some((Err(e), Some(subscription))),
+                // Nothing is pinned once the subscription has gone away.
+                None => {
+                    follow.lock().handle_event(&FollowEvent::Stop);
+                    return None
+                }
+            };
+            follow.lock().handle_event(&ev);
+            if !matches!(ev, FollowEvent::Stop) {
+                return Some((Ok(ev), Some(subscription)))
+            }
+
+            // The node has stopped the subscription, so follow the chain again.
+            let subscription = match rpc.chainhead_unstable_follow(false).await {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    tracing::warn!("Failed to follow the chain again: {}", e);
+                    return Some((Ok(ev), None))
+                }
+            };
+            match subscription.subscription_id() {
+                Some(id) => {
+                    follow.lock().subscription_id = id.into();
+                    Some((Ok(ev), Some(subscription)))
+                }
+                None => Some((Ok(ev), None)),
+            }
+        }
+    })
+    .fuse()
+    .boxed();
+
+    Ok(Some((state, FollowSubscription { inner })))
+}
+
+// Build the same sort of events from the legacy block subscriptions.
+async fn follow_legacy<T: Config>(
+    rpc: &Rpc<T>,
+) -> Result<FollowSubscription<T::Hash>, Error> {
+    // Subscribe first, so that we don't miss anything between
+    // fetching the finalized block and the subscriptions starting.
+    let best = rpc.subscribe_blocks().await?;
+    let finalized = rpc.subscribe_finalized_blocks().await?;
+    let finalized_hash = rpc.finalized_head().await?;
+
+    let initialized = FollowEvent::Initialized(Initialized {
+        finalized_block_hash: finalized_hash,
+        finalized_block_runtime: None,
+    });
+
+    let best = best.map(move |res| {
+        res.map(|header| {
+            let block_hash = header.hash();
+            // We've already reported the finalized block that we started from.
+            if block_hash == finalized_hash {
+                return Vec::new()
+            }
+            vec![
+                FollowEvent::NewBlock(NewBlock {
+                    block_hash,
+                    parent_block_hash: *header.parent_hash(),
+                    new_runtime: None,
+                }),
+                FollowEvent::BestBlockChanged(BestBlockChanged {
+                    best_block_hash: block_hash,
+                }),
+            ]
+        })
+    });
+    let finalized = finalized.map(move |res| {
+        res.map(|header| {
+            let block_hash = header.hash();
+            if block_hash == finalized_hash {
+                return Vec::new()
+            }
+            vec![FollowEvent::Finalized(Finalized {
+                finalized_block_hashes: vec![block_hash],
+                pruned_block_hashes: Vec::new(),
+            })]
+        })
+    });
+
+    let events = stream::select(best, finalized).flat_map(|res| {
+        let events: Vec<_> = match res {
+            Ok(events) => events.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)],
+        };
+        stream::iter(events)
+    });
+    let inner = stream::once(async move { Ok(initialized) })
+        .chain(events)
+        .boxed();
+    Ok(FollowSubscription { inner })
+}
+
+// Each of the `chainHead_unstable_*` operations hands back exactly one event.
+async fn wait_for_result<R: DeserializeOwned>(
+    mut sub: Subscription<ChainHeadEvent<R>>,
+) -> Result<R, Error> {
+    let ev = sub.next().await.ok_or(RpcError::SubscriptionDropped)??;
+    let err = match ev {
+        ChainHeadEvent::Done(res) => return Ok(res.result),
+        ChainHeadEvent::Inaccessible(e) => format!("inaccessible: {}", e.error),
+        ChainHeadEvent::Error(e) => e.error,
+        ChainHeadEvent::Disjoint => "the follow subscription has stopped".to_owned(),
+    };
+    Err(RpcError::ChainHeadOperationFailed(err).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        client::OnlineClientT,
+        rpc::MockNode,
+        PolkadotConfig,
+    };
+    use sp_core::H256;
+
+    fn node() -> MockNode<PolkadotConfig> {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        MockNode::new(metadata.to_vec())
+    }
+
+    async fn next_event(events: &mut FollowSubscription<H256>) -> FollowEvent<H256> {
+        events
+            .next()
+            .await
+            .expect("subscription should not end")
+            .expect("event should be valid")
+    }
+
+    #[tokio::test]
+    async fn uses_chain_head_when_supported() {
+        let node = node();
+        let genesis = node.block_hash(0).unwrap();
+        let (backend, mut events) =
+            ChainHeadBackend::<PolkadotConfig>::new(Rpc::new(Arc::new(node.clone())))
+                .await
+                .unwrap();
+        assert!(backend.is_chain_head());
+        assert!(matches!(
+            next_event(&mut events).await,
+            FollowEvent::Initialized(ev) if ev.finalized_block_hash == genesis
+        ));
+
+        node.set_storage(b"key".to_vec(), b"value".to_vec());
+        let hash = node.produce_block();
+        assert_eq!(
+            next_event(&mut events).await,
+            FollowEvent::NewBlock(NewBlock {
+                block_hash: hash,
+                parent_block_hash: genesis,
+                new_runtime: None,
+            })
+        );
+        assert!(matches!(
+            next_event(&mut events).await,
+            FollowEvent::BestBlockChanged(ev) if ev.best_block_hash == hash
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            FollowEvent::Finalized(ev) if ev.finalized_block_hashes == vec![hash]
+        ));
+
+        let mut pinned = backend.pinned_blocks();
+        pinned.sort();
+        let mut expected = vec![genesis, hash];
+        expected.sort();
+        assert_eq!(pinned, expected);
+
+        let header = backend.header(hash).await.unwrap().unwrap();
+        assert_eq!(header.parent_hash, genesis);
+        assert_eq!(backend.block_body(hash).await.unwrap(), Some(Vec::new()));
+        assert_eq!(
+            backend.storage(hash, b"key").await.unwrap(),
+            Some(b"value".to_vec())
+        );
+        assert_eq!(backend.storage(genesis, b"key").await.unwrap(), None);
+
+        // Once unpinned, the node no longer hands back details about the block.
+        backend.unpin(genesis).await.unwrap();
+        assert_eq!(backend.pinned_blocks(), vec![hash]);
+        assert_eq!(node.pinned_blocks(), vec![hash]);
+        assert!(matches!(
+            backend.storage(genesis, b"key").await,
+            Err(Error::Rpc(RpcError::ChainHeadOperationFailed(_)))
+        ));
+        assert!(backend.header(genesis).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn uses_chain_head_through_a_reconnecting_client() {
+        use crate::rpc::{
+            Backoff,
+            ReconnectingRpcClient,
+        };
+
+        let node = node();
+        let genesis = node.block_hash(0).unwrap();
+        let connect_to = node.clone();
+        let client = ReconnectingRpcClient::new(Backoff::new(), move || {
+            let node = connect_to.clone();
+            async move { Ok(node) }
+        })
+        .await
+        .unwrap();
+
+        // The subscription ID makes it through the reconnecting client, so the new
+        // methods can be used.
+        let (backend, mut events) =
+            ChainHeadBackend::<PolkadotConfig>::new(Rpc::new(Arc::new(client)))
+                .await
+                .unwrap();
+        assert!(backend.is_chain_head());
+        assert!(matches!(
+            next_event(&mut events).await,
+            FollowEvent::Initialized(ev) if ev.finalized_block_hash == genesis
+        ));
+        assert_eq!(node.pinned_blocks(), vec![genesis]);
+
+        backend.unpin(genesis).await.unwrap();
+        assert!(node.pinned_blocks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_legacy_methods() {
+        let node = node();
+        node.set_chain_head_enabled(false);
+        let genesis = node.block_hash(0).unwrap();
+        let (backend, mut events) =
+            ChainHeadBackend::<PolkadotConfig>::new(Rpc::new(Arc::new(node.clone())))
+                .await
+                .unwrap();
+        assert!(!backend.is_chain_head());
+        assert!(matches!(
+            next_event(&mut events).await,
+            FollowEvent::Initialized(ev) if ev.finalized_block_hash == genesis
+        ));
+
+        node.set_storage(b"key".to_vec(), b"value".to_vec());
+        let hash = node.produce_block();
+
+        // The new and finalized heads arrive via separate subscriptions, and so
+        // may be interleaved in either order.
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            seen.push(next_event(&mut events).await);
+        }
+        assert!(seen.contains(&FollowEvent::NewBlock(NewBlock {
+            block_hash: hash,
+            parent_block_hash: genesis,
+            new_runtime: None,
+        })));
+        assert!(seen.contains(&FollowEvent::Finalized(Finalized {
+            finalized_block_hashes: vec![hash],
+            pruned_block_hashes: Vec::new(),
+        })));
+
+        assert!(backend.pinned_blocks().is_empty());
+        assert_eq!(
+            backend.storage(hash, b"key").await.unwrap(),
+            Some(b"value".to_vec())
+        );
+        assert_eq!(backend.block_body(hash).await.unwrap(), Some(Vec::new()));
+        // Unpinning does nothing, and historic blocks remain available.
+        backend.unpin(genesis).await.unwrap();
+        assert!(backend.header(genesis).await.unwrap().is_some());
+        assert!(node.pinned_blocks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn follows_the_chain_again_after_it_stops() {
+        let node = node();
+        let (backend, mut events) =
+            ChainHeadBackend::<PolkadotConfig>::new(Rpc::new(Arc::new(node.clone())))
+                .await
+                .unwrap();
+        assert!(matches!(
+            next_event(&mut events).await,
+            FollowEvent::Initialized(_)
+        ));
+        let first_id = backend.subscription_id().unwrap();
+
+        let hash = node.produce_block();
+        node.stop_chain_head_subscriptions();
+        for _ in 0..3 {
+            next_event(&mut events).await;
+        }
+        assert_eq!(next_event(&mut events).await, FollowEvent::Stop);
+
+        // The chain is followed again from the latest finalized block, using a new
+        // subscription which the backend now makes use of.
+        assert!(matches!(
+            next_event(&mut events).await,
+            FollowEvent::Initialized(ev) if ev.finalized_block_hash == hash
+        ));
+        assert_ne!(backend.subscription_id().unwrap(), first_id);
+        assert_eq!(backend.pinned_blocks(), vec![hash]);
+        assert!(backend.header(hash).await.unwrap().is_some());
+    }
+
+    // Build an `OnlineClient` which follows the chain, running the follower in the background.
+    async fn online_client(
+        node: &MockNode<PolkadotConfig>,
+    ) -> crate::OnlineClient<PolkadotConfig> {
+        let (client, follower) =
+            crate::OnlineClient::from_rpc_client_with_chain_head(Arc::new(node.clone()))
+                .await
+                .unwrap();
+        tokio::spawn(follower.follow());
+        client
+    }
+
+    // The follower runs in the background, so give it a moment to catch up.
+    async fn wait_until(mut f: impl FnMut() -> bool) {
+        for _ in 0..100 {
+            if f() {
+                return
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("condition was not met in time");
+    }
+
+    #[tokio::test]
+    async fn online_client_uses_legacy_methods_by_default() {
+        let node = node();
+        let client = crate::OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(
+            node.clone(),
+        ))
+        .await
+        .unwrap();
+        assert!(!client.backend().is_chain_head());
+        assert!(node.pinned_blocks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn online_client_uses_chain_head_when_supported() {
+        let node = node();
+        let genesis = node.block_hash(0).unwrap();
+        let client = online_client(&node).await;
+        assert!(client.backend().is_chain_head());
+        assert_eq!(node.pinned_blocks(), vec![genesis]);
+
+        // Once the new block is finalized, the follower unpins the previous one
+        // without waiting for the client to be used.
+        node.set_storage(b"key".to_vec(), b"value".to_vec());
+        let hash = node.produce_block();
+        wait_until(|| node.pinned_blocks() == vec![hash]).await;
+        assert_eq!(client.backend().pinned_blocks(), vec![hash]);
+
+        // The new best block is used by default.
+        let value = client.storage().fetch_raw(b"key", None).await.unwrap();
+        assert_eq!(value, Some(b"value".to_vec()));
+        let block = client.blocks().at(None).await.unwrap();
+        assert_eq!(block.hash(), hash);
+        assert!(block.extrinsics().unwrap().is_empty());
+
+        // Blocks which aren't pinned are fetched using the legacy methods.
+        let value = client
+            .storage()
+            .fetch_raw(b"key", Some(genesis))
+            .await
+            .unwrap();
+        assert_eq!(value, None);
+        let block = client.blocks().at(Some(genesis)).await.unwrap();
+        assert_eq!(block.header().number, 0);
+    }
+
+    #[tokio::test]
+    async fn online_client_follows_the_chain_again_after_it_stops() {
+        let node = node();
+        let client = online_client(&node).await;
+        let first_id = client.backend().subscription_id().unwrap();
+
+        node.stop_chain_head_subscriptions();
+        wait_until(|| {
+            client
+                .backend()
+                .subscription_id()
+                .is_some_and(|id| id != first_id)
+        })
+        .await;
+
+        node.set_storage(b"key".to_vec(), b"value".to_vec());
+        let hash = node.produce_block();
+        wait_until(|| node.pinned_blocks() == vec![hash]).await;
+        assert_eq!(client.backend().pinned_blocks(), vec![hash]);
+        let value = client.storage().fetch_raw(b"key", None).await.unwrap();
+        assert_eq!(value, Some(b"value".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn online_client_falls_back_to_legacy_methods() {
+        let node = node();
+        node.set_chain_head_enabled(false);
+        let client = online_client(&node).await;
+        assert!(!client.backend().is_chain_head());
+
+        node.set_storage(b"key".to_vec(), b"value".to_vec());
+        let hash = node.produce_block();
+        let value = client.storage().fetch_raw(b"key", None).await.unwrap();
+        assert_eq!(value, Some(b"value".to_vec()));
+        let block = client.blocks().at(None).await.unwrap();
+        assert_eq!(block.hash(), hash);
+        assert!(node.pinned_blocks().is_empty());
+    }
+}
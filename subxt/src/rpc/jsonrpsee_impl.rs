@@ -0,0 +1,247 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{
+    RpcClientT,
+    RpcFuture,
+    RpcSubscription,
+};
+use crate::error::RpcError;
+use jsonrpsee::{
+    core::{
+        client::ClientT,
+        Error as JsonRpseeError,
+    },
+    types::ParamsSer,
+};
+use serde_json::value::{
+    RawValue,
+    Value,
+};
+
+#[cfg(feature = "jsonrpsee-ws")]
+use futures::stream::{
+    StreamExt,
+    TryStreamExt,
+};
+#[cfg(feature = "jsonrpsee-http")]
+use jsonrpsee::http_client::{
+    HttpClient,
+    HttpClientBuilder,
+};
+#[cfg(feature = "jsonrpsee-ws")]
+use jsonrpsee::{
+    client_transport::ws::{
+        InvalidUri,
+        Receiver,
+        Sender,
+        Uri,
+        WsTransportClientBuilder,
+    },
+    core::client::{
+        Client,
+        ClientBuilder,
+        SubscriptionClientT,
+        SubscriptionKind,
+    },
+    types::SubscriptionId,
+};
+
+#[cfg(feature = "jsonrpsee-ws")]
+impl RpcClientT for Client {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        request_raw(self, method, params)
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        batch: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RpcFuture<'a, Vec<Box<RawValue>>> {
+        batch_request_raw(self, batch)
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(async move {
+            let (_, sub) = self.subscribe_raw_with_id(sub, params, unsub).await?;
+            Ok(sub)
+        })
+    }
+
+    fn subscribe_raw_with_id<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, (Option<String>, RpcSubscription)> {
+        Box::pin(async move {
+            let params = prep_params_for_jsonrpsee(params)?;
+            let sub = SubscriptionClientT::subscribe::<Value>(self, sub, params, unsub)
+                .await
+                .map_err(to_rpc_error)?;
+            let id = match sub.kind() {
+                SubscriptionKind::Subscription(SubscriptionId::Str(id)) => {
+                    Some(id.to_string())
+                }
+                SubscriptionKind::Subscription(SubscriptionId::Num(id)) => {
+                    Some(id.to_string())
+                }
+                _ => None,
+            };
+            let sub = sub
+                .map_err(to_rpc_error)
+                .map(|res| res.and_then(|v| to_raw_value(&v)))
+                .boxed();
+            Ok((id, sub))
+        })
+    }
+}
+
+#[cfg(feature = "jsonrpsee-http")]
+impl RpcClientT for HttpClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        request_raw(self, method, params)
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        batch: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RpcFuture<'a, Vec<Box<RawValue>>> {
+        batch_request_raw(self, batch)
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        _params: Option<Box<RawValue>>,
+        _unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        // HTTP is request/response only, so we have no way to receive notifications.
+        Box::pin(async move { Err(RpcError::SubscriptionsNotSupported(sub.to_owned())) })
+    }
+}
+
+/// Build a WebSocket [`Client`] from a URL. This client implements
+/// [`RpcClientT`], and so can be handed to [`crate::OnlineClient::from_rpc_client()`].
+#[cfg(feature = "jsonrpsee-ws")]
+pub async fn ws_client(url: &str) -> Result<Client, RpcError> {
+    let (sender, receiver) = ws_transport(url).await?;
+    Ok(ClientBuilder::default()
+        .max_notifs_per_subscription(4096)
+        .build_with_tokio(sender, receiver))
+}
+
+#[cfg(feature = "jsonrpsee-ws")]
+async fn ws_transport(url: &str) -> Result<(Sender, Receiver), RpcError> {
+    let url: Uri = url.parse().map_err(|e: InvalidUri| {
+        RpcError::ClientError(Box::new(JsonRpseeError::Transport(e.into())))
+    })?;
+    WsTransportClientBuilder::default()
+        .build(url)
+        .await
+        .map_err(|e| RpcError::ClientError(Box::new(JsonRpseeError::Transport(e.into()))))
+}
+
+/// Build an HTTP [`HttpClient`] from a URL. This client implements [`RpcClientT`],
+/// and so can be handed to [`crate::OnlineClient::from_rpc_client()`]. Subscriptions
+/// are not supported over HTTP, and will return [`RpcError::SubscriptionsNotSupported`].
+#[cfg(feature = "jsonrpsee-http")]
+pub fn http_client(url: &str) -> Result<HttpClient, RpcError> {
+    HttpClientBuilder::default()
+        .build(url)
+        .map_err(|e| RpcError::ClientError(Box::new(e)))
+}
+
+fn request_raw<'a, C: ClientT + Send + Sync>(
+    client: &'a C,
+    method: &'a str,
+    params: Option<Box<RawValue>>,
+) -> RpcFuture<'a, Box<RawValue>> {
+    Box::pin(async move {
+        let params = prep_params_for_jsonrpsee(params)?;
+        let res: Value = client.request(method, params).await.map_err(to_rpc_error)?;
+        to_raw_value(&res)
+    })
+}
+
+fn batch_request_raw<'a, C: ClientT + Send + Sync>(
+    client: &'a C,
+    batch: Vec<(&'a str, Option<Box<RawValue>>)>,
+) -> RpcFuture<'a, Vec<Box<RawValue>>> {
+    Box::pin(async move {
+        let batch = batch
+            .into_iter()
+            .map(|(method, params)| Ok((method, prep_params_for_jsonrpsee(params)?)))
+            .collect::<Result<Vec<_>, RpcError>>()?;
+        let res: Vec<Value> = client.batch_request(batch).await.map_err(to_rpc_error)?;
+        res.iter().map(to_raw_value).collect()
+    })
+}
+
+// This is ugly; we have to encode to Value's to be compat with the jsonrpc interface.
+// Remove and simplify this once something like https://github.com/paritytech/jsonrpsee/issues/862 is in:
+fn prep_params_for_jsonrpsee(
+    params: Option<Box<RawValue>>,
+) -> Result<Option<ParamsSer<'static>>, RpcError> {
+    let params = match params {
+        Some(params) => params,
+        None => return Ok(None),
+    };
+    let val = serde_json::from_str(params.get())
+        .map_err(|e| RpcError::ClientError(Box::new(e)))?;
+    let arr = match val {
+        Value::Array(arr) => Ok(arr),
+        _ => {
+            Err(RpcError::ClientError(
+                format!("RPC Params are expected to be an array but got {params}").into(),
+            ))
+        }
+    }?;
+    Ok(Some(ParamsSer::Array(arr)))
+}
+
+// A terminated background task means that the connection has gone away, which
+// a reconnecting client needs to be able to tell apart from other errors.
+fn to_rpc_error(err: JsonRpseeError) -> RpcError {
+    match err {
+        JsonRpseeError::RestartNeeded(reason) => RpcError::ConnectionLost(reason),
+        err => RpcError::ClientError(Box::new(err)),
+    }
+}
+
+fn to_raw_value(val: &Value) -> Result<Box<RawValue>, RpcError> {
+    serde_json::value::to_raw_value(val).map_err(|e| RpcError::ClientError(Box::new(e)))
+}
+
+#[cfg(all(test, feature = "jsonrpsee-http"))]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn http_client_rejects_subscriptions() {
+        // Nothing is listening here, but we never get as far as making a request.
+        let client = http_client("http://127.0.0.1:9933").unwrap();
+        let err = client
+            .subscribe_raw("chain_subscribeNewHeads", None, "chain_unsubscribeNewHeads")
+            .await
+            .err()
+            .expect("subscriptions should not be supported over HTTP");
+        assert!(matches!(
+            err,
+            RpcError::SubscriptionsNotSupported(sub) if sub == "chain_subscribeNewHeads"
+        ));
+    }
+}
@@ -0,0 +1,948 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{
+    InclusionFee,
+    NumberOrHex,
+    RawValue,
+    RpcClientT,
+    RpcFuture,
+    RpcSubscription,
+    Weight,
+};
+use crate::{
+    error::RpcError,
+    Config,
+};
+use codec::{
+    Compact,
+    Decode,
+    Encode,
+};
+use futures::{
+    channel::mpsc,
+    StreamExt,
+};
+use parking_lot::Mutex;
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use sp_core::Bytes;
+use sp_runtime::traits::{
+    Hash,
+    Header,
+    One,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        HashSet,
+    },
+    sync::Arc,
+};
+
+/// An in-memory node which implements [`RpcClientT`], and so can be handed to
+/// [`crate::OnlineClient::from_rpc_client()`] in order to exercise `subxt` without
+/// a real node to talk to.
+///
+/// The node starts off with a genesis block containing no storage. Storage changes and
+/// submitted extrinsics are held in a pending state until [`MockNode::produce_block()`]
+/// is called, at which point they are included in a new block, which is immediately
+/// finalized. Every block keeps its own snapshot of storage, so historic queries work as
+/// expected.
+///
+/// The following RPC methods are served:
+///
+/// - `state_getMetadata`, `state_getRuntimeVersion`, `state_getStorage`,
+///   `state_getKeysPaged` and `state_queryStorageAt`.
+/// - `chain_getBlockHash`, `chain_getHeader`, `chain_getBlock` and `chain_getFinalizedHead`.
+/// - `system_accountNextIndex` (see [`MockNode::set_account_nonce()`]).
+/// - `payment_queryInfo` and `payment_queryFeeDetails` (see [`MockNode::set_fees()`]).
+/// - `author_submitExtrinsic` and `author_submitAndWatchExtrinsic`.
+/// - `chain_subscribeNewHeads`, `chain_subscribeAllHeads`, `chain_subscribeFinalizedHeads`,
+///   `state_subscribeRuntimeVersion` and `state_subscribeStorage`.
+/// - `rpc_methods`, and `chainHead_unstable_follow`, `_header`, `_body`, `_storage`,
+///   `_call` and `_unpin` (see [`MockNode::set_chain_head_enabled()`]). Runtime calls
+///   always fail, since the mock node has no runtime to call into.
+///
+/// Extrinsics are not executed; if you'd like some events to be emitted in a block, set the
+/// `System.Events` storage entry before producing it.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use subxt::{
+///     rpc::MockNode,
+///     OnlineClient,
+///     PolkadotConfig,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let metadata = std::fs::read("../artifacts/polkadot_metadata.scale").unwrap();
+/// let node = MockNode::<PolkadotConfig>::new(metadata);
+///
+/// // Put a value in storage, and produce a block containing it.
+/// node.set_storage(b"some_key".to_vec(), vec![1, 2, 3]);
+/// node.produce_block();
+///
+/// let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+///     .await
+///     .unwrap();
+///
+/// let value = api.storage().fetch_raw(b"some_key", None).await.unwrap();
+/// assert_eq!(value, Some(vec![1, 2, 3]));
+/// # }
+/// ```
+pub struct MockNode<T: Config> {
+    inner: Arc<Mutex<MockState<T>>>,
+}
+
+impl<T: Config> Clone for MockNode<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Config> std::fmt::Debug for MockNode<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.inner.lock();
+        f.debug_struct("MockNode")
+            .field("blocks", &state.blocks.len())
+            .field("pending_extrinsics", &state.pending_extrinsics.len())
+            .finish()
+    }
+}
+
+struct MockState<T: Config> {
+    metadata: Vec<u8>,
+    spec_version: u32,
+    transaction_version: u32,
+    nonces: HashMap<String, u64>,
+    weight: Weight,
+    fee: InclusionFee,
+    blocks: Vec<MockBlock<T>>,
+    block_numbers: HashMap<T::Hash, usize>,
+    pending_storage: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    pending_extrinsics: Vec<PendingExtrinsic>,
+    new_heads_subscribers: Vec<mpsc::UnboundedSender<Value>>,
+    finalized_heads_subscribers: Vec<mpsc::UnboundedSender<Value>>,
+    runtime_version_subscribers: Vec<mpsc::UnboundedSender<Value>>,
+    storage_subscribers: Vec<StorageSubscriber>,
+    chain_head_enabled: bool,
+    next_subscription_id: u64,
+    follow_subscriptions: HashMap<String, FollowSubscription<T>>,
+}
+
+// A `chainHead_unstable_follow` subscription, and the blocks pinned by it.
+struct FollowSubscription<T: Config> {
+    tx: mpsc::UnboundedSender<Value>,
+    pinned: HashSet<T::Hash>,
+}
+
+// A `state_subscribeStorage` subscription, and the keys that it's interested in.
+struct StorageSubscriber {
+    keys: Vec<Vec<u8>>,
+    tx: mpsc::UnboundedSender<Value>,
+}
+
+struct MockBlock<T: Config> {
+    hash: T::Hash,
+    header: T::Header,
+    extrinsics: Vec<Vec<u8>>,
+    storage: Arc<BTreeMap<Vec<u8>, Vec<u8>>>,
+}
+
+struct PendingExtrinsic {
+    bytes: Vec<u8>,
+    watcher: Option<mpsc::UnboundedSender<Value>>,
+}
+
+impl<T: Config> MockNode<T> {
+    /// Create a new [`MockNode`], given the SCALE encoded metadata that it should hand back.
+    /// This is the same format as is returned from `state_getMetadata`, or saved to disk by
+    /// `subxt metadata`.
+    pub fn new(metadata: Vec<u8>) -> Self {
+        let genesis = Self::build_block(
+            Default::default(),
+            Default::default(),
+            Vec::new(),
+            Default::default(),
+        );
+        let mut block_numbers = HashMap::new();
+        block_numbers.insert(genesis.hash, 0);
+
+        MockNode {
+            inner: Arc::new(Mutex::new(MockState {
+                metadata,
+                spec_version: 0,
+                transaction_version: 0,
+                nonces: HashMap::new(),
+                weight: Weight::default(),
+                fee: InclusionFee {
+                    base_fee: 0,
+                    len_fee: 0,
+                    adjusted_weight_fee: 0,
+                },
+                blocks: vec![genesis],
+                block_numbers,
+                pending_storage: BTreeMap::new(),
+                pending_extrinsics: Vec::new(),
+                new_heads_subscribers: Vec::new(),
+                finalized_heads_subscribers: Vec::new(),
+                runtime_version_subscribers: Vec::new(),
+                storage_subscribers: Vec::new(),
+                chain_head_enabled: true,
+                next_subscription_id: 0,
+                follow_subscriptions: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Set the runtime version reported by the node, notifying any subscribers.
+    pub fn set_runtime_version(&self, spec_version: u32, transaction_version: u32) {
+        let mut state = self.inner.lock();
+        state.spec_version = spec_version;
+        state.transaction_version = transaction_version;
+        let version = state.runtime_version();
+        notify(&mut state.runtime_version_subscribers, &version);
+    }
+
+    /// Set the SCALE encoded metadata that will be handed back from `state_getMetadata`.
+    pub fn set_metadata(&self, metadata: Vec<u8>) {
+        self.inner.lock().metadata = metadata;
+    }
+
+    /// Set the value of some storage entry. This takes effect in the next block produced.
+    pub fn set_storage(&self, key: Vec<u8>, value: Vec<u8>) {
+        self.inner.lock().pending_storage.insert(key, Some(value));
+    }
+
+    /// Remove some storage entry. This takes effect in the next block produced.
+    pub fn remove_storage(&self, key: Vec<u8>) {
+        self.inner.lock().pending_storage.insert(key, None);
+    }
+
+    /// Set the nonce that will be returned from `system_accountNextIndex` for
+    /// the given account. This is `0` for any account that it hasn't been set for.
+    pub fn set_account_nonce(&self, account: &T::AccountId, nonce: u64) {
+        let key = account_key(account);
+        self.inner.lock().nonces.insert(key, nonce);
+    }
+
+    /// Set the weight and fee that `payment_queryInfo` and `payment_queryFeeDetails` will
+    /// report for every signed extrinsic. Unsigned extrinsics pay no fee. These are all
+    /// `0` by default.
+    pub fn set_fees(&self, weight: Weight, fee: InclusionFee) {
+        let mut state = self.inner.lock();
+        state.weight = weight;
+        state.fee = fee;
+    }
+
+    /// The SCALE encoded extrinsics which have been submitted to the node, but
+    /// not yet included in a block.
+    pub fn pending_extrinsics(&self) -> Vec<Vec<u8>> {
+        self.inner
+            .lock()
+            .pending_extrinsics
+            .iter()
+            .map(|ext| ext.bytes.clone())
+            .collect()
+    }
+
+    /// Enable or disable support for the `chainHead_unstable_*` RPC methods. These are
+    /// enabled by default. When disabled, they are no longer reported from `rpc_methods`,
+    /// and calling them will return an error.
+    pub fn set_chain_head_enabled(&self, enabled: bool) {
+        self.inner.lock().chain_head_enabled = enabled;
+    }
+
+    /// The blocks which are currently pinned by any `chainHead_unstable_follow`
+    /// subscription.
+    pub fn pinned_blocks(&self) -> Vec<T::Hash> {
+        let state = self.inner.lock();
+        let pinned: HashSet<_> = state
+            .follow_subscriptions
+            .values()
+            .flat_map(|sub| sub.pinned.iter().copied())
+            .collect();
+        pinned.into_iter().collect()
+    }
+
+    /// Stop every `chainHead_unstable_follow` subscription, as a node might if it can't
+    /// keep up. Each is sent a `stop` event, and everything that it pinned is unpinned.
+    pub fn stop_chain_head_subscriptions(&self) {
+        let mut state = self.inner.lock();
+        for (_, sub) in state.follow_subscriptions.drain() {
+            // Nobody may be listening any more, which is fine.
+            let _ = sub.tx.unbounded_send(json!({ "event": "stop" }));
+        }
+    }
+
+    /// Return the hash of the block with the given number, if it exists.
+    pub fn block_hash(&self, number: u64) -> Option<T::Hash> {
+        let state = self.inner.lock();
+        usize::try_from(number)
+            .ok()
+            .and_then(|n| state.blocks.get(n))
+            .map(|block| block.hash)
+    }
+
+    fn build_block(
+        number: T::BlockNumber,
+        parent_hash: T::Hash,
+        extrinsics: Vec<Vec<u8>>,
+        storage: BTreeMap<Vec<u8>, Vec<u8>>,
+    ) -> MockBlock<T> {
+        let header = T::Header::new(
+            number,
+            Default::default(),
+            Default::default(),
+            parent_hash,
+            Default::default(),
+        );
+        MockBlock {
+            hash: header.hash(),
+            header,
+            extrinsics,
+            storage: Arc::new(storage),
+        }
+    }
+}
+
+impl<T: Config> MockState<T> {
+    fn best_block(&self) -> &MockBlock<T> {
+        self.blocks.last().expect("there is always a genesis block")
+    }
+
+    fn block(&self, hash: Option<T::Hash>) -> Option<&MockBlock<T>> {
+        match hash {
+            Some(hash) => self.block_numbers.get(&hash).map(|&n| &self.blocks[n]),
+            None => Some(self.best_block()),
+        }
+    }
+
+    fn storage(
+        &self,
+        hash: Option<T::Hash>,
+    ) -> Result<&BTreeMap<Vec<u8>, Vec<u8>>, RpcError> {
+        self.block(hash)
+            .map(|block| &*block.storage)
+            .ok_or_else(|| mock_error("block not found".into()))
+    }
+
+    // Find a block pinned by the given `chainHead_unstable_follow` subscription, handing
+    // back the event to send if something goes wrong.
+    fn pinned_block(
+        &self,
+        subscription_id: &str,
+        hash: T::Hash,
+    ) -> Result<&MockBlock<T>, Value> {
+        let follow = self
+            .follow_subscriptions
+            .get(subscription_id)
+            .ok_or_else(|| json!({ "event": "disjoint" }))?;
+        if !follow.pinned.contains(&hash) {
+            return Err(json!({ "event": "error", "error": "block is not pinned" }))
+        }
+        self.block(Some(hash))
+            .ok_or_else(|| json!({ "event": "error", "error": "block not found" }))
+    }
+
+    fn runtime_version(&self) -> Value {
+        json!({
+            "specVersion": self.spec_version,
+            "transactionVersion": self.transaction_version,
+        })
+    }
+}
+
+impl<T: Config> MockNode<T>
+where
+    T::Header: Serialize,
+{
+    /// Produce and finalize a new block containing any pending extrinsics and storage
+    /// changes, notifying any subscribers. Returns the hash of the new block.
+    pub fn produce_block(&self) -> T::Hash {
+        let mut state = self.inner.lock();
+
+        let parent = state.best_block();
+        let parent_hash = parent.hash;
+        let number = next_block_number(&parent.header);
+
+        let mut storage = (*parent.storage).clone();
+        let pending_storage = std::mem::take(&mut state.pending_storage);
+        let changed_keys: Vec<_> = pending_storage
+            .iter()
+            .filter(|(key, value)| storage.get(*key) != value.as_ref())
+            .map(|(key, _)| key.clone())
+            .collect();
+        for (key, value) in pending_storage {
+            match value {
+                Some(value) => storage.insert(key, value),
+                None => storage.remove(&key),
+            };
+        }
+
+        let pending = std::mem::take(&mut state.pending_extrinsics);
+        let extrinsics = pending.iter().map(|ext| ext.bytes.clone()).collect();
+
+        let block = Self::build_block(number, parent_hash, extrinsics, storage);
+        let hash = block.hash;
+        let header = serde_json::to_value(&block.header)
+            .expect("headers can be serialized to JSON");
+
+        let block_number = state.blocks.len();
+        state.block_numbers.insert(hash, block_number);
+        state.blocks.push(block);
+
+        notify(&mut state.new_heads_subscribers, &header);
+        notify(&mut state.finalized_heads_subscribers, &header);
+        let storage = state.best_block().storage.clone();
+        state.storage_subscribers.retain(|sub| {
+            let keys: Vec<_> = sub
+                .keys
+                .iter()
+                .filter(|key| changed_keys.contains(key))
+                .collect();
+            // Only notify subscribers if something they're interested in changed.
+            keys.is_empty()
+                || sub
+                    .tx
+                    .unbounded_send(storage_change_set(hash, &storage, keys))
+                    .is_ok()
+        });
+        let follow_events = [
+            json!({
+                "event": "newBlock",
+                "blockHash": hash,
+                "parentBlockHash": parent_hash,
+                "newRuntime": null,
+            }),
+            json!({ "event": "bestBlockChanged", "bestBlockHash": hash }),
+            json!({
+                "event": "finalized",
+                "finalizedBlockHashes": [hash],
+                "prunedBlockHashes": [],
+            }),
+        ];
+        state.follow_subscriptions.retain(|_, sub| {
+            sub.pinned.insert(hash);
+            follow_events
+                .iter()
+                .all(|ev| sub.tx.unbounded_send(ev.clone()).is_ok())
+        });
+        for ext in pending {
+            if let Some(watcher) = ext.watcher {
+                // Nobody may be listening any more, which is fine.
+                let _ = watcher.unbounded_send(json!({ "inBlock": hash }));
+                let _ = watcher.unbounded_send(json!({ "finalized": hash }));
+            }
+        }
+
+        hash
+    }
+
+    fn handle_request(&self, method: &str, params: &[Value]) -> Result<Value, RpcError> {
+        let mut state = self.inner.lock();
+        if method.starts_with("chainHead_") && !state.chain_head_enabled {
+            return Err(unsupported_method(method))
+        }
+        let res = match method {
+            "rpc_methods" => {
+                let mut methods = vec![
+                    "state_getMetadata",
+                    "state_getRuntimeVersion",
+                    "state_getStorage",
+                    "state_getKeysPaged",
+                    "state_queryStorageAt",
+                    "chain_getBlockHash",
+                    "chain_getHeader",
+                    "chain_getBlock",
+                    "chain_getFinalizedHead",
+                    "system_accountNextIndex",
+                    "payment_queryInfo",
+                    "payment_queryFeeDetails",
+                    "author_submitExtrinsic",
+                    "author_submitAndWatchExtrinsic",
+                    "chain_subscribeNewHeads",
+                    "chain_subscribeAllHeads",
+                    "chain_subscribeFinalizedHeads",
+                    "state_subscribeRuntimeVersion",
+                    "state_subscribeStorage",
+                    "rpc_methods",
+                ];
+                if state.chain_head_enabled {
+                    methods.extend([
+                        "chainHead_unstable_follow",
+                        "chainHead_unstable_header",
+                        "chainHead_unstable_body",
+                        "chainHead_unstable_storage",
+                        "chainHead_unstable_call",
+                        "chainHead_unstable_unpin",
+                    ]);
+                }
+                json!({ "version": 1, "methods": methods })
+            }
+            "chainHead_unstable_header" => {
+                let subscription_id: String = required_param(params, 0)?;
+                let hash: T::Hash = required_param(params, 1)?;
+                match state.pinned_block(&subscription_id, hash) {
+                    Ok(block) => to_hex(&block.header.encode()),
+                    Err(_) => Value::Null,
+                }
+            }
+            "chainHead_unstable_unpin" => {
+                let subscription_id: String = required_param(params, 0)?;
+                let hash: T::Hash = required_param(params, 1)?;
+                let unpinned = state
+                    .follow_subscriptions
+                    .get_mut(&subscription_id)
+                    .is_some_and(|sub| sub.pinned.remove(&hash));
+                if !unpinned {
+                    return Err(mock_error("block is not pinned".into()))
+                }
+                Value::Null
+            }
+            "state_getMetadata" => to_hex(&state.metadata),
+            "state_getRuntimeVersion" => state.runtime_version(),
+            "state_getStorage" => {
+                let key: Bytes = required_param(params, 0)?;
+                let hash = param(params, 1)?;
+                match state.storage(hash)?.get(&key.0) {
+                    Some(value) => to_hex(value),
+                    None => Value::Null,
+                }
+            }
+            "state_getKeysPaged" => {
+                let prefix: Bytes = required_param(params, 0)?;
+                let count: usize = required_param(params, 1)?;
+                let start_key: Option<Bytes> = param(params, 2)?;
+                let hash = param(params, 3)?;
+                let keys: Vec<Value> = state
+                    .storage(hash)?
+                    .keys()
+                    .filter(|key| key.starts_with(&prefix))
+                    .filter(|key| start_key.as_ref().is_none_or(|s| **key > s.0))
+                    .take(count)
+                    .map(|key| to_hex(key))
+                    .collect();
+                Value::Array(keys)
+            }
+            "state_queryStorageAt" => {
+                let keys: Vec<Bytes> = required_param(params, 0)?;
+                let hash = param(params, 1)?;
+                let block = state
+                    .block(hash)
+                    .ok_or_else(|| mock_error("block not found".into()))?;
+                let keys = keys.iter().map(|key| &key.0);
+                json!([storage_change_set(block.hash, &block.storage, keys)])
+            }
+            "chain_getBlockHash" => {
+                let number: Option<NumberOrHex> = param(params, 0)?;
+                let block = match number {
+                    None => Some(state.best_block()),
+                    Some(NumberOrHex::Number(n)) => {
+                        usize::try_from(n).ok().and_then(|n| state.blocks.get(n))
+                    }
+                    Some(NumberOrHex::Hex(n)) => {
+                        usize::try_from(n).ok().and_then(|n| state.blocks.get(n))
+                    }
+                };
+                json!(block.map(|block| block.hash))
+            }
+            "chain_getHeader" => {
+                let hash = param(params, 0)?;
+                json!(state.block(hash).map(|block| &block.header))
+            }
+            "chain_getBlock" => {
+                let hash = param(params, 0)?;
+                match state.block(hash) {
+                    Some(block) => {
+                        let extrinsics: Vec<Value> =
+                            block.extrinsics.iter().map(|ext| to_hex(ext)).collect();
+                        json!({
+                            "block": {
+                                "header": block.header,
+                                "extrinsics": extrinsics,
+                            },
+                            "justifications": null,
+                        })
+                    }
+                    None => Value::Null,
+                }
+            }
+            "chain_getFinalizedHead" => json!(state.best_block().hash),
+            "system_accountNextIndex" => {
+                let account: Value = required_param(params, 0)?;
+                let nonce = state.nonces.get(&account.to_string()).copied();
+                json!(nonce.unwrap_or(0))
+            }
+            "payment_queryInfo" => {
+                let ext: Bytes = required_param(params, 0)?;
+                let partial_fee = match is_signed(&ext.0)? {
+                    true => state.fee.inclusion_fee(),
+                    false => 0,
+                };
+                json!({
+                    "weight": state.weight,
+                    "class": "normal",
+                    "partialFee": partial_fee.to_string(),
+                })
+            }
+            "payment_queryFeeDetails" => {
+                let ext: Bytes = required_param(params, 0)?;
+                let inclusion_fee = is_signed(&ext.0)?.then(|| {
+                    json!({
+                        "baseFee": format!("{:#x}", state.fee.base_fee),
+                        "lenFee": format!("{:#x}", state.fee.len_fee),
+                        "adjustedWeightFee": format!("{:#x}", state.fee.adjusted_weight_fee),
+                    })
+                });
+                json!({ "inclusionFee": inclusion_fee })
+            }
+            "author_submitExtrinsic" => {
+                let ext: Bytes = required_param(params, 0)?;
+                drop(state);
+                json!(self.submit(ext.0, None))
+            }
+            _ => return Err(unsupported_method(method)),
+        };
+        Ok(res)
+    }
+
+    fn handle_subscription(
+        &self,
+        sub: &str,
+        params: &[Value],
+        subscription_id: &str,
+    ) -> Result<mpsc::UnboundedReceiver<Value>, RpcError> {
+        let (tx, rx) = mpsc::unbounded();
+        if sub.starts_with("chainHead_") && !self.inner.lock().chain_head_enabled {
+            return Err(unsupported_method(sub))
+        }
+        match sub {
+            "author_submitAndWatchExtrinsic" => {
+                let ext: Bytes = required_param(params, 0)?;
+                tx.unbounded_send(json!("ready"))
+                    .expect("receiver is not dropped yet");
+                self.submit(ext.0, Some(tx));
+            }
+            "state_subscribeRuntimeVersion" => {
+                let mut state = self.inner.lock();
+                tx.unbounded_send(state.runtime_version())
+                    .expect("receiver is not dropped yet");
+                state.runtime_version_subscribers.push(tx);
+            }
+            "state_subscribeStorage" => {
+                let keys: Vec<Bytes> = required_param(params, 0)?;
+                let keys: Vec<Vec<u8>> = keys.into_iter().map(|key| key.0).collect();
+                let mut state = self.inner.lock();
+                let block = state.best_block();
+                tx.unbounded_send(storage_change_set(block.hash, &block.storage, &keys))
+                    .expect("receiver is not dropped yet");
+                state
+                    .storage_subscribers
+                    .push(StorageSubscriber { keys, tx });
+            }
+            "chain_subscribeNewHeads"
+            | "chain_subscribeAllHeads"
+            | "chain_subscribeFinalizedHeads" => {
+                let mut state = self.inner.lock();
+                let header = serde_json::to_value(&state.best_block().header)
+                    .expect("headers can be serialized to JSON");
+                tx.unbounded_send(header)
+                    .expect("receiver is not dropped yet");
+                if sub == "chain_subscribeFinalizedHeads" {
+                    state.finalized_heads_subscribers.push(tx);
+                } else {
+                    state.new_heads_subscribers.push(tx);
+                }
+            }
+            "chainHead_unstable_follow" => {
+                let runtime_updates: bool = required_param(params, 0)?;
+                let mut state = self.inner.lock();
+                let hash = state.best_block().hash;
+                let runtime = runtime_updates
+                    .then(|| json!({ "type": "valid", "spec": state.runtime_version() }));
+                tx.unbounded_send(json!({
+                    "event": "initialized",
+                    "finalizedBlockHash": hash,
+                    "finalizedBlockRuntime": runtime,
+                }))
+                .expect("receiver is not dropped yet");
+                let follow = FollowSubscription {
+                    tx,
+                    pinned: HashSet::from([hash]),
+                };
+                state
+                    .follow_subscriptions
+                    .insert(subscription_id.to_owned(), follow);
+            }
+            "chainHead_unstable_body" | "chainHead_unstable_storage" => {
+                let follow_id: String = required_param(params, 0)?;
+                let hash: T::Hash = required_param(params, 1)?;
+                // Only storage requests are given a key.
+                let key: Option<Bytes> = param(params, 2)?;
+                let state = self.inner.lock();
+                let ev = state.pinned_block(&follow_id, hash).map(|block| {
+                    let result = match key {
+                        Some(key) => json!(block.storage.get(&key.0).map(|v| to_hex(v))),
+                        None => {
+                            let extrinsics: Vec<Value> =
+                                block.extrinsics.iter().map(|ext| to_hex(ext)).collect();
+                            Value::Array(extrinsics)
+                        }
+                    };
+                    json!({ "event": "done", "result": result })
+                });
+                tx.unbounded_send(ev.unwrap_or_else(|ev| ev))
+                    .expect("receiver is not dropped yet");
+            }
+            "chainHead_unstable_call" => {
+                tx.unbounded_send(json!({
+                    "event": "error",
+                    "error": "the mock node cannot execute runtime calls",
+                }))
+                .expect("receiver is not dropped yet");
+            }
+            _ => return Err(unsupported_method(sub)),
+        }
+        Ok(rx)
+    }
+
+    fn submit(
+        &self,
+        bytes: Vec<u8>,
+        watcher: Option<mpsc::UnboundedSender<Value>>,
+    ) -> T::Hash {
+        let hash = T::Hashing::hash(&bytes);
+        self.inner
+            .lock()
+            .pending_extrinsics
+            .push(PendingExtrinsic { bytes, watcher });
+        hash
+    }
+}
+
+impl<T: Config> RpcClientT for MockNode<T>
+where
+    T::Header: Serialize,
+{
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let params = parse_params(params)?;
+            let res = self.handle_request(method, &params)?;
+            to_raw_value(&res)
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(async move {
+            let (_, sub) = self.subscribe_raw_with_id(sub, params, unsub).await?;
+            Ok(sub)
+        })
+    }
+
+    fn subscribe_raw_with_id<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        _unsub: &'a str,
+    ) -> RpcFuture<'a, (Option<String>, RpcSubscription)> {
+        Box::pin(async move {
+            let params = parse_params(params)?;
+            let id = {
+                let mut state = self.inner.lock();
+                state.next_subscription_id += 1;
+                format!("mock-subscription-{}", state.next_subscription_id)
+            };
+            let rx = self.handle_subscription(sub, &params, &id)?;
+            Ok((Some(id), rx.map(|val| to_raw_value(&val)).boxed()))
+        })
+    }
+}
+
+// The bounds on `Header::Number` aren't visible via `Config::BlockNumber`, so
+// we lean on the `Header` trait to increment it.
+fn next_block_number<H: Header>(header: &H) -> H::Number {
+    *header.number() + One::one()
+}
+
+// The `StorageChangeSet` handed back for the given keys in some block.
+fn storage_change_set<H: Serialize>(
+    hash: H,
+    storage: &BTreeMap<Vec<u8>, Vec<u8>>,
+    keys: impl IntoIterator<Item = impl AsRef<[u8]>>,
+) -> Value {
+    let changes: Vec<Value> = keys
+        .into_iter()
+        .map(|key| {
+            let value = storage.get(key.as_ref()).map(|v| to_hex(v));
+            json!([to_hex(key.as_ref()), value])
+        })
+        .collect();
+    json!({ "block": hash, "changes": changes })
+}
+
+// Whether the given SCALE encoded extrinsic (including its length prefix) is signed.
+fn is_signed(ext: &[u8]) -> Result<bool, RpcError> {
+    let input = &mut &*ext;
+    <Compact<u32>>::decode(input)
+        .and_then(|_| u8::decode(input))
+        .map(|version| version & 0b1000_0000 != 0)
+        .map_err(|e| mock_error(format!("invalid extrinsic: {e}")))
+}
+
+// Send a notification to each subscriber, forgetting about any that have gone away.
+fn notify(subscribers: &mut Vec<mpsc::UnboundedSender<Value>>, val: &Value) {
+    subscribers.retain(|tx| tx.unbounded_send(val.clone()).is_ok());
+}
+
+// Accounts are looked up using their JSON representation, which is what we're handed.
+fn account_key<A: Serialize>(account: &A) -> String {
+    serde_json::to_value(account)
+        .expect("account IDs can be serialized to JSON")
+        .to_string()
+}
+
+fn parse_params(params: Option<Box<RawValue>>) -> Result<Vec<Value>, RpcError> {
+    match params {
+        Some(params) => {
+            serde_json::from_str(params.get())
+                .map_err(|e| RpcError::ClientError(Box::new(e)))
+        }
+        None => Ok(Vec::new()),
+    }
+}
+
+// Parameters which aren't provided, or are `null`, are `None`.
+fn param<P: DeserializeOwned>(
+    params: &[Value],
+    idx: usize,
+) -> Result<Option<P>, RpcError> {
+    match params.get(idx) {
+        None | Some(Value::Null) => Ok(None),
+        Some(val) => {
+            serde_json::from_value(val.clone())
+                .map(Some)
+                .map_err(|e| RpcError::ClientError(Box::new(e)))
+        }
+    }
+}
+
+fn required_param<P: DeserializeOwned>(
+    params: &[Value],
+    idx: usize,
+) -> Result<P, RpcError> {
+    param(params, idx)?
+        .ok_or_else(|| mock_error(format!("expected a parameter at position {idx}")))
+}
+
+fn to_hex(bytes: &[u8]) -> Value {
+    Value::String(format!("0x{}", hex::encode(bytes)))
+}
+
+fn to_raw_value(val: &Value) -> Result<Box<RawValue>, RpcError> {
+    serde_json::value::to_raw_value(val).map_err(|e| RpcError::ClientError(Box::new(e)))
+}
+
+fn unsupported_method(method: &str) -> RpcError {
+    mock_error(format!(
+        "the mock node does not support the `{method}` method"
+    ))
+}
+
+fn mock_error(msg: String) -> RpcError {
+    RpcError::ClientError(msg.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        dynamic::Value,
+        tx::PairSigner,
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use sp_core::{
+        sr25519,
+        Pair,
+    };
+
+    async fn client() -> (MockNode<PolkadotConfig>, OnlineClient<PolkadotConfig>) {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::new(metadata.to_vec());
+        let api = OnlineClient::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .expect("can build a client from the mock node");
+        (node, api)
+    }
+
+    #[tokio::test]
+    async fn storage_is_tracked_per_block() {
+        let (node, api) = client().await;
+        let address = crate::dynamic::storage_root("System", "Number");
+        let key = crate::storage::utils::storage_address_bytes(&address, &api.metadata())
+            .unwrap();
+
+        node.set_storage(key.clone(), 5u32.to_le_bytes().to_vec());
+        let first = node.produce_block();
+        node.set_storage(key, 6u32.to_le_bytes().to_vec());
+        node.produce_block();
+
+        let latest = api.storage().fetch(&address, None).await.unwrap().unwrap();
+        let historic = api
+            .storage()
+            .fetch(&address, Some(first))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.as_u128(), Some(6));
+        assert_eq!(historic.as_u128(), Some(5));
+    }
+
+    #[tokio::test]
+    async fn transactions_are_included_in_produced_blocks() {
+        let (node, api) = client().await;
+        let signer =
+            PairSigner::new(sr25519::Pair::from_string("//Alice", None).unwrap());
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+
+        let progress = api
+            .tx()
+            .sign_and_submit_then_watch_default(&tx, &signer)
+            .await
+            .unwrap();
+        assert_eq!(node.pending_extrinsics().len(), 1);
+
+        let block_hash = node.produce_block();
+        let in_block = progress.wait_for_finalized().await.unwrap();
+        assert_eq!(in_block.block_hash(), block_hash);
+        assert!(node.pending_extrinsics().is_empty());
+
+        // No events were set in storage, but we can still find our extrinsic in the block.
+        let events = in_block.fetch_events().await.unwrap();
+        assert_eq!(events.iter().count(), 0);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! RPC types and client for interacting with a substrate node.
+//!
+//! This is used behind the scenes by various `subxt` APIs, but can
+//! also be used directly.
+//!
+//! # Example
+//!
+//! Fetching storage keys
+//!
+//! ```no_run
+//! use subxt::{ PolkadotConfig, OnlineClient, storage::StorageKey };
+//!
+//! #[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata.scale")]
+//! pub mod polkadot {}
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+//!
+//! let key = polkadot::storage()
+//!     .xcm_pallet()
+//!     .version_notifiers_root()
+//!     .to_bytes();
+//!
+//! // Fetch up to 10 keys.
+//! let keys = api
+//!     .rpc()
+//!     .storage_keys_paged(&key, 10, None, None)
+//!     .await
+//!     .unwrap();
+//!
+//! for key in keys.iter() {
+//!     println!("Key: 0x{}", hex::encode(&key));
+//! }
+//! # }
+//! ```
+
+// Allow an `rpc.rs` file in the `rpc` folder to align better
+// with other file names for their types.
+#![allow(clippy::module_inception)]
+
+#[cfg(any(feature = "jsonrpsee-ws", feature = "jsonrpsee-http"))]
+mod jsonrpsee_impl;
+
+mod chain_head;
+mod chain_head_backend;
+mod mock_node;
+mod reconnecting_rpc_client;
+mod record_replay;
+mod rpc;
+mod rpc_client;
+mod rpc_client_t;
+
+// Expose the `Rpc` struct and any associated types.
+pub use rpc::*;
+
+pub use chain_head::{
+    BestBlockChanged,
+    ChainHeadEvent,
+    ChainHeadResult,
+    ErrorEvent,
+    Finalized,
+    FollowEvent,
+    Initialized,
+    NewBlock,
+    RpcMethods,
+    RuntimeEvent,
+    RuntimeVersionEvent,
+};
+
+pub use chain_head_backend::{
+    ChainHeadBackend,
+    ChainHeadFollower,
+    FollowSubscription,
+};
+
+pub use rpc_client_t::{
+    RawValue,
+    RpcClientT,
+    RpcFuture,
+    RpcSubscription,
+};
+
+pub use rpc_client::{
+    rpc_params,
+    BatchItem,
+    BatchRequest,
+    BatchResponse,
+    RpcClient,
+    RpcParams,
+    Subscription,
+};
+
+pub use mock_node::MockNode;
+
+pub use record_replay::{
+    RecordingRpcClient,
+    ReplayRpcClient,
+};
+
+pub use reconnecting_rpc_client::{
+    Backoff,
+    ReconnectingRpcClient,
+};
+
+pub use crate::error::RpcError;
+
+#[cfg(feature = "jsonrpsee-ws")]
+pub use jsonrpsee_impl::ws_client;
+
+#[cfg(feature = "jsonrpsee-http")]
+pub use jsonrpsee_impl::http_client;
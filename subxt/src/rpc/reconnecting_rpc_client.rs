@@ -0,0 +1,583 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{
+    RawValue,
+    RpcClientT,
+    RpcFuture,
+    RpcSubscription,
+};
+use crate::error::RpcError;
+use futures::{
+    future::BoxFuture,
+    lock::Mutex as AsyncMutex,
+    stream,
+    Future,
+    FutureExt,
+    StreamExt,
+};
+use parking_lot::RwLock;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+/// Subscriptions which are safe to re-establish after a reconnection, because
+/// re-subscribing simply continues to hand back the latest state of the chain.
+///
+/// Subscriptions like `author_submitAndWatchExtrinsic` are deliberately absent; we
+/// can't know whether the extrinsic made it into a block while we were disconnected,
+/// so those subscriptions end when the connection is lost.
+const RESUMABLE_SUBSCRIPTIONS: &[&str] = &[
+    "chain_subscribeNewHeads",
+    "chain_subscribeAllHeads",
+    "chain_subscribeFinalizedHeads",
+    "state_subscribeRuntimeVersion",
+    "state_subscribeStorage",
+];
+
+/// Methods which aren't retried after the connection is lost, because the node may have
+/// acted on the first attempt. Retrying these could lead to a spurious error (for instance,
+/// that the extrinsic has already been imported), so the error is handed back instead.
+const NON_RETRYABLE_METHODS: &[&str] =
+    &["author_submitExtrinsic", "author_submitAndWatchExtrinsic"];
+
+/// Configure how a [`ReconnectingRpcClient`] waits between attempts to reconnect.
+///
+/// The delay starts at `initial_delay`, and is multiplied by `factor` after each failed
+/// attempt, up to a maximum of `max_delay`. After `max_attempts` failed attempts in a row,
+/// we give up and hand back the last error.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use subxt::rpc::Backoff;
+///
+/// let backoff = Backoff::new()
+///     .initial_delay(Duration::from_millis(100))
+///     .max_delay(Duration::from_secs(5))
+///     .max_attempts(Some(20));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    factor: u32,
+    max_attempts: Option<usize>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Backoff {
+    /// Create a new [`Backoff`] with the default configuration: start by waiting 500ms,
+    /// doubling each time up to 10s, and try to reconnect forever.
+    pub fn new() -> Self {
+        Backoff {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            factor: 2,
+            max_attempts: None,
+        }
+    }
+
+    /// How long to wait before the first attempt to reconnect.
+    pub fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// The longest that we'll wait between attempts to reconnect.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// How much to multiply the delay by after each failed attempt.
+    pub fn factor(mut self, factor: u32) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// How many attempts in a row to make before giving up. `None` means that
+    /// we'll keep trying forever.
+    pub fn max_attempts(mut self, max_attempts: Option<usize>) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    fn delays(&self) -> impl Iterator<Item = Duration> {
+        let max_delay = self.max_delay;
+        let factor = self.factor;
+        std::iter::successors(Some(self.initial_delay.min(max_delay)), move |d| {
+            Some(d.saturating_mul(factor).min(max_delay))
+        })
+        .take(self.max_attempts.unwrap_or(usize::MAX))
+    }
+}
+
+type ConnectFn<R> =
+    Box<dyn Fn() -> BoxFuture<'static, Result<R, RpcError>> + Send + Sync + 'static>;
+
+/// An [`RpcClientT`] implementation which wraps some other [`RpcClientT`], and transparently
+/// reconnects (according to some [`Backoff`] policy) when the connection to the node is lost.
+///
+/// - Requests and subscriptions which fail with [`RpcError::ConnectionLost`] are retried once
+///   the connection has been re-established. The exception is submitting an extrinsic, which
+///   the node may have received before the connection was lost; the error is handed back.
+/// - Subscriptions to new, finalized and all block headers, and to runtime version updates,
+///   are re-established on the new connection and continue as if nothing happened. Any
+///   blocks that were produced while we were disconnected are filled in by the APIs that
+///   care about them (for instance, [`crate::events::EventSubscription`]s). If one of these
+///   subscriptions ends while the connection is still usable, it isn't re-established.
+/// - All other subscriptions (for instance, watching the progress of a transaction) end
+///   when the connection is lost.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use subxt::{
+///     rpc::{ ws_client, Backoff, ReconnectingRpcClient },
+///     OnlineClient,
+///     PolkadotConfig,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let rpc_client = ReconnectingRpcClient::new(Backoff::new(), || {
+///     ws_client("ws://127.0.0.1:9944")
+/// })
+/// .await
+/// .unwrap();
+///
+/// let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(rpc_client))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub struct ReconnectingRpcClient<R> {
+    inner: Arc<Inner<R>>,
+}
+
+impl<R> std::fmt::Debug for ReconnectingRpcClient<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectingRpcClient")
+            .field("backoff", &self.inner.backoff)
+            .finish()
+    }
+}
+
+struct Inner<R> {
+    connect: ConnectFn<R>,
+    backoff: Backoff,
+    // The current client, along with a number that is incremented each time we reconnect.
+    client: RwLock<(Arc<R>, usize)>,
+    // Held while reconnecting, so that only one reconnection happens at a time.
+    reconnecting: AsyncMutex<()>,
+}
+
+impl<R: RpcClientT> ReconnectingRpcClient<R> {
+    /// Create a new [`ReconnectingRpcClient`]. `connect` is called to establish the initial
+    /// connection, and again each time the connection needs to be re-established.
+    pub async fn new<F, Fut>(backoff: Backoff, connect: F) -> Result<Self, RpcError>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, RpcError>> + Send + 'static,
+    {
+        let connect: ConnectFn<R> = Box::new(move || connect().boxed());
+        let client = connect().await?;
+
+        Ok(ReconnectingRpcClient {
+            inner: Arc::new(Inner {
+                connect,
+                backoff,
+                client: RwLock::new((Arc::new(client), 0)),
+                reconnecting: AsyncMutex::new(()),
+            }),
+        })
+    }
+}
+
+impl<R: RpcClientT> Inner<R> {
+    fn current(&self) -> (Arc<R>, usize) {
+        let client = self.client.read();
+        (client.0.clone(), client.1)
+    }
+
+    /// Is the connection with the given `generation` still usable? Any request will do to
+    /// find out; we only care whether it fails because the connection has gone away.
+    async fn is_connected(&self, generation: usize) -> bool {
+        let (client, current_generation) = self.current();
+        if current_generation != generation {
+            // We've reconnected since, so that connection has gone.
+            return false
+        }
+        !matches!(
+            client.request_raw("rpc_methods", None).await,
+            Err(RpcError::ConnectionLost(_))
+        )
+    }
+
+    /// Reconnect, unless somebody else has already done so since we obtained the
+    /// client with the given `generation`. Either way, hand back the latest client.
+    async fn reconnect(&self, generation: usize) -> Result<(Arc<R>, usize), RpcError> {
+        let _guard = self.reconnecting.lock().await;
+
+        let current = self.current();
+        if current.1 != generation {
+            return Ok(current)
+        }
+
+        let mut last_err =
+            RpcError::ConnectionLost("no attempts to reconnect made".into());
+        for delay in self.backoff.delays() {
+            futures_timer::Delay::new(delay).await;
+            match (self.connect)().await {
+                Ok(client) => {
+                    let client = Arc::new(client);
+                    *self.client.write() = (client.clone(), generation + 1);
+                    tracing::info!("Reconnected to the node");
+                    return Ok((client, generation + 1))
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to reconnect to the node: {}", e);
+                    last_err = e;
+                }
+            }
+        }
+        Err(last_err)
+    }
+}
+
+impl<R: RpcClientT> RpcClientT for ReconnectingRpcClient<R> {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let mut current = self.inner.current();
+            loop {
+                let (client, generation) = current;
+                match client.request_raw(method, params.clone()).await {
+                    Err(RpcError::ConnectionLost(reason))
+                        if !NON_RETRYABLE_METHODS.contains(&method) =>
+                    {
+                        tracing::warn!(
+                            "Connection lost making request to `{}`: {}",
+                            method,
+                            reason
+                        );
+                        current = self.inner.reconnect(generation).await?;
+                    }
+                    res => return res,
+                }
+            }
+        })
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        batch: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RpcFuture<'a, Vec<Box<RawValue>>> {
+        Box::pin(async move {
+            let retryable = batch
+                .iter()
+                .all(|(method, _)| !NON_RETRYABLE_METHODS.contains(method));
+            let mut current = self.inner.current();
+            loop {
+                let (client, generation) = current;
+                match client.batch_request_raw(batch.clone()).await {
+                    Err(RpcError::ConnectionLost(reason)) if retryable => {
+                        tracing::warn!(
+                            "Connection lost making batch request: {}",
+                            reason
+                        );
+                        current = self.inner.reconnect(generation).await?;
+                    }
+                    res => return res,
+                }
+            }
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(async move {
+            let (_, sub) = self.subscribe_raw_with_id(sub, params, unsub).await?;
+            Ok(sub)
+        })
+    }
+
+    fn subscribe_raw_with_id<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, (Option<String>, RpcSubscription)> {
+        Box::pin(async move {
+            let mut current = self.inner.current();
+            let (id, subscription, generation) = loop {
+                let (client, generation) = current;
+                match client
+                    .subscribe_raw_with_id(sub, params.clone(), unsub)
+                    .await
+                {
+                    Ok((id, subscription)) => break (id, subscription, generation),
+                    Err(RpcError::ConnectionLost(reason))
+                        if !NON_RETRYABLE_METHODS.contains(&sub) =>
+                    {
+                        tracing::warn!(
+                            "Connection lost subscribing to `{}`: {}",
+                            sub,
+                            reason
+                        );
+                        current = self.inner.reconnect(generation).await?;
+                    }
+                    Err(e) => return Err(e),
+                }
+            };
+
+            // Subscriptions whose IDs are used in other calls (like `chainHead_unstable_follow`)
+            // are tied to the connection that they were made on, and so aren't resumable.
+            if !RESUMABLE_SUBSCRIPTIONS.contains(&sub) {
+                return Ok((id, subscription))
+            }
+
+            let state = ResumableSubscription {
+                inner: self.inner.clone(),
+                sub: sub.to_owned(),
+                params,
+                unsub: unsub.to_owned(),
+                subscription: Some(subscription),
+                generation,
+            };
+            Ok((
+                id,
+                stream::unfold(state, ResumableSubscription::next).boxed(),
+            ))
+        })
+    }
+}
+
+// The state needed to drive a subscription which re-subscribes when the connection drops.
+struct ResumableSubscription<R> {
+    inner: Arc<Inner<R>>,
+    sub: String,
+    params: Option<Box<RawValue>>,
+    unsub: String,
+    // `None` once the subscription has ended for good.
+    subscription: Option<RpcSubscription>,
+    generation: usize,
+}
+
+impl<R: RpcClientT> ResumableSubscription<R> {
+    async fn next(mut self) -> Option<(Result<Box<RawValue>, RpcError>, Self)> {
+        loop {
+            let subscription = self.subscription.as_mut()?;
+            let connection_lost = match subscription.next().await {
+                Some(Err(RpcError::ConnectionLost(_))) => true,
+                // The subscription may have ended because the connection dropped, or because
+                // the node closed it, so check whether the connection is still usable.
+                None => !self.inner.is_connected(self.generation).await,
+                Some(item) => return Some((item, self)),
+            };
+            if !connection_lost {
+                self.subscription = None;
+                return None
+            }
+
+            tracing::warn!("Subscription to `{}` dropped; resubscribing", self.sub);
+            if let Err(e) = self.resubscribe().await {
+                self.subscription = None;
+                return Some((Err(e), self))
+            }
+        }
+    }
+
+    async fn resubscribe(&mut self) -> Result<(), RpcError> {
+        loop {
+            let (client, generation) = self.inner.reconnect(self.generation).await?;
+            self.generation = generation;
+            match client
+                .subscribe_raw(&self.sub, self.params.clone(), &self.unsub)
+                .await
+            {
+                Ok(subscription) => {
+                    self.subscription = Some(subscription);
+                    return Ok(())
+                }
+                Err(RpcError::ConnectionLost(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    // A client which returns a couple of items from each subscription and then ends
+    // it, and whose first connection fails any requests (and optionally subscriptions),
+    // as if it was flaky.
+    struct FlakyClient {
+        connection: usize,
+        subscriptions_fail: bool,
+    }
+
+    impl RpcClientT for FlakyClient {
+        fn request_raw<'a>(
+            &'a self,
+            _method: &'a str,
+            _params: Option<Box<RawValue>>,
+        ) -> RpcFuture<'a, Box<RawValue>> {
+            Box::pin(async move {
+                if self.connection == 0 {
+                    return Err(RpcError::ConnectionLost("flaky".into()))
+                }
+                Ok(RawValue::from_string(self.connection.to_string()).unwrap())
+            })
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            _sub: &'a str,
+            _params: Option<Box<RawValue>>,
+            _unsub: &'a str,
+        ) -> RpcFuture<'a, RpcSubscription> {
+            if self.subscriptions_fail && self.connection == 0 {
+                return Box::pin(async { Err(RpcError::ConnectionLost("flaky".into())) })
+            }
+            let items: Vec<_> = (0..2)
+                .map(|n| {
+                    Ok(
+                        RawValue::from_string(format!("[{},{}]", self.connection, n))
+                            .unwrap(),
+                    )
+                })
+                .collect();
+            Box::pin(async move { Ok(stream::iter(items).boxed()) })
+        }
+    }
+
+    async fn flaky_client(
+        subscriptions_fail: bool,
+    ) -> (ReconnectingRpcClient<FlakyClient>, Arc<AtomicUsize>) {
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        let backoff = Backoff::new()
+            .initial_delay(Duration::from_millis(1))
+            .max_attempts(Some(3));
+        let client = ReconnectingRpcClient::new(backoff, move || {
+            let connection = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(FlakyClient {
+                    connection,
+                    subscriptions_fail,
+                })
+            }
+        })
+        .await
+        .unwrap();
+        (client, connections)
+    }
+
+    #[tokio::test]
+    async fn requests_are_retried_after_reconnecting() {
+        let (client, connections) = flaky_client(false).await;
+
+        let res = client.request_raw("foo", None).await.unwrap();
+
+        // The first connection failed, so the request was made on the second one.
+        assert_eq!(res.get(), "1");
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn resumable_subscriptions_continue_after_reconnecting() {
+        let (client, connections) = flaky_client(false).await;
+
+        let items: Vec<String> = client
+            .subscribe_raw("chain_subscribeFinalizedHeads", None, "unsub")
+            .await
+            .unwrap()
+            .map(|item| item.unwrap().get().to_owned())
+            .collect()
+            .await;
+
+        // The first connection had gone away when its subscription ended, so we resubscribed.
+        // The second connection could still be used, so its subscription ending was final.
+        assert_eq!(items, vec!["[0,0]", "[0,1]", "[1,0]", "[1,1]"]);
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn other_subscriptions_end_when_dropped() {
+        let (client, connections) = flaky_client(false).await;
+
+        let items: Vec<_> = client
+            .subscribe_raw("author_submitAndWatchExtrinsic", None, "unsub")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn subscriptions_are_retried_after_reconnecting() {
+        let (client, connections) = flaky_client(true).await;
+
+        let items: Vec<String> = client
+            .subscribe_raw("chain_subscribeNewHeads", None, "unsub")
+            .await
+            .unwrap()
+            .map(|item| item.unwrap().get().to_owned())
+            .collect()
+            .await;
+
+        // The first connection failed, so we subscribed on the second one.
+        assert_eq!(items, vec!["[1,0]", "[1,1]"]);
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn submitting_extrinsics_is_not_retried() {
+        let (client, connections) = flaky_client(true).await;
+
+        // The node may have received these before the connection was lost.
+        let res = client.request_raw("author_submitExtrinsic", None).await;
+        assert!(matches!(res, Err(RpcError::ConnectionLost(_))));
+        let res = client
+            .batch_request_raw(vec![("foo", None), ("author_submitExtrinsic", None)])
+            .await;
+        assert!(matches!(res, Err(RpcError::ConnectionLost(_))));
+        let res = client
+            .subscribe_raw("author_submitAndWatchExtrinsic", None, "unsub")
+            .await;
+        assert!(matches!(res, Err(RpcError::ConnectionLost(_))));
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        // Anything else reconnects as usual.
+        let res = client.request_raw("foo", None).await.unwrap();
+        assert_eq!(res.get(), "1");
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,609 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{
+    RawValue,
+    RpcClientT,
+    RpcFuture,
+    RpcSubscription,
+};
+use crate::error::{
+    Error,
+    RpcError,
+};
+use futures::{
+    stream,
+    StreamExt,
+};
+use parking_lot::Mutex;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    fs::File,
+    io::{
+        BufRead,
+        BufReader,
+        Write,
+    },
+    path::Path,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+/// A single line in a recording. Each line of a recording file is one of these,
+/// serialized to JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RecordedEntry {
+    /// A request and the response that was given back for it.
+    Request {
+        method: String,
+        params: Option<Value>,
+        result: RecordedResult,
+    },
+    /// An attempt to subscribe. Subsequent notifications refer back to this by `id`.
+    /// `subscription_id` is the ID that the node gave the subscription, if it was known.
+    Subscribe {
+        id: usize,
+        method: String,
+        params: Option<Value>,
+        result: RecordedResult,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        subscription_id: Option<String>,
+    },
+    /// A notification received on some subscription.
+    Notification { id: usize, result: RecordedResult },
+}
+
+/// Errors can't be recorded faithfully, since they are generic over the client
+/// that produced them, so we just hang on to the message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedResult {
+    Ok(Value),
+    Err(String),
+}
+
+impl RecordedResult {
+    fn from_result<T>(res: &Result<T, RpcError>, to_value: impl Fn(&T) -> Value) -> Self {
+        match res {
+            Ok(val) => RecordedResult::Ok(to_value(val)),
+            Err(e) => RecordedResult::Err(e.to_string()),
+        }
+    }
+
+    fn into_raw_result(self) -> Result<Box<RawValue>, RpcError> {
+        match self {
+            RecordedResult::Ok(val) => {
+                serde_json::value::to_raw_value(&val)
+                    .map_err(|e| RpcError::ClientError(Box::new(e)))
+            }
+            RecordedResult::Err(e) => Err(RpcError::ClientError(e.into())),
+        }
+    }
+}
+
+/// An [`RpcClientT`] implementation which wraps some other [`RpcClientT`], and records every
+/// request and response, along with every subscription notification, to a file as they happen.
+///
+/// The resulting file can be handed to [`ReplayRpcClient`] in order to replay the exact same
+/// responses back again without a node, which is handy for turning some problem seen in the
+/// wild into a deterministic test case.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use subxt::{
+///     rpc::{ ws_client, RecordingRpcClient },
+///     OnlineClient,
+///     PolkadotConfig,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let client = ws_client("ws://127.0.0.1:9944").await.unwrap();
+/// let recorder = RecordingRpcClient::new(client, "recording.jsonl").unwrap();
+///
+/// // Everything this client does over RPC will be written to `recording.jsonl`:
+/// let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(recorder))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub struct RecordingRpcClient<R> {
+    client: R,
+    recorder: Arc<Recorder>,
+}
+
+impl<R> std::fmt::Debug for RecordingRpcClient<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordingRpcClient").finish()
+    }
+}
+
+struct Recorder {
+    file: Mutex<File>,
+    next_subscription_id: AtomicUsize,
+}
+
+impl Recorder {
+    fn record(&self, entry: &RecordedEntry) {
+        let mut line = serde_json::to_vec(entry)
+            .expect("recorded entries can be serialized to JSON");
+        line.push(b'\n');
+        // Failing to record shouldn't get in the way of whatever we're recording.
+        if let Err(e) = self.file.lock().write_all(&line) {
+            tracing::warn!("Failed to record RPC traffic: {}", e);
+        }
+    }
+}
+
+impl<R: RpcClientT> RecordingRpcClient<R> {
+    /// Wrap the provided client, recording everything to a new file at the given path.
+    /// If a file already exists at that path, it will be overwritten.
+    pub fn new(client: R, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let file = File::create(path)?;
+        Ok(RecordingRpcClient {
+            client,
+            recorder: Arc::new(Recorder {
+                file: Mutex::new(file),
+                next_subscription_id: AtomicUsize::new(0),
+            }),
+        })
+    }
+}
+
+impl<R: RpcClientT> RpcClientT for RecordingRpcClient<R> {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let recorded_params = params_to_value(&params)?;
+            let res = self.client.request_raw(method, params).await;
+
+            self.recorder.record(&RecordedEntry::Request {
+                method: method.to_owned(),
+                params: recorded_params,
+                result: RecordedResult::from_result(&res, |raw| raw_to_value(raw)),
+            });
+            res
+        })
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        batch: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RpcFuture<'a, Vec<Box<RawValue>>> {
+        Box::pin(async move {
+            let recorded_requests = batch
+                .iter()
+                .map(|(method, params)| {
+                    Ok((method.to_string(), params_to_value(params)?))
+                })
+                .collect::<Result<Vec<_>, RpcError>>()?;
+            let num_requests = recorded_requests.len();
+            let res = self.client.batch_request_raw(batch).await.and_then(|results| {
+                if results.len() == num_requests {
+                    Ok(results)
+                } else {
+                    Err(RpcError::ClientError(
+                        format!(
+                            "Expected {num_requests} responses to a batch request but got {}",
+                            results.len()
+                        )
+                        .into(),
+                    ))
+                }
+            });
+
+            // Record each request on its own, so that replaying doesn't depend on
+            // how the requests happened to be batched up.
+            let results = match &res {
+                Ok(results) => {
+                    results
+                        .iter()
+                        .map(|raw| RecordedResult::Ok(raw_to_value(raw)))
+                        .collect()
+                }
+                Err(e) => vec![RecordedResult::Err(e.to_string()); num_requests],
+            };
+            for ((method, params), result) in recorded_requests.into_iter().zip(results) {
+                self.recorder.record(&RecordedEntry::Request {
+                    method,
+                    params,
+                    result,
+                });
+            }
+            res
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(async move {
+            let (_, sub) = self.subscribe_raw_with_id(sub, params, unsub).await?;
+            Ok(sub)
+        })
+    }
+
+    fn subscribe_raw_with_id<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, (Option<String>, RpcSubscription)> {
+        Box::pin(async move {
+            let recorded_params = params_to_value(&params)?;
+            let res = self.client.subscribe_raw_with_id(sub, params, unsub).await;
+
+            let id = self
+                .recorder
+                .next_subscription_id
+                .fetch_add(1, Ordering::Relaxed);
+            self.recorder.record(&RecordedEntry::Subscribe {
+                id,
+                method: sub.to_owned(),
+                params: recorded_params,
+                result: RecordedResult::from_result(&res, |_| Value::Null),
+                subscription_id: res.as_ref().ok().and_then(|(sub_id, _)| sub_id.clone()),
+            });
+
+            let (subscription_id, sub) = res?;
+            let recorder = self.recorder.clone();
+            let sub = sub.inspect(move |item| {
+                recorder.record(&RecordedEntry::Notification {
+                    id,
+                    result: RecordedResult::from_result(item, |raw| raw_to_value(raw)),
+                })
+            });
+            Ok((subscription_id, sub.boxed()))
+        })
+    }
+}
+
+/// An [`RpcClientT`] implementation which replays a recording made by [`RecordingRpcClient`].
+///
+/// Each request is answered with the next response that was recorded for the same method
+/// and parameters, and each subscription hands back the notifications that were recorded
+/// for the next matching subscription before ending. Requests that weren't recorded will
+/// return an error.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use subxt::{
+///     rpc::ReplayRpcClient,
+///     OnlineClient,
+///     PolkadotConfig,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let replay = ReplayRpcClient::from_file("recording.jsonl").unwrap();
+///
+/// // This client will see exactly the same responses as were recorded:
+/// let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(replay))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub struct ReplayRpcClient {
+    requests: Mutex<HashMap<RequestKey, VecDeque<RecordedResult>>>,
+    subscriptions: Mutex<HashMap<RequestKey, VecDeque<RecordedSubscription>>>,
+}
+
+impl std::fmt::Debug for ReplayRpcClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplayRpcClient").finish()
+    }
+}
+
+// Requests are matched up by method name and (serialized) parameters.
+type RequestKey = (String, Option<String>);
+
+struct RecordedSubscription {
+    result: RecordedResult,
+    subscription_id: Option<String>,
+    notifications: Vec<RecordedResult>,
+}
+
+impl ReplayRpcClient {
+    /// Load a recording made by [`RecordingRpcClient`] from the given path.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let file = File::open(path)?;
+
+        let mut requests: HashMap<_, VecDeque<_>> = HashMap::new();
+        let mut subscriptions: HashMap<usize, (RequestKey, RecordedSubscription)> =
+            HashMap::new();
+        let mut subscription_order = Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue
+            }
+            match serde_json::from_str(&line)? {
+                RecordedEntry::Request {
+                    method,
+                    params,
+                    result,
+                } => {
+                    requests
+                        .entry(request_key(method, params))
+                        .or_default()
+                        .push_back(result);
+                }
+                RecordedEntry::Subscribe {
+                    id,
+                    method,
+                    params,
+                    result,
+                    subscription_id,
+                } => {
+                    let sub = RecordedSubscription {
+                        result,
+                        subscription_id,
+                        notifications: Vec::new(),
+                    };
+                    subscriptions.insert(id, (request_key(method, params), sub));
+                    subscription_order.push(id);
+                }
+                RecordedEntry::Notification { id, result } => {
+                    let (_, sub) = subscriptions.get_mut(&id).ok_or_else(|| {
+                        Error::Other(format!(
+                            "Notification recorded for unknown subscription {id}"
+                        ))
+                    })?;
+                    sub.notifications.push(result);
+                }
+            }
+        }
+
+        // Subscriptions are handed out in the order that they were originally made.
+        let mut subscriptions_by_key: HashMap<_, VecDeque<_>> = HashMap::new();
+        for id in subscription_order {
+            let (key, sub) = subscriptions.remove(&id).expect("id was inserted above");
+            subscriptions_by_key.entry(key).or_default().push_back(sub);
+        }
+
+        Ok(ReplayRpcClient {
+            requests: Mutex::new(requests),
+            subscriptions: Mutex::new(subscriptions_by_key),
+        })
+    }
+}
+
+impl RpcClientT for ReplayRpcClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let key = request_key(method.to_owned(), params_to_value(&params)?);
+            let result = self
+                .requests
+                .lock()
+                .get_mut(&key)
+                .and_then(|results| results.pop_front())
+                .ok_or_else(|| not_recorded("request", &key))?;
+            result.into_raw_result()
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription> {
+        Box::pin(async move {
+            let (_, sub) = self.subscribe_raw_with_id(sub, params, unsub).await?;
+            Ok(sub)
+        })
+    }
+
+    fn subscribe_raw_with_id<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        _unsub: &'a str,
+    ) -> RpcFuture<'a, (Option<String>, RpcSubscription)> {
+        Box::pin(async move {
+            let key = request_key(sub.to_owned(), params_to_value(&params)?);
+            let sub = self
+                .subscriptions
+                .lock()
+                .get_mut(&key)
+                .and_then(|subs| subs.pop_front())
+                .ok_or_else(|| not_recorded("subscription", &key))?;
+
+            sub.result.into_raw_result()?;
+            let notifications = sub
+                .notifications
+                .into_iter()
+                .map(RecordedResult::into_raw_result);
+            Ok((sub.subscription_id, stream::iter(notifications).boxed()))
+        })
+    }
+}
+
+fn request_key(method: String, params: Option<Value>) -> RequestKey {
+    (method, params.map(|p| p.to_string()))
+}
+
+fn not_recorded(kind: &str, (method, params): &RequestKey) -> RpcError {
+    let params = params.as_deref().unwrap_or("no params");
+    RpcError::ClientError(
+        format!("No {kind} to `{method}` with {params} was recorded").into(),
+    )
+}
+
+fn params_to_value(params: &Option<Box<RawValue>>) -> Result<Option<Value>, RpcError> {
+    params
+        .as_ref()
+        .map(|p| serde_json::from_str(p.get()))
+        .transpose()
+        .map_err(|e| RpcError::ClientError(Box::new(e)))
+}
+
+fn raw_to_value(raw: &RawValue) -> Value {
+    serde_json::from_str(raw.get()).expect("RPC responses are valid JSON")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        rpc::MockNode,
+        OnlineClient,
+        PolkadotConfig,
+    };
+
+    #[tokio::test]
+    async fn replays_what_was_recorded() {
+        let path = std::env::temp_dir()
+            .join(format!("subxt-recording-{}.jsonl", std::process::id()));
+
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        node.set_storage(b"key".to_vec(), b"value".to_vec());
+        node.produce_block();
+
+        // Record some requests and a subscription.
+        let recorder = RecordingRpcClient::new(node.clone(), &path).unwrap();
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(recorder))
+            .await
+            .unwrap();
+        let value = api.storage().fetch_raw(b"key", None).await.unwrap();
+        let mut sub = api.rpc().subscribe_finalized_blocks().await.unwrap();
+        let header = sub.next().await.unwrap().unwrap();
+
+        // Replay them, without the node this time.
+        let replay = ReplayRpcClient::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(replay))
+            .await
+            .unwrap();
+
+        assert_eq!(api.storage().fetch_raw(b"key", None).await.unwrap(), value);
+        let mut sub = api.rpc().subscribe_finalized_blocks().await.unwrap();
+        assert_eq!(sub.next().await.unwrap().unwrap(), header);
+        assert!(sub.next().await.is_none());
+
+        // We didn't record a second storage request, so this should fail.
+        assert!(api.storage().fetch_raw(b"key", None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn replays_subscription_ids() {
+        let path = std::env::temp_dir().join(format!(
+            "subxt-subscription-ids-{}.jsonl",
+            std::process::id()
+        ));
+
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+
+        let params = || crate::rpc_params![false].build();
+        let recorder = RecordingRpcClient::new(node, &path).unwrap();
+        let (id, _) = recorder
+            .subscribe_raw_with_id(
+                "chainHead_unstable_follow",
+                params(),
+                "chainHead_unstable_unfollow",
+            )
+            .await
+            .unwrap();
+        assert!(id.is_some());
+
+        let replay = ReplayRpcClient::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let (replayed_id, _) = replay
+            .subscribe_raw_with_id(
+                "chainHead_unstable_follow",
+                params(),
+                "chainHead_unstable_unfollow",
+            )
+            .await
+            .unwrap();
+        assert_eq!(replayed_id, id);
+    }
+
+    /// Hands back one fewer response than it was asked for in a batch.
+    struct ShortBatchClient;
+
+    impl RpcClientT for ShortBatchClient {
+        fn request_raw<'a>(
+            &'a self,
+            _method: &'a str,
+            _params: Option<Box<RawValue>>,
+        ) -> RpcFuture<'a, Box<RawValue>> {
+            Box::pin(async { Ok(RawValue::from_string("1".to_owned()).unwrap()) })
+        }
+
+        fn batch_request_raw<'a>(
+            &'a self,
+            batch: Vec<(&'a str, Option<Box<RawValue>>)>,
+        ) -> RpcFuture<'a, Vec<Box<RawValue>>> {
+            Box::pin(async move {
+                Ok((1..batch.len())
+                    .map(|_| RawValue::from_string("1".to_owned()).unwrap())
+                    .collect())
+            })
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            _sub: &'a str,
+            _params: Option<Box<RawValue>>,
+            _unsub: &'a str,
+        ) -> RpcFuture<'a, RpcSubscription> {
+            Box::pin(async { Ok(stream::empty().boxed()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn records_short_batch_responses_as_errors() {
+        let path = std::env::temp_dir()
+            .join(format!("subxt-short-batch-{}.jsonl", std::process::id()));
+
+        let recorder = RecordingRpcClient::new(ShortBatchClient, &path).unwrap();
+        let res = recorder
+            .batch_request_raw(vec![("foo", None), ("bar", None)])
+            .await;
+        assert!(res.is_err());
+
+        // Both requests were recorded, and both replay as errors.
+        let replay = ReplayRpcClient::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(replay.request_raw("foo", None).await.is_err());
+        assert!(replay.request_raw("bar", None).await.is_err());
+        assert!(replay
+            .requests
+            .lock()
+            .values()
+            .all(|results| results.is_empty()));
+    }
+}
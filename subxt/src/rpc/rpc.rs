@@ -2,43 +2,6 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-//! RPC types and client for interacting with a substrate node.
-//!
-//! This is used behind the scenes by various `subxt` APIs, but can
-//! also be used directly.
-//!
-//! # Example
-//!
-//! Fetching storage keys
-//!
-//! ```no_run
-//! use subxt::{ PolkadotConfig, OnlineClient, storage::StorageKey };
-//!
-//! #[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata.scale")]
-//! pub mod polkadot {}
-//!
-//! # #[tokio::main]
-//! # async fn main() {
-//! let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
-//!
-//! let key = polkadot::storage()
-//!     .xcm_pallet()
-//!     .version_notifiers_root()
-//!     .to_bytes();
-//!
-//! // Fetch up to 10 keys.
-//! let keys = api
-//!     .rpc()
-//!     .storage_keys_paged(&key, 10, None, None)
-//!     .await
-//!     .unwrap();
-//!
-//! for key in keys.iter() {
-//!     println!("Key: 0x{}", hex::encode(&key));
-//! }
-//! # }
-//! ```
-
 // jsonrpsee subscriptions are interminable.
 // Allows `while let status = subscription.next().await {}`
 // Related: https://github.com/paritytech/subxt/issues/66
@@ -49,6 +12,13 @@ use std::{
     sync::Arc,
 };
 
+use super::{
+    rpc_params,
+    BatchRequest,
+    RpcClient,
+    RpcClientT,
+    Subscription,
+};
 use crate::{
     error::Error,
     utils::PhantomDataSendSync,
@@ -60,29 +30,6 @@ use codec::{
     Encode,
 };
 use frame_metadata::RuntimeMetadataPrefixed;
-pub use jsonrpsee::{
-    client_transport::ws::{
-        InvalidUri,
-        Receiver as WsReceiver,
-        Sender as WsSender,
-        Uri,
-        WsTransportClientBuilder,
-    },
-    core::{
-        client::{
-            Client as RpcClient,
-            ClientBuilder as RpcClientBuilder,
-            ClientT,
-            Subscription,
-            SubscriptionClientT,
-        },
-        to_json_value,
-        DeserializeOwned,
-        Error as RpcError,
-        JsonValue,
-    },
-    rpc_params,
-};
 use serde::{
     Deserialize,
     Serialize,
@@ -261,10 +208,168 @@ pub struct Health {
     pub should_have_peers: bool,
 }
 
+/// The class of a dispatchable call, as reported by `payment_queryInfo`.
+///
+/// # Note
+///
+/// This is copied from `frame-support` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DispatchClass {
+    /// A normal dispatch.
+    Normal,
+    /// An operational dispatch.
+    Operational,
+    /// A mandatory dispatch, which is always included regardless of its weight.
+    Mandatory,
+}
+
+/// The weight of a dispatchable call, as reported by `payment_queryInfo`.
+///
+/// Nodes from before weights became two dimensional report a single number, which is
+/// deserialized as the `ref_time`, leaving the `proof_size` as `0`.
+///
+/// # Note
+///
+/// This is copied from `sp-weights` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Weight {
+    /// The computational time used to execute the call.
+    pub ref_time: u64,
+    /// The size of the proof needed to execute the call.
+    pub proof_size: u64,
+}
+
+impl<'de> Deserialize<'de> for Weight {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum AnyWeight {
+            Legacy(u64),
+            V2 {
+                ref_time: u64,
+                #[serde(default)]
+                proof_size: u64,
+            },
+        }
+
+        Ok(match AnyWeight::deserialize(deserializer)? {
+            AnyWeight::Legacy(ref_time) => {
+                Weight {
+                    ref_time,
+                    proof_size: 0,
+                }
+            }
+            AnyWeight::V2 {
+                ref_time,
+                proof_size,
+            } => {
+                Weight {
+                    ref_time,
+                    proof_size,
+                }
+            }
+        })
+    }
+}
+
+/// Information about a dispatchable call, returned by the `payment_queryInfo` RPC.
+///
+/// # Note
+///
+/// This is copied from `pallet-transaction-payment-rpc-runtime-api` to avoid a dependency
+/// on that crate. Therefore it must be kept compatible with that type from the target
+/// substrate version.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeDispatchInfo {
+    /// The weight of the call.
+    pub weight: Weight,
+    /// The class of the call.
+    pub class: DispatchClass,
+    /// The inclusion fee of the call, not including any tip. This is "partial" because
+    /// the final fee may differ once the call has been executed, for instance if it
+    /// reports a lower actual weight.
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub partial_fee: u128,
+}
+
+/// The breakdown of the fees for a call, returned by the `payment_queryFeeDetails` RPC.
+///
+/// # Note
+///
+/// This is copied from `pallet-transaction-payment` to avoid a dependency on that crate.
+/// Therefore it must be kept compatible with that type from the target substrate version.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeDetails {
+    /// The fee for including the call in a block. This is `None` for unsigned calls,
+    /// which pay no fees.
+    pub inclusion_fee: Option<InclusionFee>,
+}
+
+/// The part of the fee which must be paid for a call to be included in a block.
+///
+/// # Note
+///
+/// This is copied from `pallet-transaction-payment` to avoid a dependency on that crate.
+/// Therefore it must be kept compatible with that type from the target substrate version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionFee {
+    /// The minimum fee which every call pays.
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub base_fee: u128,
+    /// The fee for the length of the encoded call, in bytes.
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub len_fee: u128,
+    /// The fee for the weight of the call, adjusted according to how congested
+    /// the chain is.
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub adjusted_weight_fee: u128,
+}
+
+impl InclusionFee {
+    /// The total inclusion fee; the sum of each of its parts.
+    pub fn inclusion_fee(&self) -> u128 {
+        self.base_fee
+            .saturating_add(self.len_fee)
+            .saturating_add(self.adjusted_weight_fee)
+    }
+}
+
+// Balances are handed back as decimal strings by `payment_queryInfo` and as hex strings
+// by `payment_queryFeeDetails`, and may be plain numbers on older nodes.
+fn deserialize_balance<'de, D>(deserializer: D) -> Result<u128, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Balance {
+        Number(u64),
+        String(String),
+    }
+
+    let balance = match Balance::deserialize(deserializer)? {
+        Balance::Number(n) => return Ok(n.into()),
+        Balance::String(s) => s,
+    };
+    let parsed = match balance.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => balance.parse(),
+    };
+    parsed.map_err(|_| serde::de::Error::custom(format!("invalid balance: {balance}")))
+}
+
 /// Client for substrate rpc interfaces
 pub struct Rpc<T: Config> {
-    /// Rpc client for sending requests.
-    pub client: Arc<RpcClient>,
+    client: RpcClient,
     _marker: PhantomDataSendSync<T>,
 }
 
@@ -277,15 +382,49 @@ impl<T: Config> Clone for Rpc<T> {
     }
 }
 
+// Expose subscribe/request, and also subscribe_raw/request_raw
+// from the even-deeper `dyn RpcClientT` impl.
+impl<T: Config> std::ops::Deref for Rpc<T> {
+    type Target = RpcClient;
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
 impl<T: Config> Rpc<T> {
     /// Create a new [`Rpc`]
-    pub fn new(client: RpcClient) -> Self {
+    pub fn new<R: RpcClientT>(client: Arc<R>) -> Self {
         Self {
-            client: Arc::new(client),
+            client: RpcClient::new(client),
             _marker: PhantomDataSendSync::new(),
         }
     }
 
+    /// Build a batch of requests to send to the node in one go. This is useful when making
+    /// many independent calls, since they will all be answered in a single round trip.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use subxt::{ rpc::rpc_params, PolkadotConfig, OnlineClient };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+    ///
+    /// let mut batch = api.rpc().batch();
+    /// let chain = batch.add::<String>("system_chain", rpc_params![]);
+    /// let genesis = batch.add::<Option<sp_core::H256>>("chain_getBlockHash", rpc_params![0]);
+    /// let res = batch.send().await.unwrap();
+    ///
+    /// println!("Chain: {}", res.get(chain).unwrap());
+    /// println!("Genesis hash: {:?}", res.get(genesis).unwrap());
+    /// # }
+    /// ```
+    pub fn batch(&self) -> BatchRequest {
+        BatchRequest::new(self.client.clone())
+    }
+
     /// Fetch the raw bytes for a given storage key
     pub async fn storage(
         &self,
@@ -322,10 +461,7 @@ impl<T: Config> Rpc<T> {
     ) -> Result<Vec<StorageChangeSet<T::Hash>>, Error> {
         let keys: Vec<String> = keys.into_iter().map(to_hex).collect();
         let params = rpc_params![keys, from, to];
-        self.client
-            .request("state_queryStorage", params)
-            .await
-            .map_err(Into::into)
+        self.client.request("state_queryStorage", params).await
     }
 
     /// Query historical storage entries
@@ -336,10 +472,7 @@ impl<T: Config> Rpc<T> {
     ) -> Result<Vec<StorageChangeSet<T::Hash>>, Error> {
         let keys: Vec<String> = keys.into_iter().map(to_hex).collect();
         let params = rpc_params![keys, at];
-        self.client
-            .request("state_queryStorageAt", params)
-            .await
-            .map_err(Into::into)
+        self.client.request("state_queryStorageAt", params).await
     }
 
     /// Fetch the genesis hash
@@ -364,30 +497,29 @@ impl<T: Config> Rpc<T> {
 
     /// Fetch system properties
     pub async fn system_properties(&self) -> Result<SystemProperties, Error> {
-        Ok(self
-            .client
+        self.client
             .request("system_properties", rpc_params![])
-            .await?)
+            .await
     }
 
     /// Fetch system health
     pub async fn system_health(&self) -> Result<Health, Error> {
-        Ok(self.client.request("system_health", rpc_params![]).await?)
+        self.client.request("system_health", rpc_params![]).await
     }
 
     /// Fetch system chain
     pub async fn system_chain(&self) -> Result<String, Error> {
-        Ok(self.client.request("system_chain", rpc_params![]).await?)
+        self.client.request("system_chain", rpc_params![]).await
     }
 
     /// Fetch system name
     pub async fn system_name(&self) -> Result<String, Error> {
-        Ok(self.client.request("system_name", rpc_params![]).await?)
+        self.client.request("system_name", rpc_params![]).await
     }
 
     /// Fetch system version
     pub async fn system_version(&self) -> Result<String, Error> {
-        Ok(self.client.request("system_version", rpc_params![]).await?)
+        self.client.request("system_version", rpc_params![]).await
     }
 
     /// Fetch the current nonce for the given account ID.
@@ -395,10 +527,9 @@ impl<T: Config> Rpc<T> {
         &self,
         account: &T::AccountId,
     ) -> Result<T::Index, Error> {
-        Ok(self
-            .client
+        self.client
             .request("system_accountNextIndex", rpc_params![account])
-            .await?)
+            .await
     }
 
     /// Get a header
@@ -523,6 +654,25 @@ impl<T: Config> Rpc<T> {
         Ok(subscription)
     }
 
+    /// Subscribe to changes in the values at the given storage keys. The first notification
+    /// hands back the current values of all of the keys, and subsequent notifications hand
+    /// back the values of any keys which changed in each new block.
+    pub async fn subscribe_storage(
+        &self,
+        keys: impl IntoIterator<Item = &[u8]>,
+    ) -> Result<Subscription<StorageChangeSet<T::Hash>>, Error> {
+        let keys: Vec<String> = keys.into_iter().map(to_hex).collect();
+        let subscription = self
+            .client
+            .subscribe(
+                "state_subscribeStorage",
+                rpc_params![keys],
+                "state_unsubscribeStorage",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
     /// Create and submit an extrinsic and return corresponding Hash if successful
     pub async fn submit_extrinsic<X: Encode>(
         &self,
@@ -563,16 +713,17 @@ impl<T: Config> Rpc<T> {
         public: Bytes,
     ) -> Result<(), Error> {
         let params = rpc_params![key_type, suri, public];
-        self.client.request("author_insertKey", params).await?;
+        self.client
+            .request::<()>("author_insertKey", params)
+            .await?;
         Ok(())
     }
 
     /// Generate new session keys and returns the corresponding public keys.
     pub async fn rotate_keys(&self) -> Result<Bytes, Error> {
-        Ok(self
-            .client
+        self.client
             .request("author_rotateKeys", rpc_params![])
-            .await?)
+            .await
     }
 
     /// Checks if the keystore has private keys for the given session public keys.
@@ -582,7 +733,7 @@ impl<T: Config> Rpc<T> {
     /// Returns `true` iff all private keys could be found.
     pub async fn has_session_keys(&self, session_keys: Bytes) -> Result<bool, Error> {
         let params = rpc_params![session_keys];
-        Ok(self.client.request("author_hasSessionKeys", params).await?)
+        self.client.request("author_hasSessionKeys", params).await
     }
 
     /// Checks if the keystore has private keys for the given public key and key type.
@@ -594,7 +745,7 @@ impl<T: Config> Rpc<T> {
         key_type: String,
     ) -> Result<bool, Error> {
         let params = rpc_params![public_key, key_type];
-        Ok(self.client.request("author_hasKey", params).await?)
+        self.client.request("author_hasKey", params).await
     }
 
     /// Submits the extrinsic to the dry_run RPC, to test if it would succeed.
@@ -611,24 +762,26 @@ impl<T: Config> Rpc<T> {
             codec::Decode::decode(&mut result_bytes.0.as_slice())?;
         Ok(data)
     }
-}
 
-/// Build WS RPC client from URL
-pub async fn ws_client(url: &str) -> Result<RpcClient, RpcError> {
-    let (sender, receiver) = ws_transport(url).await?;
-    Ok(RpcClientBuilder::default()
-        .max_notifs_per_subscription(4096)
-        .build_with_tokio(sender, receiver))
-}
+    /// Fetch the weight, class and partial fee of an encoded extrinsic.
+    pub async fn payment_query_info(
+        &self,
+        encoded_signed: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<RuntimeDispatchInfo, Error> {
+        let params = rpc_params![to_hex(encoded_signed), at];
+        self.client.request("payment_queryInfo", params).await
+    }
 
-async fn ws_transport(url: &str) -> Result<(WsSender, WsReceiver), RpcError> {
-    let url: Uri = url
-        .parse()
-        .map_err(|e: InvalidUri| RpcError::Transport(e.into()))?;
-    WsTransportClientBuilder::default()
-        .build(url)
-        .await
-        .map_err(|e| RpcError::Transport(e.into()))
+    /// Fetch a breakdown of the inclusion fee of an encoded extrinsic.
+    pub async fn payment_query_fee_details(
+        &self,
+        encoded_signed: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<FeeDetails, Error> {
+        let params = rpc_params![to_hex(encoded_signed), at];
+        self.client.request("payment_queryFeeDetails", params).await
+    }
 }
 
 fn to_hex(bytes: impl AsRef<[u8]>) -> String {
@@ -638,6 +791,11 @@ fn to_hex(bytes: impl AsRef<[u8]>) -> String {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::rpc::{
+        RawValue,
+        RpcFuture,
+        RpcSubscription,
+    };
 
     #[test]
     fn test_deser_runtime_version() {
@@ -664,4 +822,129 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn test_deser_payment_info() {
+        let val: RuntimeDispatchInfo = serde_json::from_str(
+            r#"{
+            "weight": 152822000,
+            "class": "normal",
+            "partialFee": "15600000001"
+        }"#,
+        )
+        .expect("deserializing failed");
+        assert_eq!(
+            val,
+            RuntimeDispatchInfo {
+                weight: Weight {
+                    ref_time: 152822000,
+                    proof_size: 0,
+                },
+                class: DispatchClass::Normal,
+                partial_fee: 15600000001,
+            }
+        );
+
+        let val: RuntimeDispatchInfo = serde_json::from_str(
+            r#"{
+            "weight": { "ref_time": 152822000, "proof_size": 3593 },
+            "class": "operational",
+            "partialFee": "15600000001"
+        }"#,
+        )
+        .expect("deserializing failed");
+        assert_eq!(
+            val.weight,
+            Weight {
+                ref_time: 152822000,
+                proof_size: 3593,
+            }
+        );
+        assert_eq!(val.class, DispatchClass::Operational);
+
+        let val: FeeDetails = serde_json::from_str(
+            r#"{
+            "inclusionFee": {
+                "baseFee": "0x3b9aca00",
+                "lenFee": 1440000,
+                "adjustedWeightFee": "152822000"
+            }
+        }"#,
+        )
+        .expect("deserializing failed");
+        let fee = val.inclusion_fee.unwrap();
+        assert_eq!(
+            fee,
+            InclusionFee {
+                base_fee: 1_000_000_000,
+                len_fee: 1_440_000,
+                adjusted_weight_fee: 152_822_000,
+            }
+        );
+        assert_eq!(fee.inclusion_fee(), 1_154_262_000);
+
+        let val: FeeDetails =
+            serde_json::from_str(r#"{ "inclusionFee": null }"#).unwrap();
+        assert_eq!(val.inclusion_fee, None);
+    }
+
+    /// A minimal [`RpcClientT`] which hands back canned responses, to
+    /// check that [`Rpc`] routes everything through the trait.
+    struct CannedClient;
+
+    impl RpcClientT for CannedClient {
+        fn request_raw<'a>(
+            &'a self,
+            method: &'a str,
+            params: Option<Box<RawValue>>,
+        ) -> RpcFuture<'a, Box<RawValue>> {
+            Box::pin(async move {
+                assert_eq!(method, "chain_getBlockHash");
+                assert_eq!(params.expect("params expected").get(), "[0]");
+                let hash = sp_core::H256::repeat_byte(1);
+                Ok(serde_json::value::to_raw_value(&hash).unwrap())
+            })
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            sub: &'a str,
+            params: Option<Box<RawValue>>,
+            unsub: &'a str,
+        ) -> RpcFuture<'a, RpcSubscription> {
+            Box::pin(async move {
+                assert_eq!(sub, "state_subscribeRuntimeVersion");
+                assert_eq!(unsub, "state_unsubscribeRuntimeVersion");
+                assert!(params.is_none());
+                let version = RawValue::from_string(
+                    r#"{ "specVersion": 1, "transactionVersion": 2 }"#.to_owned(),
+                )
+                .unwrap();
+                let sub: RpcSubscription =
+                    Box::pin(futures::stream::iter(vec![Ok(version)]));
+                Ok(sub)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_rpc_client_is_used() {
+        let rpc = Rpc::<crate::SubstrateConfig>::new(Arc::new(CannedClient));
+
+        let genesis_hash = rpc.genesis_hash().await.expect("request should work");
+        assert_eq!(genesis_hash, sp_core::H256::repeat_byte(1));
+
+        let mut sub = rpc
+            .subscribe_runtime_version()
+            .await
+            .expect("subscription should work");
+        let version = sub
+            .next()
+            .await
+            .expect("one item expected")
+            .expect("item should decode");
+        assert_eq!(version.spec_version, 1);
+        assert_eq!(version.transaction_version, 2);
+        assert!(sub.next().await.is_none());
+    }
 }
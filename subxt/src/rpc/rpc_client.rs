@@ -0,0 +1,357 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{
+    RpcClientT,
+    RpcSubscription,
+};
+use crate::error::Error;
+use futures::{
+    Stream,
+    StreamExt,
+};
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+use serde_json::value::RawValue;
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::Poll,
+};
+
+/// A concrete wrapper around an [`RpcClientT`] which exposes the underlying interface via some
+/// higher level methods that make it a little more ergonomic to work with.
+///
+/// Wrapping [`RpcClientT`] in this way is simply a way to expose this additional functionality
+/// without getting into issues with non-object-safe methods or no `async` in traits.
+#[derive(Clone)]
+pub struct RpcClient(Arc<dyn RpcClientT>);
+
+impl RpcClient {
+    /// Create a new [`RpcClient`] from some shared [`RpcClientT`] implementation.
+    pub fn new<R: RpcClientT>(client: Arc<R>) -> Self {
+        RpcClient(client)
+    }
+
+    /// Make an RPC request, given a method name and some parameters.
+    ///
+    /// See [`RpcParams`] and the [`crate::rpc_params!`] macro for an example of how to
+    /// construct the parameters.
+    pub async fn request<Res: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: RpcParams,
+    ) -> Result<Res, Error> {
+        let res = self.0.request_raw(method, params.build()).await?;
+        let val = serde_json::from_str(res.get())?;
+        Ok(val)
+    }
+
+    /// Subscribe to an RPC endpoint, providing the parameters and the method to call to
+    /// unsubscribe from it again.
+    ///
+    /// See [`RpcParams`] and the [`crate::rpc_params!`] macro for an example of how to
+    /// construct the parameters.
+    pub async fn subscribe<Res: DeserializeOwned>(
+        &self,
+        sub: &str,
+        params: RpcParams,
+        unsub: &str,
+    ) -> Result<Subscription<Res>, Error> {
+        let sub = self.0.subscribe_raw(sub, params.build(), unsub).await?;
+        Ok(Subscription::new(sub))
+    }
+
+    /// Subscribe to an RPC endpoint, as with [`RpcClient::subscribe()`], but also keep
+    /// hold of the ID that the node assigned to the subscription (if the underlying
+    /// [`RpcClientT`] is able to provide it). See [`Subscription::subscription_id()`].
+    pub async fn subscribe_with_id<Res: DeserializeOwned>(
+        &self,
+        sub: &str,
+        params: RpcParams,
+        unsub: &str,
+    ) -> Result<Subscription<Res>, Error> {
+        let (id, sub) = self
+            .0
+            .subscribe_raw_with_id(sub, params.build(), unsub)
+            .await?;
+        Ok(Subscription {
+            inner: sub,
+            id,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
+
+impl std::fmt::Debug for RpcClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RpcClient").finish()
+    }
+}
+
+impl std::ops::Deref for RpcClient {
+    type Target = dyn RpcClientT;
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+/// Create some [`RpcParams`] to pass to our [`RpcClient`]. [`RpcParams`]
+/// simply enforces that parameters handed to our [`RpcClient`] methods
+/// are the correct shape.
+///
+/// As with the [`serde_json::json!`] macro, this will panic if you provide
+/// parameters which cannot successfully be serialized to JSON.
+///
+/// # Example
+///
+/// ```rust
+/// use subxt::rpc::{ rpc_params, RpcParams };
+///
+/// // If you provide no params you get `None` back
+/// let params: RpcParams = rpc_params![];
+/// assert!(params.build().is_none());
+///
+/// // If you provide params you get `Some<Box<RawValue>>` back.
+/// let params: RpcParams = rpc_params![1, true, "foo"];
+/// assert_eq!(params.build().unwrap().get(), "[1,true,\"foo\"]");
+/// ```
+#[macro_export]
+macro_rules! rpc_params {
+    ($($p:expr), *) => {{
+        // May be unused if empty; no params.
+        #[allow(unused_mut)]
+        let mut params = $crate::rpc::RpcParams::new();
+        $(
+            params.push($p).expect("values passed to rpc_params! must be serializable to JSON");
+        )*
+        params
+    }}
+}
+pub use rpc_params;
+
+/// This represents the parameters passed to an [`RpcClient`], and exists to
+/// enforce that parameters are provided in the correct format.
+///
+/// Prefer to use the [`rpc_params!`] macro for simpler creation of these.
+///
+/// # Example
+///
+/// ```rust
+/// use subxt::rpc::RpcParams;
+///
+/// let mut params = RpcParams::new();
+/// params.push(1).unwrap();
+/// params.push(true).unwrap();
+/// params.push("foo").unwrap();
+///
+/// assert_eq!(params.build().unwrap().get(), "[1,true,\"foo\"]");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RpcParams(Vec<u8>);
+
+impl RpcParams {
+    /// Create a new empty set of [`RpcParams`].
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+    /// Push a parameter into our [`RpcParams`]. This serializes it to JSON
+    /// in the process, and so will return an error if this is not possible.
+    pub fn push<P: Serialize>(&mut self, param: P) -> Result<(), Error> {
+        if self.0.is_empty() {
+            self.0.push(b'[');
+        } else {
+            self.0.push(b',')
+        }
+        serde_json::to_writer(&mut self.0, &param)?;
+        Ok(())
+    }
+    /// Build a [`RawValue`] from our params, returning `None` if no parameters
+    /// were provided.
+    pub fn build(mut self) -> Option<Box<RawValue>> {
+        if self.0.is_empty() {
+            None
+        } else {
+            self.0.push(b']');
+            let s = String::from_utf8(self.0).expect("serde_json only writes valid UTF8");
+            Some(RawValue::from_string(s).expect("Should be valid JSON"))
+        }
+    }
+}
+
+/// A batch of RPC requests, to be sent to the node all at once via [`BatchRequest::send()`].
+/// Create one of these via [`super::Rpc::batch()`].
+///
+/// Each call to [`BatchRequest::add()`] hands back a [`BatchItem`], which can be used to
+/// retrieve the corresponding result from the [`BatchResponse`].
+#[derive(Debug)]
+pub struct BatchRequest {
+    client: RpcClient,
+    calls: Vec<(String, Option<Box<RawValue>>)>,
+}
+
+impl BatchRequest {
+    /// Create a new, empty [`BatchRequest`].
+    pub fn new(client: RpcClient) -> Self {
+        BatchRequest {
+            client,
+            calls: Vec::new(),
+        }
+    }
+
+    /// Add a request to the batch, returning a [`BatchItem`] which can be used to
+    /// obtain the result of this request from the [`BatchResponse`].
+    pub fn add<Res: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: RpcParams,
+    ) -> BatchItem<Res> {
+        let index = self.calls.len();
+        self.calls.push((method.to_owned(), params.build()));
+        BatchItem {
+            index,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// The number of requests in this batch.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Is this batch empty?
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Send the batch of requests, waiting for every response to come back.
+    /// If any of the requests fail, an error is returned.
+    pub async fn send(self) -> Result<BatchResponse, Error> {
+        // An empty batch is not a valid JSON-RPC request, so don't bother sending it.
+        if self.calls.is_empty() {
+            return Ok(BatchResponse {
+                results: Vec::new(),
+            })
+        }
+
+        let calls = self
+            .calls
+            .iter()
+            .map(|(method, params)| (&**method, params.clone()))
+            .collect();
+        let results = self.client.batch_request_raw(calls).await?;
+        Ok(BatchResponse { results })
+    }
+}
+
+/// A handle to the result of a single request in a [`BatchRequest`].
+pub struct BatchItem<Res> {
+    index: usize,
+    _marker: std::marker::PhantomData<fn() -> Res>,
+}
+
+impl<Res> Clone for BatchItem<Res> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Res> Copy for BatchItem<Res> {}
+
+impl<Res> std::fmt::Debug for BatchItem<Res> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BatchItem").field(&self.index).finish()
+    }
+}
+
+/// The responses to a [`BatchRequest`].
+#[derive(Debug)]
+pub struct BatchResponse {
+    results: Vec<Box<RawValue>>,
+}
+
+impl BatchResponse {
+    /// Decode the result of the request corresponding to the given [`BatchItem`].
+    pub fn get<Res: DeserializeOwned>(&self, item: BatchItem<Res>) -> Result<Res, Error> {
+        let res = self.results.get(item.index).ok_or_else(|| {
+            Error::Other(format!(
+                "Batch response has no result at index {}",
+                item.index
+            ))
+        })?;
+        Ok(serde_json::from_str(res.get())?)
+    }
+}
+
+/// A generic RPC Subscription. This implements [`Stream`], and so most of
+/// the functionality you'll need to interact with it comes from the
+/// [`StreamExt`] extension trait.
+pub struct Subscription<Res> {
+    inner: RpcSubscription,
+    id: Option<String>,
+    _marker: std::marker::PhantomData<Res>,
+}
+
+impl<Res> std::fmt::Debug for Subscription<Res> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription")
+            .field("inner", &"RpcSubscription")
+            .field("id", &self.id)
+            .field("_marker", &self._marker)
+            .finish()
+    }
+}
+
+impl<Res> Subscription<Res> {
+    /// Creates a new [`Subscription`].
+    pub fn new(inner: RpcSubscription) -> Self {
+        Self {
+            inner,
+            id: None,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// The ID that the node assigned to this subscription. This is only available for
+    /// subscriptions created via [`RpcClient::subscribe_with_id()`], and then only if the
+    /// underlying [`RpcClientT`] implementation is able to report it.
+    pub fn subscription_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+impl<Res: DeserializeOwned> Subscription<Res> {
+    /// Wait for the next item from the subscription. This just delegates to the
+    /// [`Stream`] implementation, but allows you to avoid importing [`StreamExt`]
+    /// if you don't otherwise need it.
+    pub async fn next(&mut self) -> Option<Result<Res, Error>> {
+        StreamExt::next(self).await
+    }
+}
+
+// The inner subscription stream is already `Unpin`, so we
+// don't care about the `Res` type param here.
+impl<Res> std::marker::Unpin for Subscription<Res> {}
+
+impl<Res: DeserializeOwned> Stream for Subscription<Res> {
+    type Item = Result<Res, Error>;
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let res = futures::ready!(self.inner.poll_next_unpin(cx));
+
+        // Decode the inner RawValue to the type we're expecting and map
+        // any errors to the right shape:
+        let res = res.map(|r| {
+            r.map_err(|e| e.into()).and_then(|raw_val| {
+                serde_json::from_str(raw_val.get()).map_err(|e| e.into())
+            })
+        });
+
+        Poll::Ready(res)
+    }
+}
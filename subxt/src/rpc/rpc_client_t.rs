@@ -0,0 +1,102 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::error::RpcError;
+use futures::Stream;
+use std::{
+    future::Future,
+    pin::Pin,
+};
+
+// Re-exporting for simplicity since it's used a bunch in the trait definition.
+pub use serde_json::value::RawValue;
+
+/// Any RPC client which implements this can be used in our [`super::Rpc`] type
+/// to talk to a node.
+///
+/// This is a low level interface whose methods expect an already-serialized set of params,
+/// and return an owned but still-serialized [`RawValue`], deferring deserialization to
+/// the caller. This is the case because we want the methods to be object-safe (which prohibits
+/// generics), and want to avoid any unnecessary allocations in serializing/deserializing
+/// parameters.
+///
+/// # Panics
+///
+/// Implementations are free to panic if the `RawValue`'s passed to `request_raw` or
+/// `subscribe_raw` are not JSON arrays. Internally, we ensure that this is always the case.
+pub trait RpcClientT: Send + Sync + 'static {
+    /// Make a raw request for which we expect a single response back from. Implementations
+    /// should expect that the params will either be `None`, or be an already-serialized
+    /// JSON array of parameters.
+    ///
+    /// See [`super::RpcParams`] and the [`crate::rpc_params!`] macro for an example of how to
+    /// construct the parameters.
+    ///
+    /// Prefer to use the interface provided on [`super::RpcClient`] where possible.
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RpcFuture<'a, Box<RawValue>>;
+
+    /// Subscribe to some method. Implementations should expect that the params will
+    /// either be `None`, or be an already-serialized JSON array of parameters.
+    ///
+    /// See [`super::RpcParams`] and the [`crate::rpc_params!`] macro for an example of how to
+    /// construct the parameters.
+    ///
+    /// Prefer to use the interface provided on [`super::RpcClient`] where possible.
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, RpcSubscription>;
+
+    /// Subscribe to some method, as with [`RpcClientT::subscribe_raw`], but also hand back
+    /// the ID that the node assigned to the subscription, if it is known. Some methods (for
+    /// instance, those in the `chainHead_unstable_*` family) take this ID as a parameter.
+    ///
+    /// By default, this calls [`RpcClientT::subscribe_raw`] and returns no ID; implementations
+    /// should override this if they are able to obtain the subscription ID.
+    fn subscribe_raw_with_id<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RpcFuture<'a, (Option<String>, RpcSubscription)> {
+        Box::pin(async move {
+            let sub = self.subscribe_raw(sub, params, unsub).await?;
+            Ok((None, sub))
+        })
+    }
+
+    /// Make a batch of raw requests, handing back the responses in the same order that the
+    /// requests were given. If any of the requests fail, the whole batch fails. Parameters are
+    /// expected to be in the same form as those handed to [`RpcClientT::request_raw`].
+    ///
+    /// By default, this makes each request concurrently using [`RpcClientT::request_raw`];
+    /// implementations should override this if they are able to send JSON-RPC batch requests.
+    ///
+    /// Prefer to use [`super::Rpc::batch()`] where possible.
+    fn batch_request_raw<'a>(
+        &'a self,
+        batch: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RpcFuture<'a, Vec<Box<RawValue>>> {
+        Box::pin(async move {
+            let requests = batch
+                .into_iter()
+                .map(|(method, params)| self.request_raw(method, params));
+            futures::future::try_join_all(requests).await
+        })
+    }
+}
+
+/// A boxed future that is returned from the [`RpcClientT`] methods.
+pub type RpcFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, RpcError>> + Send + 'a>>;
+
+/// The inner subscription stream returned from our [`RpcClientT`]'s `subscription` method.
+pub type RpcSubscription =
+    Pin<Box<dyn Stream<Item = Result<Box<RawValue>, RpcError>> + Send + 'static>>;
@@ -4,15 +4,18 @@
 
 //! Types associated with accessing and working with storage items.
 
+mod read_proof;
 mod storage_address;
 mod storage_client;
 mod storage_map_key;
 
 pub mod utils;
 
+pub use read_proof::ReadProofVerifier;
 pub use storage_client::{
     KeyIter,
     StorageClient,
+    StorageSubscription,
 };
 
 // Re-export as this is used in the public API:
@@ -31,7 +34,9 @@ pub mod address {
             Yes,
         },
         storage_map_key::{
+            DecodeStorageKeys,
             StorageHasher,
+            StorageKeyPart,
             StorageMapKey,
         },
     };
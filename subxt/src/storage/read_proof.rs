@@ -0,0 +1,252 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Look up storage values in a read proof (see [`crate::rpc::Rpc::read_proof()`]), checking
+//! them against a state root rather than trusting the node which handed them over.
+
+use super::storage_address::{
+    StorageAddress,
+    Yes,
+};
+use crate::{
+    client::OfflineClientT,
+    error::{
+        Error,
+        ReadProofError,
+    },
+    metadata::DecodeWithMetadata,
+    rpc::ReadProof,
+    Config,
+};
+use sp_trie::{
+    HashDBT,
+    LayoutV1,
+    MemoryDB,
+    StorageProof,
+    TrieError,
+    EMPTY_PREFIX,
+};
+
+// Values are looked up the same way regardless of the version of the trie layout.
+type Layout<T> = LayoutV1<<T as Config>::Hashing>;
+
+/// Looks up storage values in a read proof, checking that each of them is a part of the state
+/// with a given state root (for instance, the `state_root` in a block header). This is
+/// obtained via [`super::StorageClient::verify_read_proof()`].
+pub struct ReadProofVerifier<T: Config, Client> {
+    client: Client,
+    state_root: T::Hash,
+    db: MemoryDB<T::Hashing>,
+}
+
+impl<T: Config, Client> std::fmt::Debug for ReadProofVerifier<T, Client> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReadProofVerifier")
+            .field("state_root", &self.state_root)
+            .finish()
+    }
+}
+
+impl<T, Client> ReadProofVerifier<T, Client>
+where
+    T: Config,
+    Client: OfflineClientT<T>,
+{
+    pub(super) fn new(
+        client: Client,
+        state_root: T::Hash,
+        proof: &ReadProof<T::Hash>,
+    ) -> Result<Self, Error> {
+        let nodes = proof.proof.iter().map(|node| node.0.clone()).collect();
+        let db = StorageProof::new(nodes).into_memory_db::<T::Hashing>();
+        if !db.contains(&state_root, EMPTY_PREFIX) {
+            return Err(ReadProofError::RootNotFound(format!("{:?}", state_root)).into())
+        }
+
+        Ok(Self {
+            client,
+            state_root,
+            db,
+        })
+    }
+
+    /// The state root which values are checked against.
+    pub fn state_root(&self) -> T::Hash {
+        self.state_root
+    }
+
+    /// Look up the raw bytes stored at the given storage key. `None` is returned if the
+    /// proof shows that there is no value at this key.
+    pub fn fetch_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        let value =
+            sp_trie::read_trie_value::<Layout<T>, _>(&self.db, &self.state_root, key)
+                .map_err(|e| {
+                    match *e {
+                        TrieError::<Layout<T>>::InvalidStateRoot(root) => {
+                            ReadProofError::RootNotFound(format!("{:?}", root))
+                        }
+                        TrieError::<Layout<T>>::IncompleteDatabase(_) => {
+                            ReadProofError::MissingKey(hex::encode(key))
+                        }
+                        other => ReadProofError::Invalid(other.to_string()),
+                    }
+                })?;
+        Ok(value)
+    }
+
+    /// Look up and decode the value at the given storage address. `None` is returned if the
+    /// proof shows that there is no value at this address.
+    pub fn fetch<Address>(
+        &self,
+        address: &Address,
+    ) -> Result<Option<<Address::Target as DecodeWithMetadata>::Target>, Error>
+    where
+        Address: StorageAddress<IsFetchable = Yes>,
+    {
+        // As with fetching values from a node, check that the address lines up
+        // with the metadata first.
+        self.client.storage().validate(address)?;
+
+        let metadata = self.client.metadata();
+        let key = super::utils::storage_address_bytes(address, &metadata)?;
+        match self.fetch_raw(&key)? {
+            Some(data) => {
+                let val = <Address::Target as DecodeWithMetadata>::decode_storage_with_metadata(
+                    &mut &*data,
+                    address.pallet_name(),
+                    address.entry_name(),
+                    &metadata,
+                )?;
+                Ok(Some(val))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        dynamic::Value,
+        rpc::MockNode,
+        Metadata,
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use codec::Encode;
+    use sp_core::{
+        Bytes,
+        H256,
+    };
+    use sp_runtime::traits::BlakeTwo256;
+    use sp_trie::{
+        trie_types::TrieDBMutV1,
+        Recorder,
+        TrieMut,
+    };
+    use std::sync::Arc;
+
+    fn storage_key(
+        metadata: &Metadata,
+        entry: &str,
+        block_number: Option<u32>,
+    ) -> Vec<u8> {
+        let keys = block_number
+            .map(|n| Value::u128(n as u128))
+            .into_iter()
+            .collect();
+        let address = crate::dynamic::storage("System", entry, keys);
+        super::super::utils::storage_address_bytes(&address, metadata).unwrap()
+    }
+
+    // Build a trie containing the given entries, returning its nodes and its root.
+    fn build_trie(entries: &[(Vec<u8>, Vec<u8>)]) -> (MemoryDB<BlakeTwo256>, H256) {
+        let mut db = MemoryDB::default();
+        let mut root = H256::default();
+        {
+            let mut trie = TrieDBMutV1::<BlakeTwo256>::new(&mut db, &mut root);
+            for (key, value) in entries {
+                trie.insert(key, value).unwrap();
+            }
+        }
+        (db, root)
+    }
+
+    // Build a read proof for the given keys, as a node would.
+    fn read_proof(
+        db: &MemoryDB<BlakeTwo256>,
+        root: H256,
+        keys: &[&[u8]],
+    ) -> ReadProof<H256> {
+        let mut recorder = Recorder::new();
+        for key in keys {
+            sp_trie::read_trie_value_with::<LayoutV1<BlakeTwo256>, _, _>(
+                db,
+                &root,
+                key,
+                &mut recorder,
+            )
+            .unwrap();
+        }
+        let proof = recorder
+            .drain()
+            .into_iter()
+            .map(|r| Bytes(r.data))
+            .collect();
+        ReadProof { at: root, proof }
+    }
+
+    #[tokio::test]
+    async fn verifies_values_in_read_proof() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node))
+            .await
+            .unwrap();
+        let metadata = api.metadata();
+
+        // Enough entries that not every node is inlined into the root node.
+        let mut entries: Vec<_> = (0..64)
+            .map(|n| {
+                (
+                    storage_key(&metadata, "BlockHash", Some(n)),
+                    [n as u8; 32].to_vec(),
+                )
+            })
+            .collect();
+        entries.push((storage_key(&metadata, "Number", None), 63u32.encode()));
+        let (db, root) = build_trie(&entries);
+
+        let number_key = storage_key(&metadata, "Number", None);
+        let missing_key = storage_key(&metadata, "BlockHash", Some(100));
+        let proof = read_proof(&db, root, &[&number_key, &missing_key]);
+        let verifier = api.storage().verify_read_proof(root, &proof).unwrap();
+
+        // Values in the proof can be looked up and decoded.
+        let number = crate::dynamic::storage_root("System", "Number");
+        let value = verifier.fetch(&number).unwrap().unwrap();
+        assert_eq!(value.as_u128(), Some(63));
+
+        // The proof can also show that there is no value at some key.
+        let missing =
+            crate::dynamic::storage("System", "BlockHash", vec![Value::u128(100)]);
+        assert!(verifier.fetch(&missing).unwrap().is_none());
+
+        // Values which aren't covered by the proof can't be looked up.
+        let not_proven =
+            crate::dynamic::storage("System", "BlockHash", vec![Value::u128(7)]);
+        assert!(matches!(
+            verifier.fetch(&not_proven),
+            Err(Error::ReadProof(ReadProofError::MissingKey(_)))
+        ));
+
+        // The proof doesn't belong to any other state root.
+        assert!(matches!(
+            api.storage()
+                .verify_read_proof(H256::repeat_byte(1), &proof),
+            Err(Error::ReadProof(ReadProofError::RootNotFound(_)))
+        ));
+    }
+}
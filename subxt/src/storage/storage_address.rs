@@ -2,7 +2,10 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use super::storage_map_key::StorageMapKey;
+use super::storage_map_key::{
+    DecodeStorageKeys,
+    StorageMapKey,
+};
 use crate::{
     dynamic::{
         DecodedValue,
@@ -30,6 +33,9 @@ pub use frame_metadata::StorageHasher;
 pub trait StorageAddress {
     /// The target type of the value that lives at this address.
     type Target: DecodeWithMetadata;
+    /// The type that the keys of the storage entry at this address are decoded into.
+    /// See [`crate::storage::StorageClient::decode_keys()`].
+    type Keys: DecodeStorageKeys;
     /// Can an entry be fetched from this address?
     /// Set this type to [`Yes`] to enable the corresponding calls to be made.
     type IsFetchable;
@@ -54,6 +60,17 @@ pub trait StorageAddress {
         bytes: &mut Vec<u8>,
     ) -> Result<(), Error>;
 
+    /// Output the non-prefix bytes shared by every entry that iterating over this
+    /// address will visit. By default, these are the same as the bytes given by
+    /// [`StorageAddress::append_entry_bytes()`].
+    fn append_entry_prefix_bytes(
+        &self,
+        metadata: &Metadata,
+        bytes: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        self.append_entry_bytes(metadata, bytes)
+    }
+
     /// An optional hash which, if present, will be checked against
     /// the node metadata to confirm that the return type matches what
     /// we are expecting.
@@ -66,21 +83,23 @@ pub trait StorageAddress {
 /// fetched and returned with a default value in the type system.
 pub struct Yes;
 
-/// This represents a statically generated storage lookup address.
-pub struct StaticStorageAddress<ReturnTy, Fetchable, Defaultable, Iterable> {
+/// This represents a statically generated storage lookup address. `Keys` is a tuple
+/// of the types of the storage entry's keys, which they can be decoded back into.
+pub struct StaticStorageAddress<ReturnTy, Fetchable, Defaultable, Iterable, Keys = ()> {
     pallet_name: &'static str,
     entry_name: &'static str,
     // How to access the specific value at that storage address.
     storage_entry_keys: Vec<StorageMapKey>,
     // Hash provided from static code for validation.
     validation_hash: Option<[u8; 32]>,
-    _marker: std::marker::PhantomData<(ReturnTy, Fetchable, Defaultable, Iterable)>,
+    _marker: std::marker::PhantomData<(ReturnTy, Fetchable, Defaultable, Iterable, Keys)>,
 }
 
-impl<ReturnTy, Fetchable, Defaultable, Iterable>
-    StaticStorageAddress<ReturnTy, Fetchable, Defaultable, Iterable>
+impl<ReturnTy, Fetchable, Defaultable, Iterable, Keys>
+    StaticStorageAddress<ReturnTy, Fetchable, Defaultable, Iterable, Keys>
 where
     ReturnTy: DecodeWithMetadata,
+    Keys: DecodeStorageKeys,
 {
     /// Create a new [`StaticStorageAddress`] that will be validated
     /// against node metadata using the hash given.
@@ -124,12 +143,14 @@ where
     }
 }
 
-impl<ReturnTy, Fetchable, Defaultable, Iterable> StorageAddress
-    for StaticStorageAddress<ReturnTy, Fetchable, Defaultable, Iterable>
+impl<ReturnTy, Fetchable, Defaultable, Iterable, Keys> StorageAddress
+    for StaticStorageAddress<ReturnTy, Fetchable, Defaultable, Iterable, Keys>
 where
     ReturnTy: DecodeWithMetadata,
+    Keys: DecodeStorageKeys,
 {
     type Target = ReturnTy;
+    type Keys = Keys;
     type IsDefaultable = Defaultable;
     type IsIterable = Iterable;
     type IsFetchable = Fetchable;
@@ -177,7 +198,10 @@ pub fn dynamic_root<'a>(
     }
 }
 
-/// Construct a new dynamic storage lookup.
+/// Construct a new dynamic storage lookup. Every key must be given in order to fetch an entry,
+/// but for maps whose keys are each hashed separately (ie double maps and N-maps), only some
+/// of the leading keys need to be given in order to iterate over all of the entries which share
+/// those keys via [`crate::storage::StorageClient::iter()`].
 pub fn dynamic<'a, Encodable: EncodeWithMetadata>(
     pallet_name: impl Into<Cow<'a, str>>,
     entry_name: impl Into<Cow<'a, str>>,
@@ -195,6 +219,7 @@ where
     Encodable: EncodeWithMetadata,
 {
     type Target = DecodedValue;
+    type Keys = Vec<DecodedValue>;
 
     // For dynamic types, we have no static guarantees about any of
     // this stuff, so we just allow it and let it fail at runtime:
@@ -214,6 +239,31 @@ where
         &self,
         metadata: &Metadata,
         bytes: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        self.append_keys(metadata, bytes, false)
+    }
+
+    fn append_entry_prefix_bytes(
+        &self,
+        metadata: &Metadata,
+        bytes: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        self.append_keys(metadata, bytes, true)
+    }
+}
+
+impl<'a, Encodable> DynamicStorageAddress<'a, Encodable>
+where
+    Encodable: EncodeWithMetadata,
+{
+    // Encode and hash the keys given. If `allow_partial` is true, only some of the leading
+    // keys of a map need to be given, and the bytes output are the prefix shared by all of
+    // the entries with those keys.
+    fn append_keys(
+        &self,
+        metadata: &Metadata,
+        bytes: &mut Vec<u8>,
+        allow_partial: bool,
     ) -> Result<(), Error> {
         let pallet = metadata.pallet(&self.pallet_name)?;
         let storage = pallet.storage(&self.entry_name)?;
@@ -246,24 +296,24 @@ where
                     }
                 };
 
-                if type_ids.len() != self.storage_entry_keys.len() {
-                    return Err(StorageAddressError::WrongNumberOfKeys {
+                let wrong_number_of_keys = || {
+                    StorageAddressError::WrongNumberOfKeys {
                         expected: type_ids.len(),
                         actual: self.storage_entry_keys.len(),
                     }
-                    .into())
+                };
+                let too_many_keys = self.storage_entry_keys.len() > type_ids.len();
+                let too_few_keys = self.storage_entry_keys.len() < type_ids.len();
+                if too_many_keys || (too_few_keys && !allow_partial) {
+                    return Err(wrong_number_of_keys().into())
                 }
 
-                if hashers.len() == 1 {
-                    // One hasher; hash a tuple of all SCALE encoded bytes with the one hash function.
-                    let mut input = Vec::new();
-                    for (key, type_id) in self.storage_entry_keys.iter().zip(type_ids) {
-                        key.encode_with_metadata(type_id, metadata, &mut input)?;
-                    }
-                    super::storage_map_key::hash_bytes(&input, &hashers[0], bytes);
+                if self.storage_entry_keys.is_empty() {
+                    // No keys; this is the root of the map, which all of its entries share.
                     Ok(())
                 } else if hashers.len() == type_ids.len() {
-                    // A hasher per field; encode and hash each field independently.
+                    // A hasher per field; encode and hash each field independently. When
+                    // iterating, only some of the leading fields may be given.
                     for ((key, type_id), hasher) in
                         self.storage_entry_keys.iter().zip(type_ids).zip(hashers)
                     {
@@ -272,6 +322,17 @@ where
                         super::storage_map_key::hash_bytes(&input, hasher, bytes);
                     }
                     Ok(())
+                } else if hashers.len() == 1 {
+                    // One hasher; hash a tuple of all SCALE encoded bytes with the one hash function.
+                    if self.storage_entry_keys.len() != type_ids.len() {
+                        return Err(wrong_number_of_keys().into())
+                    }
+                    let mut input = Vec::new();
+                    for (key, type_id) in self.storage_entry_keys.iter().zip(type_ids) {
+                        key.encode_with_metadata(type_id, metadata, &mut input)?;
+                    }
+                    super::storage_map_key::hash_bytes(&input, &hashers[0], bytes);
+                    Ok(())
                 } else {
                     // Mismatch; wrong number of hashers/fields.
                     Err(StorageAddressError::WrongNumberOfHashers {
@@ -2,32 +2,65 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use super::storage_address::{
-    StorageAddress,
-    Yes,
+use super::{
+    read_proof::ReadProofVerifier,
+    storage_address::{
+        StorageAddress,
+        Yes,
+    },
+    storage_map_key::{
+        storage_key_parts,
+        DecodeStorageKeys,
+    },
 };
 use crate::{
     client::{
         OfflineClientT,
         OnlineClientT,
     },
-    error::Error,
+    error::{
+        Error,
+        StorageAddressError,
+    },
     metadata::{
         DecodeWithMetadata,
         Metadata,
     },
+    rpc::{
+        rpc_params,
+        ReadProof,
+        Subscription,
+    },
     Config,
 };
 use derivative::Derivative;
 use frame_metadata::StorageEntryType;
+use futures::{
+    channel::oneshot,
+    future::BoxFuture,
+    stream::FuturesOrdered,
+    FutureExt,
+    Stream,
+    StreamExt,
+};
 use scale_info::form::PortableForm;
 use sp_core::storage::{
+    StorageChangeSet,
     StorageData,
     StorageKey,
 };
 use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
     future::Future,
     marker::PhantomData,
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
 };
 
 /// Query the runtime storage.
@@ -71,6 +104,103 @@ where
         }
         Ok(())
     }
+
+    /// Decode the keys of a storage map from a full storage key, such as those handed back
+    /// when iterating over the map (see [`StorageClient::iter()`]). The address is only used
+    /// to find the storage entry and the types to decode the keys into, and so the root
+    /// address of the entry can be given. Only keys which are hashed with the `Identity`
+    /// hasher or one of the `*Concat` hashers can be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use subxt::{ PolkadotConfig, OnlineClient };
+    ///
+    /// #[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata.scale")]
+    /// pub mod polkadot {}
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+    ///
+    /// let address = polkadot::storage().system().account_root();
+    /// let mut iter = api
+    ///     .storage()
+    ///     .iter(address, 10, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// while let Some((key, value)) = iter.next().await.unwrap() {
+    ///     let address = polkadot::storage().system().account_root();
+    ///     let (account_id,) = api.storage().decode_keys(&address, &key.0).unwrap();
+    ///     println!("{}: {}", account_id, value.data.free);
+    /// }
+    /// # }
+    /// ```
+    pub fn decode_keys<Address: StorageAddress>(
+        &self,
+        address: &Address,
+        key: &[u8],
+    ) -> Result<Address::Keys, Error> {
+        self.validate(address)?;
+
+        let root = super::utils::storage_address_root_bytes(address);
+        let mut bytes = key.strip_prefix(&root[..]).ok_or_else(|| {
+            StorageAddressError::UnexpectedKeyPrefix {
+                pallet_name: address.pallet_name().to_owned(),
+                entry_name: address.entry_name().to_owned(),
+            }
+        })?;
+
+        let metadata = self.client.metadata();
+        let parts =
+            storage_key_parts(&metadata, address.pallet_name(), address.entry_name())?;
+        let keys = Address::Keys::decode_storage_keys(&mut bytes, &parts, &metadata)?;
+        if !bytes.is_empty() {
+            return Err(StorageAddressError::TooManyKeyBytes(bytes.len()).into())
+        }
+        Ok(keys)
+    }
+
+    /// Check a read proof (see [`crate::rpc::Rpc::read_proof()`]) against the given state
+    /// root, for instance the `state_root` in the header of the block that the proof was
+    /// obtained at. The values in the proof can then be looked up via the returned
+    /// [`ReadProofVerifier`], which checks that each of them is a part of that state, and
+    /// so they can be trusted without trusting the node that handed over the proof.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use subxt::{ PolkadotConfig, OnlineClient };
+    ///
+    /// #[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata.scale")]
+    /// pub mod polkadot {}
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+    ///
+    /// let address = polkadot::storage().timestamp().now();
+    /// let key = subxt::storage::utils::storage_address_bytes(&address, &api.metadata()).unwrap();
+    ///
+    /// // Obtain a proof of the value at some block, and the state root of that block.
+    /// let block_hash = api.rpc().block_hash(None).await.unwrap().unwrap();
+    /// let header = api.rpc().header(Some(block_hash)).await.unwrap().unwrap();
+    /// let proof = api.rpc().read_proof([&key[..]], Some(block_hash)).await.unwrap();
+    ///
+    /// // Check the value against the state root.
+    /// let verifier = api.storage().verify_read_proof(header.state_root, &proof).unwrap();
+    /// let now = verifier.fetch(&address).unwrap();
+    /// println!("Timestamp: {:?}", now);
+    /// # }
+    /// ```
+    pub fn verify_read_proof(
+        &self,
+        state_root: T::Hash,
+        proof: &ReadProof<T::Hash>,
+    ) -> Result<ReadProofVerifier<T, Client>, Error> {
+        ReadProofVerifier::new(self.client.clone(), state_root, proof)
+    }
 }
 
 impl<T, Client> StorageClient<T, Client>
@@ -87,10 +217,7 @@ where
         let client = self.client.clone();
         // Ensure that the returned future doesn't have a lifetime tied to api.storage(),
         // which is a temporary thing we'll be throwing away quickly:
-        async move {
-            let data = client.rpc().storage(key, hash).await?;
-            Ok(data.map(|d| d.0))
-        }
+        async move { client.backend().storage_at(key, hash).await }
     }
 
     /// Fetch a decoded value from storage at a given address and optional block hash.
@@ -197,6 +324,74 @@ where
         }
     }
 
+    /// Subscribe to changes in the value at the given storage address. The returned
+    /// [`StorageSubscription`] first hands back the current value, and then the new value
+    /// each time that it changes, alongside the hash of the block it changed in. The value
+    /// is `None` if there is nothing stored at the address.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use subxt::{ PolkadotConfig, OnlineClient };
+    ///
+    /// #[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata.scale")]
+    /// pub mod polkadot {}
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let api = OnlineClient::<PolkadotConfig>::new().await.unwrap();
+    ///
+    /// // Address to a storage entry we'd like to watch.
+    /// let address = polkadot::storage().timestamp().now();
+    ///
+    /// let mut sub = api
+    ///     .storage()
+    ///     .subscribe(&address)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// while let Some(change) = sub.next().await {
+    ///     let (block_hash, value) = change.unwrap();
+    ///     println!("Value in block {:?}: {:?}", block_hash, value);
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe<'a, Address>(
+        &self,
+        address: &'a Address,
+    ) -> impl Future<Output = Result<StorageSubscription<T, Address::Target>, Error>> + 'a
+    where
+        Address: StorageAddress<IsFetchable = Yes> + 'a,
+    {
+        let client = self.clone();
+        async move {
+            // Metadata validation checks whether the static address given
+            // is likely to actually correspond to a real storage entry or not.
+            // if not, it means static codegen doesn't line up with runtime
+            // metadata.
+            client.validate(address)?;
+
+            let metadata = client.client.metadata();
+            let return_type_id = lookup_storage_return_type(
+                &metadata,
+                address.pallet_name(),
+                address.entry_name(),
+            )?;
+            let key = super::utils::storage_address_bytes(address, &metadata)?;
+
+            let subscription = client.client.rpc().subscribe_storage([&key[..]]).await?;
+
+            Ok(StorageSubscription {
+                subscription,
+                key: StorageKey(key),
+                return_type_id,
+                metadata,
+                _marker: PhantomData,
+            })
+        }
+    }
+
     /// Fetch up to `count` keys for a storage map in lexicographic order.
     ///
     /// Supports pagination by passing a value to `start_key`.
@@ -217,7 +412,11 @@ where
         }
     }
 
-    /// Returns an iterator of key value pairs.
+    /// Returns an iterator of key value pairs. Given the root address of a storage map, this
+    /// iterates over every entry in the map. Double maps and N-maps can also be iterated over
+    /// given only some of their leading keys, to iterate over the entries which share them.
+    /// Entries are fetched `page_size` at a time, and are handed back in the order of their
+    /// keys. The returned [`KeyIter`] is also a [`Stream`].
     ///
     /// ```no_run
     /// use subxt::{ PolkadotConfig, OnlineClient };
@@ -243,6 +442,19 @@ where
     ///     println!("Key: 0x{}", hex::encode(&key));
     ///     println!("Value: {}", value);
     /// }
+    ///
+    /// // Iterate over the stakers exposed in era 100; the first key of `ErasStakers`.
+    /// let address = polkadot::storage().staking().eras_stakers_iter1(100);
+    /// let mut iter = api
+    ///     .storage()
+    ///     .iter(address, 10, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// while let Some((key, exposure)) = iter.next().await.unwrap() {
+    ///     println!("Key: 0x{}", hex::encode(&key));
+    ///     println!("Total: {}", exposure.total);
+    /// }
     /// # }
     /// ```
     pub fn iter<Address>(
@@ -265,15 +477,10 @@ where
             // Fetch a concrete block hash to iterate over. We do this so that if new blocks
             // are produced midway through iteration, we continue to iterate at the block
             // we started with and not the new block.
-            let hash = if let Some(hash) = hash {
-                hash
-            } else {
-                client
-                    .client
-                    .rpc()
-                    .block_hash(None)
-                    .await?
-                    .expect("didn't pass a block number; qed")
+            // This is the same block that other storage requests default to.
+            let hash = match hash {
+                Some(hash) => hash,
+                None => client.client.backend().best_block_hash().await?,
             };
 
             let metadata = client.client.metadata();
@@ -287,88 +494,308 @@ where
                 address.entry_name(),
             )?;
 
-            // The root pallet/entry bytes for this storage entry:
-            let address_root_bytes = super::utils::storage_address_root_bytes(&address);
+            // The bytes shared by every key that we'll iterate over. For a root address,
+            // this is just the pallet/entry bytes, but the leading keys of double maps
+            // and N-maps can also be given.
+            let address_bytes =
+                super::utils::storage_address_prefix_bytes(&address, &metadata)?;
 
-            Ok(KeyIter {
+            let mut iter = KeyIter {
                 client,
-                address_root_bytes,
+                address_bytes,
                 metadata,
                 return_type_id,
                 block_hash: hash,
                 count: page_size,
-                start_key: None,
-                buffer: Default::default(),
-                _marker: std::marker::PhantomData,
-            })
+                concurrency: 1,
+                next_keys: None,
+                next_keys_count: page_size,
+                values: FuturesOrdered::new(),
+                buffer: VecDeque::new(),
+                _marker: PhantomData,
+            };
+            iter.next_keys = Some(iter.fetch_first_keys());
+            Ok(iter)
         }
     }
 }
 
-/// Iterates over key value pairs in a map.
+/// A subscription to the value at some storage address, which implements [`Stream`]. This is
+/// obtained via [`StorageClient::subscribe()`], and hands back the hash of each block in which
+/// the value changed, along with the new value (or `None` if it was removed).
+pub struct StorageSubscription<T: Config, ReturnTy> {
+    subscription: Subscription<StorageChangeSet<T::Hash>>,
+    key: StorageKey,
+    return_type_id: u32,
+    metadata: Metadata,
+    _marker: PhantomData<ReturnTy>,
+}
+
+impl<T: Config, ReturnTy> std::fmt::Debug for StorageSubscription<T, ReturnTy> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageSubscription")
+            .field("subscription", &self.subscription)
+            .field("key", &self.key)
+            .finish()
+    }
+}
+
+// Nothing is pinned, so this is safe regardless of the return type.
+impl<T: Config, ReturnTy> Unpin for StorageSubscription<T, ReturnTy> {}
+
+impl<T, ReturnTy> Stream for StorageSubscription<T, ReturnTy>
+where
+    T: Config,
+    ReturnTy: DecodeWithMetadata,
+{
+    type Item = Result<(T::Hash, Option<ReturnTy::Target>), Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            let change_set = match futures::ready!(self.subscription.poll_next_unpin(cx))
+            {
+                Some(Ok(change_set)) => change_set,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            };
+
+            // Ignore any notifications which don't mention our key.
+            let data = match change_set.changes.into_iter().find(|(k, _)| *k == self.key)
+            {
+                Some((_, data)) => data,
+                None => continue,
+            };
+            let value = data
+                .map(|data| {
+                    ReturnTy::decode_with_metadata(
+                        &mut &data.0[..],
+                        self.return_type_id,
+                        &self.metadata,
+                    )
+                })
+                .transpose()
+                .map(|value| (change_set.block, value));
+            return Poll::Ready(Some(value))
+        }
+    }
+}
+
+/// Iterates over key value pairs in a map, in the order of their keys. This is obtained via
+/// [`StorageClient::iter()`], and can be used either via [`KeyIter::next()`] or as a [`Stream`].
+///
+/// While the current page of keys is being handed back, the next page is fetched. The values
+/// for several pages of keys can also be fetched at once; see [`KeyIter::concurrency()`]. The
+/// values for a page are fetched in the same batch of requests as any keys which follow it.
 pub struct KeyIter<T: Config, Client, ReturnTy> {
     client: StorageClient<T, Client>,
-    address_root_bytes: Vec<u8>,
+    address_bytes: Vec<u8>,
     return_type_id: u32,
     metadata: Metadata,
     count: u32,
     block_hash: T::Hash,
-    start_key: Option<StorageKey>,
-    buffer: Vec<(StorageKey, StorageData)>,
-    _marker: std::marker::PhantomData<ReturnTy>,
+    concurrency: usize,
+    // Fetches the next pages of keys, unless we've seen the last page already.
+    next_keys: Option<BoxFuture<'static, Result<Vec<StorageKey>, Error>>>,
+    // How many keys `next_keys` asked for. If fewer come back, there are no more to fetch.
+    next_keys_count: u32,
+    // Fetches the values for each page of keys, in the order that the pages were fetched.
+    values:
+        FuturesOrdered<BoxFuture<'static, Result<Vec<(StorageKey, StorageData)>, Error>>>,
+    // Key value pairs which are ready to be handed back.
+    buffer: VecDeque<(StorageKey, StorageData)>,
+    _marker: PhantomData<ReturnTy>,
 }
 
-impl<'a, T: Config, Client: OnlineClientT<T>, ReturnTy> KeyIter<T, Client, ReturnTy>
+impl<T, Client, ReturnTy> KeyIter<T, Client, ReturnTy>
 where
     T: Config,
     Client: OnlineClientT<T>,
     ReturnTy: DecodeWithMetadata,
 {
+    /// Set the number of pages of keys whose values can be fetched at once (by default, one).
+    /// Keys are then fetched this many pages at a time. The values are still handed back in
+    /// the order of their keys.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
     /// Returns the next key value pair from a map.
     pub async fn next(
         &mut self,
     ) -> Result<Option<(StorageKey, ReturnTy::Target)>, Error> {
-        loop {
-            if let Some((k, v)) = self.buffer.pop() {
-                let val = ReturnTy::decode_with_metadata(
-                    &mut &v.0[..],
-                    self.return_type_id,
-                    &self.metadata,
-                )?;
-                return Ok(Some((k, val)))
-            } else {
-                let start_key = self.start_key.take();
-                let keys = self
-                    .client
-                    .fetch_keys(
-                        &self.address_root_bytes,
-                        self.count,
-                        start_key.as_ref().map(|k| &*k.0),
-                        Some(self.block_hash),
-                    )
-                    .await?;
+        StreamExt::next(self).await.transpose()
+    }
+
+    // Fetch the first page of keys. Each page after this is fetched alongside the values
+    // for the page before it; see `fetch_values`.
+    fn fetch_first_keys(&self) -> BoxFuture<'static, Result<Vec<StorageKey>, Error>> {
+        let rpc = self.client.client.rpc().clone();
+        let prefix = self.address_bytes.clone();
+        let count = self.count;
+        let block_hash = self.block_hash;
+        Box::pin(async move {
+            rpc.storage_keys_paged(&prefix, count, None, Some(block_hash))
+                .await
+        })
+    }
+
+    // Fetch the values for a page of keys, keeping them in the same order as the keys. If
+    // `next_keys_count` is given, that many of the keys which follow this page are fetched in
+    // the same batch of requests, and handed back via the second future returned.
+    #[allow(clippy::type_complexity)]
+    fn fetch_values(
+        &self,
+        keys: Vec<StorageKey>,
+        next_keys_count: Option<u32>,
+    ) -> (
+        BoxFuture<'static, Result<Vec<(StorageKey, StorageData)>, Error>>,
+        Option<BoxFuture<'static, Result<Vec<StorageKey>, Error>>>,
+    ) {
+        let rpc = self.client.client.rpc().clone();
+        let prefix = StorageKey(self.address_bytes.clone());
+        let block_hash = self.block_hash;
+
+        let start_key = keys.last().cloned().filter(|_| next_keys_count.is_some());
+        let (next_keys_tx, next_keys) = match start_key {
+            Some(_) => {
+                let (tx, rx) = oneshot::channel();
+                // If the batch fails, the values future hands back the error, and so
+                // there are no more keys to fetch.
+                let rx = rx.map(|keys| Ok(keys.unwrap_or_default()));
+                (Some(tx), Some(rx.boxed()))
+            }
+            None => (None, None),
+        };
+
+        let values = Box::pin(async move {
+            let mut batch = rpc.batch();
+            let change_sets_item = batch.add::<Vec<StorageChangeSet<T::Hash>>>(
+                "state_queryStorageAt",
+                rpc_params![&keys, Some(block_hash)],
+            );
+            let next_keys_item = start_key.map(|start_key| {
+                batch.add::<Vec<StorageKey>>(
+                    "state_getKeysPaged",
+                    rpc_params![
+                        prefix,
+                        next_keys_count,
+                        Some(start_key),
+                        Some(block_hash)
+                    ],
+                )
+            });
+            let res = batch.send().await?;
+
+            if let (Some(tx), Some(item)) = (next_keys_tx, next_keys_item) {
+                let _ = tx.send(res.get(item)?);
+            }
+
+            let mut values: HashMap<_, _> = res
+                .get(change_sets_item)?
+                .into_iter()
+                .flat_map(|change_set| change_set.changes)
+                .collect();
+            Ok(keys
+                .into_iter()
+                .filter_map(|key| {
+                    let value = values.remove(&key).flatten()?;
+                    Some((key, value))
+                })
+                .collect())
+        });
+
+        (values, next_keys)
+    }
+
+    // Stop fetching anything else once an error is encountered, handing the error back.
+    fn stop(&mut self, err: Error) -> Error {
+        self.next_keys = None;
+        self.values = FuturesOrdered::new();
+        self.buffer.clear();
+        err
+    }
+}
+
+// Nothing is pinned, so this is safe regardless of the return type.
+impl<T: Config, Client, ReturnTy> Unpin for KeyIter<T, Client, ReturnTy> {}
+
+impl<T, Client, ReturnTy> Stream for KeyIter<T, Client, ReturnTy>
+where
+    T: Config,
+    Client: OnlineClientT<T>,
+    ReturnTy: DecodeWithMetadata,
+{
+    type Item = Result<(StorageKey, ReturnTy::Target), Error>;
 
-                if keys.is_empty() {
-                    return Ok(None)
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let page_size = this.count as usize;
+        loop {
+            // Fetch pages of keys ahead of time and start fetching their values, so long as
+            // not too many pages are being fetched or are waiting to be handed back.
+            while this.values.len() < this.concurrency && this.buffer.len() <= page_size {
+                let next_keys = match &mut this.next_keys {
+                    Some(next_keys) => next_keys,
+                    None => break,
+                };
+                let keys = match next_keys.poll_unpin(cx) {
+                    Poll::Ready(Ok(keys)) => keys,
+                    Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(this.stop(e)))),
+                    Poll::Pending => break,
+                };
+                this.next_keys = None;
+                // If fewer keys came back than were asked for, there are none left to fetch.
+                // Otherwise, the keys which follow are fetched alongside the last page's values.
+                let has_more_keys = keys.len() >= this.next_keys_count as usize;
+                let next_keys_count = this.count.saturating_mul(this.concurrency as u32);
+                let mut pages = keys.chunks(page_size.max(1)).peekable();
+                while let Some(page) = pages.next() {
+                    let is_last_page = pages.peek().is_none();
+                    let (values, next_keys) = this.fetch_values(
+                        page.to_vec(),
+                        Some(next_keys_count).filter(|_| is_last_page && has_more_keys),
+                    );
+                    if next_keys.is_some() {
+                        this.next_keys = next_keys;
+                        this.next_keys_count = next_keys_count;
+                    }
+                    this.values.push_back(values);
                 }
+            }
 
-                self.start_key = keys.last().cloned();
-
-                let change_sets = self
-                    .client
-                    .client
-                    .rpc()
-                    .query_storage_at(keys.iter().map(|k| &*k.0), Some(self.block_hash))
-                    .await?;
-                for change_set in change_sets {
-                    for (k, v) in change_set.changes {
-                        if let Some(v) = v {
-                            self.buffer.push((k, v));
-                        }
+            if this.buffer.len() <= page_size {
+                match this.values.poll_next_unpin(cx) {
+                    Poll::Ready(Some(Ok(values))) => {
+                        this.buffer.extend(values);
+                        continue
+                    }
+                    Poll::Ready(Some(Err(e))) => {
+                        return Poll::Ready(Some(Err(this.stop(e))))
                     }
+                    Poll::Ready(None) | Poll::Pending => {}
                 }
-                debug_assert_eq!(self.buffer.len(), keys.len());
             }
+
+            if let Some((key, value)) = this.buffer.pop_front() {
+                let value = ReturnTy::decode_with_metadata(
+                    &mut &value.0[..],
+                    this.return_type_id,
+                    &this.metadata,
+                );
+                return Poll::Ready(Some(value.map(|value| (key, value))))
+            }
+
+            if this.next_keys.is_none() && this.values.is_empty() {
+                return Poll::Ready(None)
+            }
+            return Poll::Pending
         }
     }
 }
@@ -408,3 +835,353 @@ fn return_type_from_storage_entry_type(entry: &StorageEntryType<PortableForm>) -
         StorageEntryType::Map { value, .. } => value.id(),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        rpc::MockNode,
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn iterates_over_every_page() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        // 5 block hashes, which we'll iterate over 2 at a time.
+        let address = crate::dynamic::storage_root("System", "BlockHash");
+        let root = super::super::utils::storage_address_root_bytes(&address);
+        for n in 0u8..5 {
+            let key = root.iter().copied().chain([n]).collect();
+            node.set_storage(key, [n; 32].to_vec());
+        }
+        node.produce_block();
+
+        let mut iter = api.storage().iter(address, 2, None).await.unwrap();
+        let mut values = Vec::new();
+        while let Some((_, value)) = iter.next().await.unwrap() {
+            values.push(value);
+        }
+
+        assert_eq!(values.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn streams_entries_in_key_order() {
+        use futures::TryStreamExt;
+
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        let address = crate::dynamic::storage_root("System", "BlockHash");
+        let root = super::super::utils::storage_address_root_bytes(&address);
+        for n in 0u8..7 {
+            let key = root.iter().copied().chain([n]).collect();
+            node.set_storage(key, [n; 32].to_vec());
+        }
+        node.produce_block();
+
+        // Values for several pages are fetched at once, but handed back in order.
+        let iter = api.storage().iter(address, 2, None).await.unwrap();
+        let entries: Vec<_> = iter.concurrency(3).try_collect().await.unwrap();
+
+        let keys: Vec<u8> = entries
+            .iter()
+            .map(|(key, _)| *key.0.last().unwrap())
+            .collect();
+        assert_eq!(keys, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn streams_entries_in_key_order_when_values_arrive_out_of_order() {
+        use crate::rpc::{
+            RpcClientT,
+            RpcFuture,
+            RpcSubscription,
+        };
+        use futures::TryStreamExt;
+        use parking_lot::Mutex;
+        use serde_json::value::RawValue;
+        use std::time::Duration;
+
+        // Answers requests for the values of later keys more quickly than those for
+        // earlier keys, noting down the order in which the keys are answered.
+        struct DelayedClient {
+            node: MockNode<PolkadotConfig>,
+            answered: Mutex<Vec<u8>>,
+        }
+
+        impl RpcClientT for DelayedClient {
+            fn request_raw<'a>(
+                &'a self,
+                method: &'a str,
+                params: Option<Box<RawValue>>,
+            ) -> RpcFuture<'a, Box<RawValue>> {
+                Box::pin(async move {
+                    if method == "state_queryStorageAt" {
+                        let (keys, _): (Vec<sp_core::Bytes>, serde_json::Value) =
+                            serde_json::from_str(params.as_ref().unwrap().get()).unwrap();
+                        let n = *keys[0].last().unwrap();
+                        tokio::time::sleep(Duration::from_millis(10 * (10 - n as u64)))
+                            .await;
+                        self.answered.lock().push(n);
+                    }
+                    self.node.request_raw(method, params).await
+                })
+            }
+
+            fn subscribe_raw<'a>(
+                &'a self,
+                sub: &'a str,
+                params: Option<Box<RawValue>>,
+                unsub: &'a str,
+            ) -> RpcFuture<'a, RpcSubscription> {
+                self.node.subscribe_raw(sub, params, unsub)
+            }
+        }
+
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let client = Arc::new(DelayedClient {
+            node: node.clone(),
+            answered: Mutex::new(Vec::new()),
+        });
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(client.clone())
+            .await
+            .unwrap();
+
+        let address = crate::dynamic::storage_root("System", "BlockHash");
+        let root = super::super::utils::storage_address_root_bytes(&address);
+        for n in 0u8..7 {
+            let key = root.iter().copied().chain([n]).collect();
+            node.set_storage(key, [n; 32].to_vec());
+        }
+        node.produce_block();
+
+        let iter = api.storage().iter(address, 1, None).await.unwrap();
+        let entries: Vec<_> = iter.concurrency(3).try_collect().await.unwrap();
+
+        let keys: Vec<u8> = entries
+            .iter()
+            .map(|(key, _)| *key.0.last().unwrap())
+            .collect();
+        assert_eq!(keys, vec![0, 1, 2, 3, 4, 5, 6]);
+
+        // The values really were fetched out of order.
+        let answered = client.answered.lock().clone();
+        assert_eq!(answered.len(), 7);
+        assert_ne!(answered, keys);
+    }
+
+    #[tokio::test]
+    async fn subscribes_to_storage_changes() {
+        use codec::Encode;
+        use futures::StreamExt;
+
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        let address = crate::dynamic::storage_root("System", "Number");
+        let key = super::super::utils::storage_address_root_bytes(&address);
+        node.set_storage(key.clone(), 5u32.encode());
+        let first_hash = node.produce_block();
+
+        // We're handed the current value first.
+        let mut sub = api.storage().subscribe(&address).await.unwrap();
+        let (hash, value) = sub.next().await.unwrap().unwrap();
+        assert_eq!(hash, first_hash);
+        assert_eq!(value.unwrap().as_u128(), Some(5));
+
+        // Blocks which don't change the value are skipped.
+        node.set_storage(b"something else".to_vec(), vec![1]);
+        node.produce_block();
+        node.set_storage(key.clone(), 6u32.encode());
+        let second_hash = node.produce_block();
+        let (hash, value) = sub.next().await.unwrap().unwrap();
+        assert_eq!(hash, second_hash);
+        assert_eq!(value.unwrap().as_u128(), Some(6));
+
+        // Removed values are handed back as `None`.
+        node.remove_storage(key);
+        let third_hash = node.produce_block();
+        let (hash, value) = sub.next().await.unwrap().unwrap();
+        assert_eq!(hash, third_hash);
+        assert!(value.is_none());
+    }
+
+    #[tokio::test]
+    async fn decodes_storage_map_keys() {
+        use crate::{
+            dynamic::Value,
+            metadata::DecodeStaticType,
+            storage::address::{
+                StaticStorageAddress,
+                StorageHasher,
+                StorageMapKey,
+                Yes,
+            },
+        };
+        use sp_core::{
+            crypto::AccountId32,
+            H256,
+        };
+
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node))
+            .await
+            .unwrap();
+        let metadata = api.metadata();
+
+        // A map with a single key:
+        let address =
+            crate::dynamic::storage("System", "BlockHash", vec![Value::u128(3)]);
+        let key =
+            super::super::utils::storage_address_bytes(&address, &metadata).unwrap();
+
+        let root = crate::dynamic::storage_root("System", "BlockHash");
+        let keys = api.storage().decode_keys(&root, &key).unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].as_u128(), Some(3));
+
+        let root =
+            StaticStorageAddress::<DecodeStaticType<H256>, (), Yes, Yes, (u32,)>::new(
+                "System",
+                "BlockHash",
+                vec![],
+                [0; 32],
+            )
+            .unvalidated();
+        assert_eq!(api.storage().decode_keys(&root, &key).unwrap(), (3,));
+
+        // A map with several keys, each hashed separately:
+        let account_id = AccountId32::from([7; 32]);
+        let address = StaticStorageAddress::<
+            DecodeStaticType<()>,
+            Yes,
+            (),
+            Yes,
+            (u32, AccountId32),
+        >::new(
+            "Staking",
+            "ErasStakers",
+            vec![
+                StorageMapKey::new(10u32, StorageHasher::Twox64Concat),
+                StorageMapKey::new(&account_id, StorageHasher::Twox64Concat),
+            ],
+            [0; 32],
+        )
+        .unvalidated();
+        let key = address.to_bytes();
+        assert_eq!(
+            api.storage().decode_keys(&address, &key).unwrap(),
+            (10, account_id)
+        );
+
+        let root = crate::dynamic::storage_root("Staking", "ErasStakers");
+        let keys = api.storage().decode_keys(&root, &key).unwrap();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].as_u128(), Some(10));
+
+        // Keys which belong to another storage entry can't be decoded:
+        let root = crate::dynamic::storage_root("System", "BlockHash");
+        assert!(api.storage().decode_keys(&root, &key).is_err());
+    }
+
+    #[tokio::test]
+    async fn iterates_over_entries_sharing_leading_keys() {
+        use crate::{
+            dynamic::Value,
+            metadata::DecodeStaticType,
+            storage::address::{
+                StaticStorageAddress,
+                StorageHasher,
+                StorageMapKey,
+                Yes,
+            },
+        };
+        use codec::{
+            Compact,
+            Encode,
+        };
+        use sp_core::crypto::AccountId32;
+
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        // Some exposures across two eras.
+        let exposure =
+            |total: u128| (Compact(total), Compact(total), Vec::<()>::new()).encode();
+        for (era, account) in [(1u32, 1u8), (2, 2), (2, 3), (3, 4)] {
+            let address = StaticStorageAddress::<
+                DecodeStaticType<()>,
+                Yes,
+                (),
+                Yes,
+                (u32, AccountId32),
+            >::new(
+                "Staking",
+                "ErasStakers",
+                vec![
+                    StorageMapKey::new(era, StorageHasher::Twox64Concat),
+                    StorageMapKey::new(
+                        AccountId32::from([account; 32]),
+                        StorageHasher::Twox64Concat,
+                    ),
+                ],
+                [0; 32],
+            )
+            .unvalidated();
+            node.set_storage(address.to_bytes(), exposure(account as u128));
+        }
+        node.produce_block();
+
+        // Fix the era, and iterate over the accounts exposed in it.
+        let address =
+            crate::dynamic::storage("Staking", "ErasStakers", vec![Value::u128(2)]);
+        let mut iter = api.storage().iter(address, 1, None).await.unwrap();
+        let mut accounts = Vec::new();
+        while let Some((key, _)) = iter.next().await.unwrap() {
+            let root = crate::dynamic::storage_root("Staking", "ErasStakers");
+            let keys = api.storage().decode_keys(&root, &key.0).unwrap();
+            assert_eq!(keys[0].as_u128(), Some(2));
+            accounts.push(key);
+        }
+        assert_eq!(accounts.len(), 2);
+
+        // Every key must be given in order to fetch an entry, though.
+        let address =
+            crate::dynamic::storage("Staking", "ErasStakers", vec![Value::u128(2)]);
+        assert!(matches!(
+            api.storage().fetch(&address, None).await,
+            Err(crate::Error::StorageAddress(
+                crate::error::StorageAddressError::WrongNumberOfKeys {
+                    expected: 2,
+                    actual: 1
+                }
+            ))
+        ));
+
+        // Too many keys aren't allowed.
+        let address = crate::dynamic::storage(
+            "Staking",
+            "ErasStakers",
+            vec![Value::u128(2), Value::from_bytes([2; 32]), Value::u128(0)],
+        );
+        assert!(api.storage().iter(address, 1, None).await.is_err());
+    }
+}
@@ -2,7 +2,23 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use codec::Encode;
+use crate::{
+    dynamic::DecodedValue,
+    error::{
+        Error,
+        StorageAddressError,
+    },
+    metadata::{
+        DecodeWithMetadata,
+        Metadata,
+    },
+};
+use codec::{
+    Decode,
+    Encode,
+};
+use frame_metadata::StorageEntryType;
+use scale_info::TypeDef;
 pub use sp_runtime::traits::SignedExtension;
 
 // We use this type a bunch, so export it from here.
@@ -51,3 +67,167 @@ pub(super) fn hash_bytes(input: &[u8], hasher: &StorageHasher, bytes: &mut Vec<u
         }
     }
 }
+
+/// One of the keys of a storage map, as described by the metadata. These are handed to
+/// [`DecodeStorageKeys`] in order to decode the keys from a storage key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageKeyPart {
+    /// The hasher whose output precedes this key, if any. When several keys are hashed
+    /// together by a single hasher, only the first of them is preceded by its output.
+    pub hasher: Option<StorageHasher>,
+    /// The type ID of this key.
+    pub type_id: u32,
+}
+
+impl StorageKeyPart {
+    /// Skip over the hash which precedes this key, if there is one, leaving `bytes` at the
+    /// start of the SCALE encoded key. This fails if the key was hashed using a hasher which
+    /// doesn't keep a copy of the original key (ie one which isn't `Identity` or `*Concat`).
+    pub fn skip_hash(&self, bytes: &mut &[u8]) -> Result<(), Error> {
+        let hash_len = match &self.hasher {
+            None | Some(StorageHasher::Identity) => 0,
+            Some(StorageHasher::Twox64Concat) => 8,
+            Some(StorageHasher::Blake2_128Concat) => 16,
+            Some(hasher) => {
+                return Err(StorageAddressError::HasherNotReversible(hasher.clone()).into())
+            }
+        };
+        if bytes.len() < hash_len {
+            return Err(
+                codec::Error::from("Not enough bytes for the storage key hash").into(),
+            )
+        }
+        *bytes = &bytes[hash_len..];
+        Ok(())
+    }
+}
+
+/// Something which can be decoded from the keys of a storage map. This is implemented for
+/// tuples of types which implement [`Decode`], which statically generated storage addresses
+/// use, and for a `Vec` of [`DecodedValue`]s, which dynamic storage addresses use.
+/// `()` ignores the keys entirely.
+pub trait DecodeStorageKeys: Sized {
+    /// Decode the keys from the bytes which follow the root of a storage key (ie the hashed
+    /// pallet and entry names), given a description of each key from the metadata.
+    fn decode_storage_keys(
+        bytes: &mut &[u8],
+        parts: &[StorageKeyPart],
+        metadata: &Metadata,
+    ) -> Result<Self, Error>;
+}
+
+impl DecodeStorageKeys for () {
+    fn decode_storage_keys(
+        bytes: &mut &[u8],
+        _parts: &[StorageKeyPart],
+        _metadata: &Metadata,
+    ) -> Result<Self, Error> {
+        *bytes = &[];
+        Ok(())
+    }
+}
+
+impl DecodeStorageKeys for Vec<DecodedValue> {
+    fn decode_storage_keys(
+        bytes: &mut &[u8],
+        parts: &[StorageKeyPart],
+        metadata: &Metadata,
+    ) -> Result<Self, Error> {
+        parts
+            .iter()
+            .map(|part| {
+                part.skip_hash(bytes)?;
+                DecodedValue::decode_with_metadata(bytes, part.type_id, metadata)
+            })
+            .collect()
+    }
+}
+
+macro_rules! impl_decode_storage_keys {
+    ($($ident:ident $index:tt),+) => {
+        impl<$($ident: Decode),+> DecodeStorageKeys for ($($ident,)+) {
+            fn decode_storage_keys(
+                bytes: &mut &[u8],
+                parts: &[StorageKeyPart],
+                _metadata: &Metadata,
+            ) -> Result<Self, Error> {
+                let num_keys = [$(stringify!($ident)),+].len();
+                if parts.len() != num_keys {
+                    return Err(StorageAddressError::WrongNumberOfKeys {
+                        expected: parts.len(),
+                        actual: num_keys,
+                    }
+                    .into())
+                }
+                Ok(($({
+                    parts[$index].skip_hash(bytes)?;
+                    $ident::decode(bytes)?
+                },)+))
+            }
+        }
+    }
+}
+
+impl_decode_storage_keys!(A 0);
+impl_decode_storage_keys!(A 0, B 1);
+impl_decode_storage_keys!(A 0, B 1, C 2);
+impl_decode_storage_keys!(A 0, B 1, C 2, D 3);
+impl_decode_storage_keys!(A 0, B 1, C 2, D 3, E 4);
+impl_decode_storage_keys!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_decode_storage_keys!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_decode_storage_keys!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Describe each of the keys of the given storage entry using the metadata. Plain storage
+/// entries have no keys.
+pub(super) fn storage_key_parts(
+    metadata: &Metadata,
+    pallet_name: &str,
+    entry_name: &str,
+) -> Result<Vec<StorageKeyPart>, Error> {
+    let storage = metadata.pallet(pallet_name)?.storage(entry_name)?;
+    let (hashers, key) = match &storage.ty {
+        StorageEntryType::Plain(_) => return Ok(Vec::new()),
+        StorageEntryType::Map { hashers, key, .. } => (hashers, key),
+    };
+
+    let ty = metadata
+        .resolve_type(key.id())
+        .ok_or_else(|| StorageAddressError::TypeNotFound(key.id()))?;
+    // As when encoding keys; a tuple key is made up of one key per field.
+    let type_ids: Vec<u32> = match ty.type_def() {
+        TypeDef::Tuple(tuple) => tuple.fields().iter().map(|f| f.id()).collect(),
+        _other => vec![key.id()],
+    };
+
+    if hashers.len() == type_ids.len() {
+        // A hasher per key; each key is preceded by its hash.
+        Ok(hashers
+            .iter()
+            .zip(type_ids)
+            .map(|(hasher, type_id)| {
+                StorageKeyPart {
+                    hasher: Some(hasher.clone()),
+                    type_id,
+                }
+            })
+            .collect())
+    } else if hashers.len() == 1 {
+        // One hasher; the keys are all encoded together after the one hash.
+        Ok(type_ids
+            .into_iter()
+            .enumerate()
+            .map(|(idx, type_id)| {
+                StorageKeyPart {
+                    hasher: (idx == 0).then(|| hashers[0].clone()),
+                    type_id,
+                }
+            })
+            .collect())
+    } else {
+        Err(StorageAddressError::WrongNumberOfHashers {
+            hashers: hashers.len(),
+            fields: type_ids.len(),
+        }
+        .into())
+    }
+}
@@ -34,6 +34,18 @@ pub fn storage_address_bytes<Address: StorageAddress>(
     Ok(bytes)
 }
 
+/// Outputs the [`storage_address_root_bytes`] as well as the bytes shared by every entry that
+/// iterating over the given address will visit.
+pub fn storage_address_prefix_bytes<Address: StorageAddress>(
+    addr: &Address,
+    metadata: &Metadata,
+) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    write_storage_address_root_bytes(addr, &mut bytes);
+    addr.append_entry_prefix_bytes(metadata, &mut bytes)?;
+    Ok(bytes)
+}
+
 /// Outputs a vector containing the bytes written by [`write_storage_address_root_bytes`].
 pub fn storage_address_root_bytes<Address: StorageAddress>(addr: &Address) -> Vec<u8> {
     let mut bytes = Vec::new();
@@ -0,0 +1,212 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Decode an already encoded extrinsic into its parts, and verify its signature.
+
+use crate::{
+    blocks::{
+        decode_extrinsic_parts,
+        ExtrinsicTypeIds,
+        SignedDetails,
+    },
+    error::{
+        Error,
+        ExtrinsicVerificationError,
+    },
+    metadata::CallMetadata,
+    utils::AccountId20,
+    Config,
+    Metadata,
+};
+use codec::{
+    Compact,
+    Decode,
+    Error as CodecError,
+};
+use sp_runtime::{
+    traits::{
+        IdentifyAccount,
+        Verify,
+    },
+    MultiAddress,
+};
+
+/// An extrinsic which has been decoded from its SCALE encoded bytes (for instance, those
+/// from [`super::SubmittableExtrinsic::encoded()`] or from a block) using the metadata, so
+/// that each of its parts can be inspected. It's obtained via
+/// [`super::TxClient::decode_extrinsic()`].
+#[derive(Clone, Debug)]
+pub struct DecodedExtrinsic<T: Config> {
+    bytes: Vec<u8>,
+    version_idx: usize,
+    signed: Option<SignedDetails>,
+    call_start_idx: usize,
+    metadata: Metadata,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<T: Config> DecodedExtrinsic<T> {
+    /// Decode the given extrinsic bytes, which are expected to begin with the compact
+    /// encoded length of the rest of the extrinsic.
+    pub fn decode(bytes: &[u8], metadata: &Metadata) -> Result<Self, Error> {
+        let type_ids = ExtrinsicTypeIds::new(metadata)?;
+        let (signed, call_start_idx) =
+            decode_extrinsic_parts(bytes, 0, metadata, &type_ids)?;
+
+        // This will have been decoded successfully above.
+        let input = &mut &*bytes;
+        let _len = <Compact<u32>>::decode(input)?;
+        let version_idx = bytes.len() - input.len();
+
+        Ok(DecodedExtrinsic {
+            bytes: bytes.to_vec(),
+            version_idx,
+            signed,
+            call_start_idx,
+            metadata: metadata.clone(),
+            marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Return _all_ of the SCALE encoded bytes representing this extrinsic.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The version of the extrinsic format. Only version 4 extrinsics can be decoded.
+    pub fn version(&self) -> u8 {
+        self.bytes[self.version_idx] & 0b0111_1111
+    }
+
+    /// Is this extrinsic signed?
+    pub fn is_signed(&self) -> bool {
+        self.signed.is_some()
+    }
+
+    /// Return the bytes representing the address of the signer, if the
+    /// extrinsic is signed.
+    pub fn address_bytes(&self) -> Option<&[u8]> {
+        self.signed
+            .map(|s| &self.bytes[s.address_start_idx..s.address_end_idx])
+    }
+
+    /// Decode and return the address of the signer, if the extrinsic is signed.
+    pub fn address(&self) -> Result<Option<T::Address>, CodecError> {
+        self.address_bytes()
+            .map(|mut bytes| T::Address::decode(&mut bytes))
+            .transpose()
+    }
+
+    /// Return the bytes representing the signature, if the extrinsic is signed.
+    pub fn signature_bytes(&self) -> Option<&[u8]> {
+        self.signed
+            .map(|s| &self.bytes[s.address_end_idx..s.signature_end_idx])
+    }
+
+    /// Decode and return the signature, if the extrinsic is signed.
+    pub fn signature(&self) -> Result<Option<T::Signature>, CodecError>
+    where
+        T::Signature: Decode,
+    {
+        self.signature_bytes()
+            .map(|mut bytes| T::Signature::decode(&mut bytes))
+            .transpose()
+    }
+
+    /// Return the bytes representing the signed extension values (sometimes known
+    /// as the "extra" parameters), if the extrinsic is signed.
+    pub fn signed_extensions_bytes(&self) -> Option<&[u8]> {
+        self.signed
+            .map(|s| &self.bytes[s.signature_end_idx..s.extensions_end_idx])
+    }
+
+    /// Return the bytes representing the call, which include the pallet and
+    /// call index followed by the call arguments.
+    pub fn call_bytes(&self) -> &[u8] {
+        &self.bytes[self.call_start_idx..]
+    }
+
+    /// Fetch the metadata for this extrinsic's call.
+    pub fn call_metadata(&self) -> &CallMetadata {
+        let call = self.call_bytes();
+        self.metadata
+            .call(call[0], call[1])
+            .expect("this must exist in order to have decoded the extrinsic")
+    }
+
+    /// The name of the pallet that the call belongs to.
+    pub fn pallet_name(&self) -> &str {
+        self.call_metadata().pallet()
+    }
+
+    /// The name of the call (ie the name of the variant that it corresponds to).
+    pub fn variant_name(&self) -> &str {
+        self.call_metadata().call()
+    }
+
+    /// Reconstruct the payload which was signed, given the SCALE encoded "additional"
+    /// parameters, which aren't a part of the extrinsic itself (see
+    /// [`super::TxClient::additional_params()`]). Returns `None` if the extrinsic
+    /// isn't signed.
+    pub fn signer_payload(&self, additional: &[u8]) -> Option<Vec<u8>> {
+        let extra = self.signed_extensions_bytes()?;
+        Some(super::tx_client::signer_payload(
+            self.call_bytes(),
+            extra,
+            additional,
+        ))
+    }
+
+    /// Check that the signature of this extrinsic is valid, given the SCALE encoded
+    /// "additional" parameters, which aren't a part of the extrinsic itself (see
+    /// [`super::TxClient::additional_params()`]).
+    pub fn verify_signature(&self, additional: &[u8]) -> Result<(), Error>
+    where
+        T::Signature: Decode,
+        T::Address: AddressAccountId<T::AccountId>,
+        <T::Signature as Verify>::Signer: IdentifyAccount<AccountId = T::AccountId>,
+    {
+        let (address, signature) = match (self.address()?, self.signature()?) {
+            (Some(address), Some(signature)) => (address, signature),
+            _ => return Err(ExtrinsicVerificationError::NotSigned.into()),
+        };
+        let account_id = address
+            .account_id()
+            .ok_or(ExtrinsicVerificationError::UnknownSigner)?;
+        let payload = self
+            .signer_payload(additional)
+            .ok_or(ExtrinsicVerificationError::NotSigned)?;
+
+        if signature.verify(&payload[..], &account_id) {
+            Ok(())
+        } else {
+            Err(ExtrinsicVerificationError::InvalidSignature.into())
+        }
+    }
+}
+
+/// Obtain the account ID that an address refers to, if this is possible without
+/// looking anything up. This is needed in order to verify the signature of an extrinsic
+/// (see [`DecodedExtrinsic::verify_signature()`]).
+pub trait AddressAccountId<AccountId> {
+    /// The account ID that this address refers to, if it can be known.
+    fn account_id(&self) -> Option<AccountId>;
+}
+
+impl<AccountId: Clone, Index> AddressAccountId<AccountId>
+    for MultiAddress<AccountId, Index>
+{
+    fn account_id(&self) -> Option<AccountId> {
+        match self {
+            MultiAddress::Id(id) => Some(id.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl AddressAccountId<AccountId20> for AccountId20 {
+    fn account_id(&self) -> Option<AccountId20> {
+        Some(*self)
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! A [`Signer`] for Ethereum-compatible chains, which signs with an ECDSA key and
+//! identifies the account by its 20 byte address.
+
+use crate::{
+    tx::Signer,
+    utils::{
+        AccountId20,
+        EthereumSignature,
+    },
+    Config,
+};
+use sp_core::{
+    ecdsa,
+    Pair,
+};
+
+/// A [`Signer`] for chains which use [`AccountId20`]s and [`EthereumSignature`]s, such as
+/// those configured with [`crate::config::EthereumConfig`]. Payloads are hashed with
+/// keccak-256 and then signed with the given secp256k1 key, and the account ID is the
+/// address derived from its public key.
+///
+/// # Example
+///
+/// ```rust
+/// use subxt::{
+///     config::EthereumConfig,
+///     ext::sp_core::{
+///         ecdsa,
+///         Pair,
+///     },
+///     tx::EthereumSigner,
+/// };
+///
+/// // An Ethereum private key is the raw 32 byte secp256k1 secret key.
+/// let private_key =
+///     hex::decode("5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133")
+///         .unwrap();
+/// let pair = ecdsa::Pair::from_seed_slice(&private_key).unwrap();
+/// let signer = EthereumSigner::<EthereumConfig>::new(pair);
+///
+/// assert_eq!(
+///     signer.account_id().to_string(),
+///     "0xf24FF3a9CF04c71Dbc94D0b566f7A27B94566cac"
+/// );
+/// ```
+pub struct EthereumSigner<T: Config> {
+    account_id: AccountId20,
+    nonce: Option<T::Index>,
+    pair: ecdsa::Pair,
+}
+
+impl<T: Config> Clone for EthereumSigner<T> {
+    fn clone(&self) -> Self {
+        Self {
+            account_id: self.account_id,
+            nonce: self.nonce,
+            pair: self.pair.clone(),
+        }
+    }
+}
+
+impl<T: Config> std::fmt::Debug for EthereumSigner<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EthereumSigner")
+            .field("account_id", &self.account_id)
+            .field("nonce", &self.nonce)
+            .finish()
+    }
+}
+
+impl<T: Config> EthereumSigner<T> {
+    /// Creates a new [`EthereumSigner`] from an ECDSA pair.
+    pub fn new(pair: ecdsa::Pair) -> Self {
+        Self {
+            account_id: AccountId20::from_public(&pair.public()),
+            nonce: None,
+            pair,
+        }
+    }
+
+    /// Sets the nonce to a new value. By default, the nonce will
+    /// be retrieved from the node. Setting one here will override that.
+    pub fn set_nonce(&mut self, nonce: T::Index) {
+        self.nonce = Some(nonce);
+    }
+
+    /// Increment the nonce.
+    pub fn increment_nonce(&mut self) {
+        self.nonce = self.nonce.map(|nonce| nonce + 1u32.into());
+    }
+
+    /// Returns the address of the account that this signs for.
+    pub fn account_id(&self) -> &AccountId20 {
+        &self.account_id
+    }
+
+    /// Returns the [`ecdsa::Pair`] that this signs with.
+    pub fn signer(&self) -> &ecdsa::Pair {
+        &self.pair
+    }
+}
+
+impl<T> Signer<T> for EthereumSigner<T>
+where
+    T: Config<AccountId = AccountId20>,
+    T::Address: From<AccountId20>,
+    T::Signature: From<EthereumSignature>,
+{
+    fn nonce(&self) -> Option<T::Index> {
+        self.nonce
+    }
+
+    fn account_id(&self) -> &AccountId20 {
+        &self.account_id
+    }
+
+    fn address(&self) -> T::Address {
+        self.account_id.into()
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> T::Signature {
+        let hash = sp_core::keccak_256(signer_payload);
+        EthereumSignature(self.pair.sign_prehashed(&hash)).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        config::EthereumConfig,
+        dynamic::Value,
+        rpc::MockNode,
+        OnlineClient,
+    };
+    use sp_runtime::traits::Verify;
+    use std::sync::Arc;
+
+    // The well known "Alith" development account.
+    const ALITH_PRIVATE_KEY: &str =
+        "5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133";
+    const ALITH_ADDRESS: &str = "0xf24FF3a9CF04c71Dbc94D0b566f7A27B94566cac";
+
+    fn alith() -> EthereumSigner<EthereumConfig> {
+        let private_key = hex::decode(ALITH_PRIVATE_KEY).unwrap();
+        EthereumSigner::new(ecdsa::Pair::from_seed_slice(&private_key).unwrap())
+    }
+
+    #[test]
+    fn derives_the_address() {
+        let signer = alith();
+        assert_eq!(signer.account_id().to_string(), ALITH_ADDRESS);
+        assert_eq!(
+            ALITH_ADDRESS.parse::<AccountId20>().unwrap(),
+            *signer.account_id()
+        );
+    }
+
+    #[test]
+    fn signatures_verify() {
+        let signer = alith();
+        let signature = Signer::sign(&signer, b"hello");
+        assert!(signature.verify(&b"hello"[..], signer.account_id()));
+        assert!(!signature.verify(&b"goodbye"[..], signer.account_id()));
+        assert!(!signature.verify(&b"hello"[..], &AccountId20([1; 20])));
+    }
+
+    #[tokio::test]
+    async fn transactions_can_be_signed_and_submitted() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<EthereumConfig>::new(metadata.to_vec());
+        // Fails if the signed extensions don't line up with those in the metadata.
+        let api = OnlineClient::<EthereumConfig>::from_rpc_client_validated(Arc::new(
+            node.clone(),
+        ))
+        .await
+        .unwrap();
+
+        let signer = alith();
+        node.set_account_nonce(signer.account_id(), 5);
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+        let ext = api
+            .tx()
+            .create_signed(&tx, &signer, Default::default())
+            .await
+            .unwrap();
+        ext.submit().await.unwrap();
+
+        assert_eq!(node.pending_extrinsics(), vec![ext.encoded().to_vec()]);
+    }
+}
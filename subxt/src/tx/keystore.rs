@@ -0,0 +1,710 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! A [`KeystoreSigner`], whose keys can be loaded from polkadot-js JSON keystores,
+//! encrypted [`KeyFile`]s or secret URIs.
+
+use crate::{
+    error::KeystoreError,
+    tx::Signer,
+    Config,
+};
+use poly1305::{
+    universal_hash::KeyInit,
+    Poly1305,
+};
+use salsa20::{
+    cipher::{
+        KeyIvInit,
+        StreamCipher,
+    },
+    XSalsa20,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use sp_core::{
+    ecdsa,
+    ed25519,
+    sr25519,
+    Pair,
+};
+use sp_runtime::traits::{
+    IdentifyAccount,
+    Verify,
+};
+use std::path::Path;
+
+// Scrypt parameters used by polkadot-js, which we also use by default.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+
+// The polkadot-js encrypted content is a PKCS8-like header, the secret key,
+// a divider and then the public key.
+const PKCS8_HEADER: [u8; 16] = [48, 83, 2, 1, 1, 48, 5, 6, 3, 43, 101, 112, 4, 34, 4, 32];
+const PKCS8_DIVIDER: [u8; 5] = [161, 35, 3, 33, 0];
+
+/// The cryptographic schemes that a [`KeystoreSigner`] can sign with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CryptoScheme {
+    /// Schnorr signatures over ristretto25519.
+    Sr25519,
+    /// Ed25519 signatures.
+    Ed25519,
+    /// ECDSA signatures over secp256k1.
+    Ecdsa,
+}
+
+impl CryptoScheme {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sr25519" => Some(CryptoScheme::Sr25519),
+            "ed25519" => Some(CryptoScheme::Ed25519),
+            "ecdsa" => Some(CryptoScheme::Ecdsa),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum KeyPair {
+    Sr25519(sr25519::Pair),
+    Ed25519(ed25519::Pair),
+    Ecdsa(ecdsa::Pair),
+}
+
+impl KeyPair {
+    fn from_uri(
+        scheme: CryptoScheme,
+        suri: &str,
+        password: Option<&str>,
+    ) -> Result<Self, KeystoreError> {
+        let pair = match scheme {
+            CryptoScheme::Sr25519 => {
+                sr25519::Pair::from_string(suri, password).map(KeyPair::Sr25519)
+            }
+            CryptoScheme::Ed25519 => {
+                ed25519::Pair::from_string(suri, password).map(KeyPair::Ed25519)
+            }
+            CryptoScheme::Ecdsa => {
+                ecdsa::Pair::from_string(suri, password).map(KeyPair::Ecdsa)
+            }
+        };
+        pair.map_err(KeystoreError::InvalidSecretUri)
+    }
+
+    fn scheme(&self) -> CryptoScheme {
+        match self {
+            KeyPair::Sr25519(_) => CryptoScheme::Sr25519,
+            KeyPair::Ed25519(_) => CryptoScheme::Ed25519,
+            KeyPair::Ecdsa(_) => CryptoScheme::Ecdsa,
+        }
+    }
+
+    fn public_key(&self) -> Vec<u8> {
+        match self {
+            KeyPair::Sr25519(pair) => pair.public().0.to_vec(),
+            KeyPair::Ed25519(pair) => pair.public().0.to_vec(),
+            KeyPair::Ecdsa(pair) => pair.public().0.to_vec(),
+        }
+    }
+}
+
+/// A [`Signer`] for `sr25519`, `ed25519` or `ecdsa` keys, which can be loaded from:
+///
+/// - A secret URI, such as a mnemonic phrase followed by a derivation path like
+///   `//Alice/soft` (see [`KeystoreSigner::from_uri()`]).
+/// - A JSON keystore exported from polkadot-js (see
+///   [`KeystoreSigner::from_polkadot_js_json()`]).
+/// - A password protected [`KeyFile`] (see [`KeystoreSigner::from_key_file()`]).
+///
+/// # Example
+///
+/// ```rust
+/// use subxt::{
+///     tx::{
+///         CryptoScheme,
+///         KeystoreSigner,
+///     },
+///     PolkadotConfig,
+/// };
+///
+/// let signer = KeystoreSigner::<PolkadotConfig>::from_uri(
+///     CryptoScheme::Sr25519,
+///     "bottom drive obey lake curtain smoke basket hold race lonely fit walk//Alice",
+///     None,
+/// )
+/// .unwrap();
+/// ```
+pub struct KeystoreSigner<T: Config> {
+    account_id: T::AccountId,
+    nonce: Option<T::Index>,
+    pair: KeyPair,
+}
+
+impl<T: Config> Clone for KeystoreSigner<T> {
+    fn clone(&self) -> Self {
+        Self {
+            account_id: self.account_id.clone(),
+            nonce: self.nonce,
+            pair: self.pair.clone(),
+        }
+    }
+}
+
+impl<T: Config> std::fmt::Debug for KeystoreSigner<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeystoreSigner")
+            .field("scheme", &self.pair.scheme())
+            .field("account_id", &self.account_id)
+            .field("nonce", &self.nonce)
+            .finish()
+    }
+}
+
+impl<T> KeystoreSigner<T>
+where
+    T: Config,
+    <T::Signature as Verify>::Signer: From<sr25519::Public>
+        + From<ed25519::Public>
+        + From<ecdsa::Public>
+        + IdentifyAccount<AccountId = T::AccountId>,
+{
+    fn new(pair: KeyPair) -> Self {
+        let signer: <T::Signature as Verify>::Signer = match &pair {
+            KeyPair::Sr25519(pair) => pair.public().into(),
+            KeyPair::Ed25519(pair) => pair.public().into(),
+            KeyPair::Ecdsa(pair) => pair.public().into(),
+        };
+        Self {
+            account_id: signer.into_account(),
+            nonce: None,
+            pair,
+        }
+    }
+
+    /// Create a [`KeystoreSigner`] from a secret URI, which is a mnemonic phrase, a hex
+    /// encoded seed or a development account name, optionally followed by a derivation
+    /// path made up of hard (`//hard`) and soft (`/soft`) junctions. The optional
+    /// `password` is used along with the mnemonic phrase to generate the seed.
+    pub fn from_uri(
+        scheme: CryptoScheme,
+        suri: &str,
+        password: Option<&str>,
+    ) -> Result<Self, KeystoreError> {
+        KeyPair::from_uri(scheme, suri, password).map(Self::new)
+    }
+
+    /// Create a [`KeystoreSigner`] from a JSON keystore which has been exported from
+    /// polkadot-js, and which is encrypted with the given password. Only version 3
+    /// keystores, whose keys are encrypted using scrypt and xsalsa20-poly1305, are
+    /// supported.
+    pub fn from_polkadot_js_json(
+        json: &str,
+        password: &str,
+    ) -> Result<Self, KeystoreError> {
+        let keystore: PolkadotJsKeystore = serde_json::from_str(json)?;
+        keystore.decrypt(password).map(Self::new)
+    }
+
+    /// Create a [`KeystoreSigner`] from a [`KeyFile`] which is encrypted with the
+    /// given password.
+    pub fn from_key_file(
+        key_file: &KeyFile,
+        password: &str,
+    ) -> Result<Self, KeystoreError> {
+        key_file.decrypt(password).map(Self::new)
+    }
+}
+
+impl<T: Config> KeystoreSigner<T> {
+    /// The cryptographic scheme that this signs with.
+    pub fn scheme(&self) -> CryptoScheme {
+        self.pair.scheme()
+    }
+
+    /// Sets the nonce to a new value. By default, the nonce will
+    /// be retrieved from the node. Setting one here will override that.
+    pub fn set_nonce(&mut self, nonce: T::Index) {
+        self.nonce = Some(nonce);
+    }
+
+    /// Increment the nonce.
+    pub fn increment_nonce(&mut self) {
+        self.nonce = self.nonce.map(|nonce| nonce + 1u32.into());
+    }
+
+    /// Return the account ID.
+    pub fn account_id(&self) -> &T::AccountId {
+        &self.account_id
+    }
+}
+
+impl<T> Signer<T> for KeystoreSigner<T>
+where
+    T: Config,
+    T::AccountId: Into<T::Address> + Clone,
+    T::Signature:
+        From<sr25519::Signature> + From<ed25519::Signature> + From<ecdsa::Signature>,
+{
+    fn nonce(&self) -> Option<T::Index> {
+        self.nonce
+    }
+
+    fn account_id(&self) -> &T::AccountId {
+        &self.account_id
+    }
+
+    fn address(&self) -> T::Address {
+        self.account_id.clone().into()
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> T::Signature {
+        match &self.pair {
+            KeyPair::Sr25519(pair) => pair.sign(signer_payload).into(),
+            KeyPair::Ed25519(pair) => pair.sign(signer_payload).into(),
+            KeyPair::Ecdsa(pair) => pair.sign(signer_payload).into(),
+        }
+    }
+}
+
+/// A key which is stored encrypted with a password, and which can be loaded into a
+/// [`KeystoreSigner`] via [`KeystoreSigner::from_key_file()`]. This is serialized as JSON.
+///
+/// The secret URI that the key was created from is encrypted with xsalsa20-poly1305,
+/// using a key derived from the password with scrypt.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyFile {
+    version: u32,
+    scheme: CryptoScheme,
+    public_key: String,
+    scrypt: ScryptParams,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+impl KeyFile {
+    const VERSION: u32 = 1;
+
+    /// Encrypt a secret URI (see [`KeystoreSigner::from_uri()`]) for the given scheme
+    /// with the given password.
+    pub fn encrypt(
+        scheme: CryptoScheme,
+        suri: &str,
+        password: &str,
+    ) -> Result<Self, KeystoreError> {
+        let scrypt = ScryptParams {
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+        };
+        Self::encrypt_with_params(scheme, suri, password, scrypt)
+    }
+
+    fn encrypt_with_params(
+        scheme: CryptoScheme,
+        suri: &str,
+        password: &str,
+        scrypt: ScryptParams,
+    ) -> Result<Self, KeystoreError> {
+        let pair = KeyPair::from_uri(scheme, suri, None)?;
+
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        random_bytes(&mut salt)?;
+        random_bytes(&mut nonce)?;
+
+        let key = derive_key(password, &salt, scrypt)?;
+        let ciphertext = secretbox_seal(&key, &nonce, suri.as_bytes());
+
+        Ok(KeyFile {
+            version: Self::VERSION,
+            scheme,
+            public_key: to_hex(&pair.public_key()),
+            scrypt,
+            salt: to_hex(&salt),
+            nonce: to_hex(&nonce),
+            ciphertext: to_hex(&ciphertext),
+        })
+    }
+
+    /// Load a [`KeyFile`] from the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, KeystoreError> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Save this [`KeyFile`] to the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), KeystoreError> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// The cryptographic scheme of the key.
+    pub fn scheme(&self) -> CryptoScheme {
+        self.scheme
+    }
+
+    /// The hex encoded public key, which is available without the password.
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    fn decrypt(&self, password: &str) -> Result<KeyPair, KeystoreError> {
+        if self.version != Self::VERSION {
+            return Err(KeystoreError::Unsupported(format!(
+                "key file version {}",
+                self.version
+            )))
+        }
+        let salt = from_hex(&self.salt)?;
+        let nonce = from_hex(&self.nonce)?;
+        let ciphertext = from_hex(&self.ciphertext)?;
+
+        let key = derive_key(password, &salt, self.scrypt)?;
+        let suri = secretbox_open(&key, &nonce, &ciphertext)?;
+        let suri = String::from_utf8(suri)
+            .map_err(|_| KeystoreError::Invalid("secret URI is not UTF-8".into()))?;
+
+        let pair = KeyPair::from_uri(self.scheme, &suri, None)?;
+        if to_hex(&pair.public_key()) != self.public_key {
+            return Err(KeystoreError::Invalid(
+                "the public key does not match the secret key".into(),
+            ))
+        }
+        Ok(pair)
+    }
+}
+
+/// A JSON keystore as exported from polkadot-js.
+#[derive(Deserialize)]
+struct PolkadotJsKeystore {
+    encoded: String,
+    encoding: PolkadotJsEncoding,
+}
+
+#[derive(Deserialize)]
+struct PolkadotJsEncoding {
+    content: Vec<String>,
+    #[serde(rename = "type")]
+    ty: Vec<String>,
+    version: serde_json::Value,
+}
+
+impl PolkadotJsKeystore {
+    fn decrypt(&self, password: &str) -> Result<KeyPair, KeystoreError> {
+        let encoding = &self.encoding;
+        let version = match &encoding.version {
+            serde_json::Value::String(version) => version.clone(),
+            version => version.to_string(),
+        };
+        if version != "3" {
+            return Err(KeystoreError::Unsupported(format!(
+                "polkadot-js keystore version {}",
+                version
+            )))
+        }
+        let has_type = |ty: &str| encoding.ty.iter().any(|t| t == ty);
+        if !has_type("scrypt") || !has_type("xsalsa20-poly1305") {
+            return Err(KeystoreError::Unsupported(format!(
+                "encryption type {:?}",
+                encoding.ty
+            )))
+        }
+        let scheme = match encoding.content.as_slice() {
+            [pkcs8, scheme] if pkcs8 == "pkcs8" => CryptoScheme::from_name(scheme),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            KeystoreError::Unsupported(format!("content {:?}", encoding.content))
+        })?;
+
+        let encoded = base64::decode(&self.encoded)
+            .map_err(|e| KeystoreError::Invalid(format!("encoded data: {}", e)))?;
+        if encoded.len() < SALT_LEN + 12 + NONCE_LEN + TAG_LEN {
+            return Err(KeystoreError::Invalid("encoded data is too short".into()))
+        }
+
+        // The scrypt salt and parameters come first, then the nonce and the encrypted data.
+        let (salt, rest) = encoded.split_at(SALT_LEN);
+        let (params, rest) = rest.split_at(12);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let param = |idx: usize| {
+            u32::from_le_bytes(params[idx * 4..idx * 4 + 4].try_into().unwrap())
+        };
+        let (n, p, r) = (param(0), param(1), param(2));
+        if !n.is_power_of_two() {
+            return Err(KeystoreError::Invalid(format!("scrypt N of {}", n)))
+        }
+        let scrypt = ScryptParams {
+            log_n: n.trailing_zeros() as u8,
+            r,
+            p,
+        };
+
+        let key = derive_key(password, salt, scrypt)?;
+        let decrypted = secretbox_open(&key, nonce, ciphertext)?;
+        decode_pkcs8(scheme, &decrypted)
+    }
+}
+
+// Decode the secret key from the decrypted polkadot-js PKCS8 data, checking that it
+// matches the public key stored alongside it.
+fn decode_pkcs8(scheme: CryptoScheme, data: &[u8]) -> Result<KeyPair, KeystoreError> {
+    let invalid = |msg: &str| KeystoreError::Invalid(format!("PKCS8 data: {}", msg));
+
+    let data = data
+        .strip_prefix(&PKCS8_HEADER[..])
+        .ok_or_else(|| invalid("bad header"))?;
+    let secret_len = match scheme {
+        CryptoScheme::Sr25519 | CryptoScheme::Ed25519 => 64,
+        CryptoScheme::Ecdsa => 32,
+    };
+    if data.len() < secret_len + PKCS8_DIVIDER.len() {
+        return Err(invalid("too short"))
+    }
+    let (secret, rest) = data.split_at(secret_len);
+    let public = rest
+        .strip_prefix(&PKCS8_DIVIDER[..])
+        .ok_or_else(|| invalid("bad divider"))?;
+
+    let pair = match scheme {
+        // polkadot-js stores sr25519 secret keys in their "ed25519 expanded" form.
+        CryptoScheme::Sr25519 => {
+            let secret = schnorrkel::SecretKey::from_ed25519_bytes(secret)
+                .map_err(|_| invalid("bad sr25519 secret key"))?;
+            KeyPair::Sr25519(sr25519::Pair::from(secret))
+        }
+        // ed25519 secret keys are stored as the seed followed by the public key.
+        CryptoScheme::Ed25519 => {
+            let pair = ed25519::Pair::from_seed_slice(&secret[..32])
+                .map_err(|_| invalid("bad ed25519 secret key"))?;
+            KeyPair::Ed25519(pair)
+        }
+        CryptoScheme::Ecdsa => {
+            let pair = ecdsa::Pair::from_seed_slice(secret)
+                .map_err(|_| invalid("bad ecdsa secret key"))?;
+            KeyPair::Ecdsa(pair)
+        }
+    };
+    if pair.public_key() != public {
+        return Err(invalid("the public key does not match the secret key"))
+    }
+    Ok(pair)
+}
+
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    params: ScryptParams,
+) -> Result<[u8; 32], KeystoreError> {
+    let params = scrypt::Params::new(params.log_n, params.r, params.p)
+        .map_err(|_| KeystoreError::Invalid("bad scrypt parameters".into()))?;
+    let mut key = [0u8; 32];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+        .expect("32 bytes is a valid scrypt output length; qed");
+    Ok(key)
+}
+
+// Encrypt some data in the same way as NaCl's `crypto_secretbox`, returning the
+// authentication tag followed by the ciphertext.
+fn secretbox_seal(key: &[u8; 32], nonce: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let mut cipher =
+        XSalsa20::new_from_slices(key, nonce).expect("key and nonce lengths are valid");
+    // The first 32 bytes of the keystream are used as the poly1305 key.
+    let mut poly_key = [0u8; 32];
+    cipher.apply_keystream(&mut poly_key);
+
+    let mut ciphertext = plaintext.to_vec();
+    cipher.apply_keystream(&mut ciphertext);
+    let tag = Poly1305::new_from_slice(&poly_key)
+        .expect("32 bytes is a valid poly1305 key length; qed")
+        .compute_unpadded(&ciphertext);
+
+    let mut sealed = tag.to_vec();
+    sealed.extend(ciphertext);
+    sealed
+}
+
+// Decrypt data produced by `secretbox_seal`, checking that it hasn't been tampered with.
+fn secretbox_open(
+    key: &[u8; 32],
+    nonce: &[u8],
+    sealed: &[u8],
+) -> Result<Vec<u8>, KeystoreError> {
+    if nonce.len() != NONCE_LEN || sealed.len() < TAG_LEN {
+        return Err(KeystoreError::DecryptionFailed)
+    }
+    let mut cipher =
+        XSalsa20::new_from_slices(key, nonce).expect("key and nonce lengths are valid");
+    let mut poly_key = [0u8; 32];
+    cipher.apply_keystream(&mut poly_key);
+
+    let (tag, ciphertext) = sealed.split_at(TAG_LEN);
+    let expected_tag = Poly1305::new_from_slice(&poly_key)
+        .expect("32 bytes is a valid poly1305 key length; qed")
+        .compute_unpadded(ciphertext);
+    // Compare in constant time, to not leak how much of the tag is correct.
+    let diff = tag
+        .iter()
+        .zip(expected_tag.iter())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    if diff != 0 {
+        return Err(KeystoreError::DecryptionFailed)
+    }
+
+    let mut plaintext = ciphertext.to_vec();
+    cipher.apply_keystream(&mut plaintext);
+    Ok(plaintext)
+}
+
+fn random_bytes(bytes: &mut [u8]) -> Result<(), KeystoreError> {
+    getrandom::getrandom(bytes)
+        .map_err(|e| KeystoreError::Io(std::io::Error::other(e.to_string())))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, KeystoreError> {
+    hex::decode(s.trim_start_matches("0x"))
+        .map_err(|e| KeystoreError::Invalid(format!("hex value: {}", e)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::PolkadotConfig;
+    use sp_core::crypto::AccountId32;
+
+    // Alice's sr25519 key, exported in the polkadot-js format with the password
+    // "correct horse" (using a lower scrypt cost than polkadot-js, to keep the test fast).
+    const ALICE_JSON: &str = r#"{"encoded":"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8ABAAAAQAAAAgAAABkZWZnaGlqa2xtbm9wcXJzdHV2d3h5ensoi5WVf5v7UrYcA3kWNDf3q5LR6oGXJrf/YLEytPEfXijCLDv/9uNILyWoqW4mRF5H+7NEzcSBkrDjLlCGMpoPtZgTrrJt8WxvqRXvGPDUs9EN6RUsr7J3Oc3wsZCWDWRYLoZReV/9EkEx1P8JdVrQ+tmRAR1GGhvYbkrchGs7bavQrsGT","encoding":{"content":["pkcs8","sr25519"],"type":["scrypt","xsalsa20-poly1305"],"version":"3"},"address":"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY","meta":{"name":"Alice"}}"#;
+
+    fn alice_account_id() -> AccountId32 {
+        let public = hex::decode(
+            "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+        )
+        .unwrap();
+        AccountId32::new(public.try_into().unwrap())
+    }
+
+    const TEST_SCRYPT: ScryptParams = ScryptParams {
+        log_n: 10,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn loads_polkadot_js_json() {
+        let signer = KeystoreSigner::<PolkadotConfig>::from_polkadot_js_json(
+            ALICE_JSON,
+            "correct horse",
+        )
+        .unwrap();
+        assert_eq!(signer.scheme(), CryptoScheme::Sr25519);
+        assert_eq!(signer.account_id(), &alice_account_id());
+
+        let signature = Signer::sign(&signer, b"hello");
+        assert!(signature.verify(&b"hello"[..], signer.account_id()));
+
+        assert!(matches!(
+            KeystoreSigner::<PolkadotConfig>::from_polkadot_js_json(ALICE_JSON, "wrong"),
+            Err(KeystoreError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn derives_keys_from_secret_uris() {
+        let alice = KeystoreSigner::<PolkadotConfig>::from_uri(
+            CryptoScheme::Sr25519,
+            "//Alice",
+            None,
+        )
+        .unwrap();
+        assert_eq!(alice.account_id(), &alice_account_id());
+
+        for scheme in [
+            CryptoScheme::Sr25519,
+            CryptoScheme::Ed25519,
+            CryptoScheme::Ecdsa,
+        ] {
+            let hard = KeystoreSigner::<PolkadotConfig>::from_uri(
+                scheme,
+                "//Alice//stash",
+                None,
+            )
+            .unwrap();
+            let signature = Signer::sign(&hard, b"hello");
+            assert!(signature.verify(&b"hello"[..], hard.account_id()));
+        }
+
+        // Only sr25519 supports soft derivation.
+        assert!(KeystoreSigner::<PolkadotConfig>::from_uri(
+            CryptoScheme::Sr25519,
+            "//Alice/soft",
+            None
+        )
+        .is_ok());
+        assert!(matches!(
+            KeystoreSigner::<PolkadotConfig>::from_uri(
+                CryptoScheme::Ed25519,
+                "//Alice/soft",
+                None
+            ),
+            Err(KeystoreError::InvalidSecretUri(_))
+        ));
+    }
+
+    #[test]
+    fn key_files_round_trip() {
+        let suri =
+            "bottom drive obey lake curtain smoke basket hold race lonely fit walk//Bob";
+        let key_file = KeyFile::encrypt_with_params(
+            CryptoScheme::Ed25519,
+            suri,
+            "hunter2",
+            TEST_SCRYPT,
+        )
+        .unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("subxt-key-{}.json", std::process::id()));
+        key_file.save(&path).unwrap();
+        let loaded = KeyFile::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, key_file);
+
+        let signer =
+            KeystoreSigner::<PolkadotConfig>::from_key_file(&loaded, "hunter2").unwrap();
+        let expected =
+            KeystoreSigner::<PolkadotConfig>::from_uri(CryptoScheme::Ed25519, suri, None)
+                .unwrap();
+        assert_eq!(signer.account_id(), expected.account_id());
+
+        assert!(matches!(
+            KeystoreSigner::<PolkadotConfig>::from_key_file(&loaded, "hunter3"),
+            Err(KeystoreError::DecryptionFailed)
+        ));
+    }
+}
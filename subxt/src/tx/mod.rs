@@ -17,14 +17,33 @@
 //! Polkadot and Substrate nodes. Only the shape of the tip payments differs, leading to
 //! [SubstrateExtrinsicParams] and [PolkadotExtrinsicParams] structs which pick an
 //! appropriate shape for Substrate/Polkadot chains respectively.
+//!
+//! Each of the "signed extra" and "additional" parameters belongs to some signed extension,
+//! and these are encoded in the order that the node's metadata declares. See
+//! [signed_extensions] for the signed extensions that are supported, and for how to
+//! put together your own set of them.
 
+mod decoded_extrinsic;
+#[cfg(feature = "ethereum")]
+mod ethereum_signer;
+#[cfg(feature = "keystore")]
+mod keystore;
+mod nonce_manager;
 mod params;
+pub mod signed_extensions;
 mod signer;
 mod tx_client;
 mod tx_payload;
 mod tx_progress;
+#[cfg(unix)]
+mod unix_socket_signer;
 
 pub use self::{
+    decoded_extrinsic::{
+        AddressAccountId,
+        DecodedExtrinsic,
+    },
+    nonce_manager::NonceManager,
     params::{
         AssetTip,
         BaseExtrinsicParams,
@@ -37,12 +56,30 @@ pub use self::{
         SubstrateExtrinsicParams,
         SubstrateExtrinsicParamsBuilder,
     },
+    signed_extensions::{
+        validate_extrinsic_params,
+        ChargeAssetTxPayment,
+        ChargeTransactionPayment,
+        CheckGenesis,
+        CheckMortality,
+        CheckMortalityParams,
+        CheckNonce,
+        CheckSpecVersion,
+        CheckTxVersion,
+        SignedExtension,
+        SignedExtensions,
+        Tip,
+    },
     signer::{
+        AsyncSigner,
         PairSigner,
         Signer,
+        SignerFuture,
     },
     tx_client::{
-        SignedSubmittableExtrinsic,
+        PartialExtrinsic,
+        PartialFee,
+        SubmittableExtrinsic,
         TxClient,
     },
     tx_payload::{
@@ -58,3 +95,21 @@ pub use self::{
         TxStatus,
     },
 };
+
+#[allow(deprecated)]
+pub use self::tx_client::SignedSubmittableExtrinsic;
+
+#[cfg(unix)]
+pub use self::unix_socket_signer::{
+    UnixSocketSigner,
+    UnixSocketSignerServer,
+};
+
+#[cfg(feature = "ethereum")]
+pub use self::ethereum_signer::EthereumSigner;
+#[cfg(feature = "keystore")]
+pub use self::keystore::{
+    CryptoScheme,
+    KeyFile,
+    KeystoreSigner,
+};
@@ -0,0 +1,270 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! A [`NonceManager`] hands out account nonces locally, so that many transactions can be
+//! signed and submitted concurrently from the same account.
+
+use crate::{
+    client::OnlineClientT,
+    error::Error,
+    tx::TxStatus,
+    Config,
+};
+use codec::Encode;
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// Hands out nonces for accounts without asking the node each time, so that concurrently
+/// submitted transactions from the same account don't end up with the same nonce.
+///
+/// The first nonce for an account is obtained from the node via `system_accountNextIndex`,
+/// and subsequent ones are handed out locally. If a nonce is handed out but the transaction
+/// using it never makes it into a block (see [`NonceManager::rollback()`] and
+/// [`NonceManager::observe_status()`]), the local state is either rolled back or, if later
+/// nonces have already been handed out, marked as stale, in which case the next nonce is
+/// obtained from the node again.
+///
+/// This is cheap to clone, and clones share the same state. It's expected to be used via
+/// methods like [`crate::tx::TxClient::sign_and_submit_then_watch_with_nonce_manager()`],
+/// which take care of rolling back nonces for transactions which fail.
+pub struct NonceManager<T: Config> {
+    accounts: Arc<Mutex<HashMap<Vec<u8>, AccountNonce<T::Index>>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AccountNonce<Index> {
+    // The next nonce to hand out.
+    next: Index,
+    // If true, the node must be asked for the next nonce before another is handed out.
+    stale: bool,
+}
+
+impl<T: Config> Clone for NonceManager<T> {
+    fn clone(&self) -> Self {
+        Self {
+            accounts: self.accounts.clone(),
+        }
+    }
+}
+
+impl<T: Config> Default for NonceManager<T> {
+    fn default() -> Self {
+        Self {
+            accounts: Default::default(),
+        }
+    }
+}
+
+impl<T: Config> std::fmt::Debug for NonceManager<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NonceManager")
+            .field("accounts", &self.accounts.lock().len())
+            .finish()
+    }
+}
+
+impl<T: Config> NonceManager<T> {
+    /// Create a new [`NonceManager`], which knows about no accounts yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hand out the next nonce to use for the given account. The node is asked for the
+    /// nonce if this is the first time we've seen this account, or if we've lost track
+    /// of it since.
+    pub async fn next_nonce<C: OnlineClientT<T>>(
+        &self,
+        client: &C,
+        account_id: &T::AccountId,
+    ) -> Result<T::Index, Error> {
+        let key = account_id.encode();
+        if let Some(nonce) = self.take_local(&key) {
+            return Ok(nonce)
+        }
+
+        // Don't hold the lock while we wait for the node.
+        let from_node = client.rpc().system_account_next_index(account_id).await?;
+
+        let mut accounts = self.accounts.lock();
+        let account = accounts.entry(key).or_insert(AccountNonce {
+            next: from_node,
+            stale: false,
+        });
+        // Another task may have re-synced this account while we were waiting, in
+        // which case we carry on from where it is.
+        if account.stale {
+            account.next = from_node;
+            account.stale = false;
+        }
+        let nonce = account.next;
+        account.next += 1u32.into();
+        Ok(nonce)
+    }
+
+    /// Hand back a nonce which was obtained from [`NonceManager::next_nonce()`], but which
+    /// will not end up being used, for instance because the transaction was never
+    /// submitted. If it's the most recent nonce handed out for the account, it will be
+    /// handed out again next. Otherwise, the next nonce will be obtained from the node.
+    pub fn rollback(&self, account_id: &T::AccountId, nonce: T::Index) {
+        self.rollback_key(&account_id.encode(), nonce)
+    }
+
+    /// Forget what we know about the given account's nonce, so that the next nonce is
+    /// obtained from the node. This is useful if transactions are also being submitted
+    /// from the account by some other means.
+    pub fn resync(&self, account_id: &T::AccountId) {
+        self.resync_key(&account_id.encode())
+    }
+
+    /// Update our view of an account's nonce given the status of a transaction which was
+    /// submitted using it. If the transaction was dropped, the nonce is rolled back (see
+    /// [`NonceManager::rollback()`]). If it was usurped by another transaction or is invalid
+    /// (which may be because its nonce was stale), the next nonce will be obtained from the
+    /// node. Other statuses are ignored.
+    pub fn observe_status<C>(
+        &self,
+        account_id: &T::AccountId,
+        nonce: T::Index,
+        status: &TxStatus<T, C>,
+    ) {
+        self.observe_status_key(&account_id.encode(), nonce, status)
+    }
+
+    pub(crate) fn observe_status_key<C>(
+        &self,
+        key: &[u8],
+        nonce: T::Index,
+        status: &TxStatus<T, C>,
+    ) {
+        match status {
+            TxStatus::Dropped => self.rollback_key(key, nonce),
+            // Handing the same nonce out again wouldn't help if it's the reason that
+            // the transaction is invalid.
+            TxStatus::Usurped(_) | TxStatus::Invalid => self.resync_key(key),
+            _ => {}
+        }
+    }
+
+    fn take_local(&self, key: &[u8]) -> Option<T::Index> {
+        let mut accounts = self.accounts.lock();
+        let account = accounts.get_mut(key).filter(|account| !account.stale)?;
+        let nonce = account.next;
+        account.next += 1u32.into();
+        Some(nonce)
+    }
+
+    fn rollback_key(&self, key: &[u8], nonce: T::Index) {
+        if let Some(account) = self.accounts.lock().get_mut(key) {
+            if !account.stale && account.next == nonce + 1u32.into() {
+                account.next = nonce;
+            } else {
+                account.stale = true;
+            }
+        }
+    }
+
+    fn resync_key(&self, key: &[u8]) {
+        if let Some(account) = self.accounts.lock().get_mut(key) {
+            account.stale = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        rpc::MockNode,
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use sp_core::{
+        crypto::AccountId32,
+        H256,
+    };
+
+    async fn setup() -> (
+        MockNode<PolkadotConfig>,
+        OnlineClient<PolkadotConfig>,
+        NonceManager<PolkadotConfig>,
+    ) {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+        (node, api, NonceManager::new())
+    }
+
+    #[tokio::test]
+    async fn hands_out_unique_nonces_concurrently() {
+        let (node, api, nonces) = setup().await;
+        let alice = AccountId32::new([1; 32]);
+        let bob = AccountId32::new([2; 32]);
+        node.set_account_nonce(&alice, 5);
+
+        let mut handed_out = futures::future::try_join_all(
+            (0..10).map(|_| nonces.next_nonce(&api, &alice)),
+        )
+        .await
+        .unwrap();
+        handed_out.sort();
+        assert_eq!(handed_out, (5..15).collect::<Vec<u32>>());
+
+        // Accounts are tracked separately.
+        assert_eq!(nonces.next_nonce(&api, &bob).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn rolls_back_and_resyncs() {
+        let (node, api, nonces) = setup().await;
+        let alice = AccountId32::new([1; 32]);
+
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 0);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 1);
+
+        // Rolling back the latest nonce means it's handed out again.
+        nonces.rollback(&alice, 1);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 1);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 2);
+
+        // Rolling back an earlier one leaves a gap, so we ask the node again.
+        node.set_account_nonce(&alice, 1);
+        nonces.rollback(&alice, 1);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 1);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 2);
+
+        // Usurped transactions lead to asking the node again, too.
+        node.set_account_nonce(&alice, 7);
+        let status = TxStatus::<PolkadotConfig, OnlineClient<PolkadotConfig>>::Usurped(
+            H256::zero(),
+        );
+        nonces.observe_status(&alice, 2, &status);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 7);
+
+        // Dropped transactions are rolled back.
+        let status = TxStatus::<PolkadotConfig, OnlineClient<PolkadotConfig>>::Dropped;
+        nonces.observe_status(&alice, 7, &status);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn invalid_transactions_lead_to_a_resync() {
+        let (node, api, nonces) = setup().await;
+        let alice = AccountId32::new([1; 32]);
+
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 0);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 1);
+
+        // Even though this was the latest nonce, it isn't handed out again.
+        node.set_account_nonce(&alice, 4);
+        let status = TxStatus::<PolkadotConfig, OnlineClient<PolkadotConfig>>::Invalid;
+        nonces.observe_status(&alice, 1, &status);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 4);
+        assert_eq!(nonces.next_nonce(&api, &alice).await.unwrap(), 5);
+    }
+}
@@ -2,14 +2,23 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
+use super::signed_extensions::{
+    CheckGenesis,
+    CheckMortality,
+    CheckMortalityParams,
+    CheckNonce,
+    CheckSpecVersion,
+    CheckTxVersion,
+    SignedExtension,
+    SignedExtensions,
+    Tip as TipPayment,
+};
 use crate::{
-    utils::Encoded,
+    error::ExtrinsicParamsError,
     Config,
+    Metadata,
 };
-use codec::{
-    Compact,
-    Encode,
-};
+use codec::Encode;
 use core::fmt::Debug;
 use derivative::Derivative;
 
@@ -20,20 +29,23 @@ pub use sp_runtime::generic::Era;
 /// "additional" parameters that are signed and used in transactions.
 /// see [`BaseExtrinsicParams`] for an implementation that is compatible with
 /// a Polkadot node.
-pub trait ExtrinsicParams<Index, Hash>: Debug + 'static {
+pub trait ExtrinsicParams<Index, Hash>: Debug + Sized + 'static {
     /// These parameters can be provided to the constructor along with
     /// some default parameters that `subxt` understands, in order to
     /// help construct your [`ExtrinsicParams`] object.
     type OtherParams;
 
-    /// Construct a new instance of our [`ExtrinsicParams`]
+    /// Construct a new instance of our [`ExtrinsicParams`]. The node's metadata is
+    /// provided so that the parameters can be shaped according to the signed extensions
+    /// that it declares; an error should be returned if this isn't possible.
     fn new(
         spec_version: u32,
         tx_version: u32,
         nonce: Index,
         genesis_hash: Hash,
+        metadata: &Metadata,
         other_params: Self::OtherParams,
-    ) -> Self;
+    ) -> Result<Self, ExtrinsicParamsError>;
 
     /// This is expected to SCALE encode the "signed extra" parameters
     /// to some buffer that has been provided. These are the parameters
@@ -46,6 +58,25 @@ pub trait ExtrinsicParams<Index, Hash>: Debug + 'static {
     /// sent along with the transaction, but are taken into account when
     /// signing it, meaning the client and node must agree on their values.
     fn encode_additional_to(&self, v: &mut Vec<u8>);
+
+    /// The identifiers of the signed extensions that this is able to encode, if known.
+    /// These are compared with the signed extensions in the node's metadata by
+    /// [`super::validate_extrinsic_params()`], in order to catch a mismatch before any
+    /// transactions are submitted. By default, this returns `None`, and so no such
+    /// checks are possible.
+    fn signed_extension_identifiers() -> Option<Vec<&'static str>> {
+        None
+    }
+
+    /// Given the identifier of a signed extension in the node's metadata, hand back the
+    /// identifier (as in [`ExtrinsicParams::signed_extension_identifiers()`]) of the signed
+    /// extension which this encodes in its place, if any. By default, this looks for the
+    /// same identifier.
+    fn match_signed_extension(identifier: &str) -> Option<&'static str> {
+        Self::signed_extension_identifiers()?
+            .into_iter()
+            .find(|id| *id == identifier)
+    }
 }
 
 /// A struct representing the signed extra and additional parameters required
@@ -64,26 +95,34 @@ pub type PolkadotExtrinsicParams<T> = BaseExtrinsicParams<T, PlainTip>;
 /// This is what you provide to methods like `sign_and_submit()`.
 pub type PolkadotExtrinsicParamsBuilder<T> = BaseExtrinsicParamsBuilder<T, PlainTip>;
 
+/// The signed extensions that [`BaseExtrinsicParams`] knows how to encode.
+type BaseSignedExtensions<T, Tip> = SignedExtensions<
+    T,
+    (
+        CheckSpecVersion,
+        CheckTxVersion,
+        CheckNonce,
+        CheckGenesis<<T as Config>::Hash>,
+        CheckMortality<<T as Config>::Hash>,
+        <Tip as TipPayment>::Extension,
+    ),
+>;
+
 /// An implementation of [`ExtrinsicParams`] that is suitable for constructing
 /// extrinsics that can be sent to a node with the same signed extra and additional
 /// parameters as a Polkadot/Substrate node. The way that tip payments are specified
 /// differs between Substrate and Polkadot nodes, and so we are generic over that in
 /// order to support both here with relative ease.
 ///
-/// If your node differs in the "signed extra" and "additional" parameters expected
-/// to be sent/signed with a transaction, then you can define your own type which
-/// implements the [`ExtrinsicParams`] trait.
+/// The signed extensions are encoded in the order that the node's metadata declares
+/// them (see [`SignedExtensions`]). If your node expects some "signed extra" or
+/// "additional" parameters that aren't supported here, then you can assemble your
+/// own [`SignedExtensions`] (see [`super::signed_extensions`]), or define your own type
+/// which implements the [`ExtrinsicParams`] trait.
 #[derive(Derivative)]
-#[derivative(Debug(bound = "Tip: Debug"))]
-pub struct BaseExtrinsicParams<T: Config, Tip: Debug> {
-    era: Era,
-    nonce: T::Index,
-    tip: Tip,
-    spec_version: u32,
-    transaction_version: u32,
-    genesis_hash: T::Hash,
-    mortality_checkpoint: T::Hash,
-    marker: std::marker::PhantomData<T>,
+#[derivative(Debug(bound = ""))]
+pub struct BaseExtrinsicParams<T: Config, Tip: TipPayment> {
+    inner: BaseSignedExtensions<T, Tip>,
 }
 
 /// This builder allows you to provide the parameters that can be configured in order to
@@ -140,8 +179,10 @@ impl<T: Config, Tip: Default> Default for BaseExtrinsicParamsBuilder<T, Tip> {
     }
 }
 
-impl<T: Config, Tip: Debug + Encode + 'static> ExtrinsicParams<T::Index, T::Hash>
+impl<T: Config, Tip: TipPayment> ExtrinsicParams<T::Index, T::Hash>
     for BaseExtrinsicParams<T, Tip>
+where
+    Tip::Extension: SignedExtension<T::Index, T::Hash, OtherParams = Tip>,
 {
     type OtherParams = BaseExtrinsicParamsBuilder<T, Tip>;
 
@@ -151,37 +192,39 @@ impl<T: Config, Tip: Debug + Encode + 'static> ExtrinsicParams<T::Index, T::Hash
         transaction_version: u32,
         nonce: T::Index,
         genesis_hash: T::Hash,
+        metadata: &Metadata,
         // Provided externally:
         other_params: Self::OtherParams,
-    ) -> Self {
-        BaseExtrinsicParams {
-            era: other_params.era,
-            mortality_checkpoint: other_params
-                .mortality_checkpoint
-                .unwrap_or(genesis_hash),
-            tip: other_params.tip,
-            nonce,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        let mortality = match other_params.mortality_checkpoint {
+            Some(checkpoint) => CheckMortalityParams::new(other_params.era, checkpoint),
+            None => CheckMortalityParams::default(),
+        };
+        let inner = BaseSignedExtensions::<T, Tip>::new(
             spec_version,
             transaction_version,
+            nonce,
             genesis_hash,
-            marker: std::marker::PhantomData,
-        }
+            metadata,
+            ((), (), (), (), mortality, other_params.tip),
+        )?;
+        Ok(BaseExtrinsicParams { inner })
     }
 
     fn encode_extra_to(&self, v: &mut Vec<u8>) {
-        let nonce: u64 = self.nonce.into();
-        let tip = Encoded(self.tip.encode());
-        (self.era, Compact(nonce), tip).encode_to(v);
+        self.inner.encode_extra_to(v);
     }
 
     fn encode_additional_to(&self, v: &mut Vec<u8>) {
-        (
-            self.spec_version,
-            self.transaction_version,
-            self.genesis_hash,
-            self.mortality_checkpoint,
-        )
-            .encode_to(v);
+        self.inner.encode_additional_to(v);
+    }
+
+    fn signed_extension_identifiers() -> Option<Vec<&'static str>> {
+        BaseSignedExtensions::<T, Tip>::signed_extension_identifiers()
+    }
+
+    fn match_signed_extension(identifier: &str) -> Option<&'static str> {
+        BaseSignedExtensions::<T, Tip>::match_signed_extension(identifier)
     }
 }
 
@@ -0,0 +1,714 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! Composable signed extensions. Each type here knows how to encode the "signed extra"
+//! and "additional" data for a single signed extension, and [`SignedExtensions`] puts a
+//! set of them together in whichever order the node's metadata declares.
+//!
+//! Custom signed extensions can be supported by implementing [`ExtrinsicParams`] and
+//! [`SignedExtension`] for some type, and including it in [`SignedExtensions`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use codec::Encode;
+//! use subxt::{
+//!     config::{ SubstrateConfig, WithExtrinsicParams },
+//!     error::ExtrinsicParamsError,
+//!     tx::{
+//!         ChargeTransactionPayment, CheckGenesis, CheckMortality, CheckNonce,
+//!         CheckSpecVersion, CheckTxVersion, ExtrinsicParams, SignedExtension,
+//!         SignedExtensions,
+//!     },
+//!     Metadata,
+//! };
+//!
+//! // A signed extension which asks the node to check a hash of its metadata.
+//! #[derive(Debug)]
+//! pub struct CheckMetadataHash(Option<[u8; 32]>);
+//!
+//! impl<Index, Hash> ExtrinsicParams<Index, Hash> for CheckMetadataHash {
+//!     type OtherParams = Option<[u8; 32]>;
+//!
+//!     fn new(
+//!         _spec_version: u32,
+//!         _tx_version: u32,
+//!         _nonce: Index,
+//!         _genesis_hash: Hash,
+//!         _metadata: &Metadata,
+//!         metadata_hash: Self::OtherParams,
+//!     ) -> Result<Self, ExtrinsicParamsError> {
+//!         Ok(CheckMetadataHash(metadata_hash))
+//!     }
+//!
+//!     fn encode_extra_to(&self, v: &mut Vec<u8>) {
+//!         // The "mode"; whether or not the check is enabled.
+//!         (self.0.is_some() as u8).encode_to(v);
+//!     }
+//!
+//!     fn encode_additional_to(&self, v: &mut Vec<u8>) {
+//!         self.0.encode_to(v);
+//!     }
+//! }
+//!
+//! impl<Index, Hash> SignedExtension<Index, Hash> for CheckMetadataHash {
+//!     const IDENTIFIER: &'static str = "CheckMetadataHash";
+//! }
+//!
+//! type MyExtrinsicParams<T> = SignedExtensions<
+//!     T,
+//!     (
+//!         CheckSpecVersion,
+//!         CheckTxVersion,
+//!         CheckNonce,
+//!         CheckGenesis<<T as subxt::Config>::Hash>,
+//!         CheckMortality<<T as subxt::Config>::Hash>,
+//!         ChargeTransactionPayment,
+//!         CheckMetadataHash,
+//!     ),
+//! >;
+//!
+//! type MyConfig = WithExtrinsicParams<SubstrateConfig, MyExtrinsicParams<SubstrateConfig>>;
+//! ```
+
+use super::{
+    AssetTip,
+    Era,
+    ExtrinsicParams,
+    PlainTip,
+};
+use crate::{
+    error::ExtrinsicParamsError,
+    Config,
+    Metadata,
+};
+use codec::{
+    Compact,
+    Encode,
+};
+use core::fmt::Debug;
+use derivative::Derivative;
+use frame_metadata::SignedExtensionMetadata;
+use scale_info::{
+    form::PortableForm,
+    PortableRegistry,
+    TypeDef,
+};
+
+/// A single signed extension, which knows how to construct and encode itself via
+/// [`ExtrinsicParams`], and which can be used as part of [`SignedExtensions`].
+pub trait SignedExtension<Index, Hash>: ExtrinsicParams<Index, Hash> {
+    /// The identifier of this signed extension, as given in the metadata.
+    const IDENTIFIER: &'static str;
+
+    /// Is this the signed extension with the given identifier in the metadata? By default,
+    /// this compares the identifier with [`SignedExtension::IDENTIFIER`], but signed
+    /// extensions which go by different names in different runtimes can accept each of them.
+    fn matches(identifier: &str) -> bool {
+        identifier == Self::IDENTIFIER
+    }
+}
+
+/// A set of [`SignedExtension`]s, given as a tuple. When constructed, each signed extension
+/// that the node's metadata asks for is encoded, in the order that the metadata declares them.
+///
+/// Signed extensions in the metadata which carry no data (for instance `CheckWeight`) are
+/// skipped if they aren't in the set. An [`ExtrinsicParamsError::UnknownSignedExtension`]
+/// error is returned if the node asks for a signed extension which carries some data, but
+/// isn't in the set.
+///
+/// The parameters handed to this are a tuple of the parameters for each signed extension.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""))]
+pub struct SignedExtensions<T: Config, Exts> {
+    extra: Vec<u8>,
+    additional: Vec<u8>,
+    #[derivative(Debug = "ignore")]
+    marker: std::marker::PhantomData<fn() -> (T, Exts)>,
+}
+
+macro_rules! impl_signed_extensions {
+    ($($ident:ident $index:tt),+) => {
+        impl<T: Config, $($ident),+> ExtrinsicParams<T::Index, T::Hash>
+            for SignedExtensions<T, ($($ident,)+)>
+        where
+            $($ident: SignedExtension<T::Index, T::Hash>,)+
+        {
+            type OtherParams =
+                ($(<$ident as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,)+);
+
+            fn new(
+                spec_version: u32,
+                tx_version: u32,
+                nonce: T::Index,
+                genesis_hash: T::Hash,
+                metadata: &Metadata,
+                other_params: Self::OtherParams,
+            ) -> Result<Self, ExtrinsicParamsError> {
+                let mut other_params = ($(Some(other_params.$index),)+);
+                let mut extra = Vec::new();
+                let mut additional = Vec::new();
+
+                for ext in &metadata.runtime_metadata().extrinsic.signed_extensions {
+                    $(
+                        if <$ident as SignedExtension<T::Index, T::Hash>>::matches(
+                            &ext.identifier,
+                        ) {
+                            let params = other_params.$index.take().ok_or_else(|| {
+                                ExtrinsicParamsError::Custom(format!(
+                                    "The '{}' signed extension appears more than once",
+                                    ext.identifier
+                                ))
+                            })?;
+                            let ext = <$ident as ExtrinsicParams<T::Index, T::Hash>>::new(
+                                spec_version,
+                                tx_version,
+                                nonce,
+                                genesis_hash,
+                                metadata,
+                                params,
+                            )?;
+                            ext.encode_extra_to(&mut extra);
+                            ext.encode_additional_to(&mut additional);
+                            continue
+                        }
+                    )+
+                    skip_unknown_extension(ext, metadata.types())?;
+                }
+
+                Ok(SignedExtensions {
+                    extra,
+                    additional,
+                    marker: std::marker::PhantomData,
+                })
+            }
+
+            fn encode_extra_to(&self, v: &mut Vec<u8>) {
+                v.extend_from_slice(&self.extra);
+            }
+
+            fn encode_additional_to(&self, v: &mut Vec<u8>) {
+                v.extend_from_slice(&self.additional);
+            }
+
+            fn signed_extension_identifiers() -> Option<Vec<&'static str>> {
+                Some(vec![
+                    $(<$ident as SignedExtension<T::Index, T::Hash>>::IDENTIFIER,)+
+                ])
+            }
+
+            fn match_signed_extension(identifier: &str) -> Option<&'static str> {
+                $(
+                    if <$ident as SignedExtension<T::Index, T::Hash>>::matches(identifier) {
+                        return Some(<$ident as SignedExtension<T::Index, T::Hash>>::IDENTIFIER)
+                    }
+                )+
+                None
+            }
+        }
+    };
+}
+
+impl_signed_extensions!(A 0);
+impl_signed_extensions!(A 0, B 1);
+impl_signed_extensions!(A 0, B 1, C 2);
+impl_signed_extensions!(A 0, B 1, C 2, D 3);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_signed_extensions!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// Check that the [`Config::ExtrinsicParams`] of the given [`Config`] are able to encode
+/// every signed extension that the node's metadata asks for (ignoring any which carry no
+/// data). An [`ExtrinsicParamsError::IncompatibleSignedExtensions`] error listing the
+/// mismatch is returned if not.
+///
+/// No checks are done if the [`ExtrinsicParams`] don't report which signed extensions
+/// they support (see [`ExtrinsicParams::signed_extension_identifiers()`]).
+pub fn validate_extrinsic_params<T: Config>(
+    metadata: &Metadata,
+) -> Result<(), ExtrinsicParamsError> {
+    let supported = match T::ExtrinsicParams::signed_extension_identifiers() {
+        Some(supported) => supported,
+        None => return Ok(()),
+    };
+    let required = &metadata.runtime_metadata().extrinsic.signed_extensions;
+
+    let mut missing = Vec::new();
+    let mut used = Vec::new();
+    for ext in required {
+        match T::ExtrinsicParams::match_signed_extension(&ext.identifier) {
+            Some(id) => used.push(id),
+            None => {
+                if skip_unknown_extension(ext, metadata.types()).is_err() {
+                    missing.push(ext.identifier.clone());
+                }
+            }
+        }
+    }
+    if missing.is_empty() {
+        return Ok(())
+    }
+
+    let unused = supported
+        .into_iter()
+        .filter(|id| !used.contains(id))
+        .map(|id| id.to_owned())
+        .collect();
+    Err(ExtrinsicParamsError::IncompatibleSignedExtensions { missing, unused })
+}
+
+// We can only leave out a signed extension that we don't know about if it
+// has nothing to encode.
+fn skip_unknown_extension(
+    ext: &SignedExtensionMetadata<PortableForm>,
+    types: &PortableRegistry,
+) -> Result<(), ExtrinsicParamsError> {
+    if is_type_empty(ext.ty.id(), types)?
+        && is_type_empty(ext.additional_signed.id(), types)?
+    {
+        Ok(())
+    } else {
+        Err(ExtrinsicParamsError::UnknownSignedExtension(
+            ext.identifier.clone(),
+        ))
+    }
+}
+
+// Does the given type always encode to zero bytes?
+pub(crate) fn is_type_empty(
+    type_id: u32,
+    types: &PortableRegistry,
+) -> Result<bool, ExtrinsicParamsError> {
+    let ty = types
+        .resolve(type_id)
+        .ok_or(ExtrinsicParamsError::TypeNotFound(type_id))?;
+    let field_ids: Vec<u32> = match ty.type_def() {
+        TypeDef::Composite(composite) => {
+            composite.fields().iter().map(|f| f.ty().id()).collect()
+        }
+        TypeDef::Tuple(tuple) => tuple.fields().iter().map(|f| f.id()).collect(),
+        TypeDef::Array(array) if array.len() == 0 => Vec::new(),
+        _ => return Ok(false),
+    };
+    for id in field_ids {
+        if !is_type_empty(id, types)? {
+            return Ok(false)
+        }
+    }
+    Ok(true)
+}
+
+/// The `CheckSpecVersion` signed extension, which signs the spec version of the runtime.
+#[derive(Clone, Debug)]
+pub struct CheckSpecVersion(u32);
+
+impl<Index, Hash> ExtrinsicParams<Index, Hash> for CheckSpecVersion {
+    type OtherParams = ();
+
+    fn new(
+        spec_version: u32,
+        _tx_version: u32,
+        _nonce: Index,
+        _genesis_hash: Hash,
+        _metadata: &Metadata,
+        _other_params: Self::OtherParams,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        Ok(CheckSpecVersion(spec_version))
+    }
+
+    fn encode_extra_to(&self, _v: &mut Vec<u8>) {}
+
+    fn encode_additional_to(&self, v: &mut Vec<u8>) {
+        self.0.encode_to(v);
+    }
+}
+
+impl<Index, Hash> SignedExtension<Index, Hash> for CheckSpecVersion {
+    const IDENTIFIER: &'static str = "CheckSpecVersion";
+}
+
+/// The `CheckTxVersion` signed extension, which signs the transaction version of the runtime.
+#[derive(Clone, Debug)]
+pub struct CheckTxVersion(u32);
+
+impl<Index, Hash> ExtrinsicParams<Index, Hash> for CheckTxVersion {
+    type OtherParams = ();
+
+    fn new(
+        _spec_version: u32,
+        tx_version: u32,
+        _nonce: Index,
+        _genesis_hash: Hash,
+        _metadata: &Metadata,
+        _other_params: Self::OtherParams,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        Ok(CheckTxVersion(tx_version))
+    }
+
+    fn encode_extra_to(&self, _v: &mut Vec<u8>) {}
+
+    fn encode_additional_to(&self, v: &mut Vec<u8>) {
+        self.0.encode_to(v);
+    }
+}
+
+impl<Index, Hash> SignedExtension<Index, Hash> for CheckTxVersion {
+    const IDENTIFIER: &'static str = "CheckTxVersion";
+}
+
+/// The `CheckGenesis` signed extension, which signs the genesis hash of the chain.
+#[derive(Clone, Debug)]
+pub struct CheckGenesis<Hash>(Hash);
+
+impl<Index, Hash: Encode + Debug + 'static> ExtrinsicParams<Index, Hash>
+    for CheckGenesis<Hash>
+{
+    type OtherParams = ();
+
+    fn new(
+        _spec_version: u32,
+        _tx_version: u32,
+        _nonce: Index,
+        genesis_hash: Hash,
+        _metadata: &Metadata,
+        _other_params: Self::OtherParams,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        Ok(CheckGenesis(genesis_hash))
+    }
+
+    fn encode_extra_to(&self, _v: &mut Vec<u8>) {}
+
+    fn encode_additional_to(&self, v: &mut Vec<u8>) {
+        self.0.encode_to(v);
+    }
+}
+
+impl<Index, Hash: Encode + Debug + 'static> SignedExtension<Index, Hash>
+    for CheckGenesis<Hash>
+{
+    const IDENTIFIER: &'static str = "CheckGenesis";
+}
+
+/// The `CheckNonce` signed extension, which sends the account nonce along with the
+/// transaction.
+#[derive(Clone, Debug)]
+pub struct CheckNonce(u64);
+
+impl<Index: Into<u64>, Hash> ExtrinsicParams<Index, Hash> for CheckNonce {
+    type OtherParams = ();
+
+    fn new(
+        _spec_version: u32,
+        _tx_version: u32,
+        nonce: Index,
+        _genesis_hash: Hash,
+        _metadata: &Metadata,
+        _other_params: Self::OtherParams,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        Ok(CheckNonce(nonce.into()))
+    }
+
+    fn encode_extra_to(&self, v: &mut Vec<u8>) {
+        Compact(self.0).encode_to(v);
+    }
+
+    fn encode_additional_to(&self, _v: &mut Vec<u8>) {}
+}
+
+impl<Index: Into<u64>, Hash> SignedExtension<Index, Hash> for CheckNonce {
+    const IDENTIFIER: &'static str = "CheckNonce";
+}
+
+/// The `CheckMortality` signed extension, which sends the [`Era`] that the transaction is
+/// valid for along with it, and signs the hash of the block that the era begins at.
+#[derive(Clone, Debug)]
+pub struct CheckMortality<Hash> {
+    era: Era,
+    checkpoint: Hash,
+}
+
+/// The parameters needed to construct [`CheckMortality`]. By default, transactions
+/// are immortal.
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "Hash: Debug"),
+    Clone(bound = "Hash: Clone"),
+    Copy(bound = "Hash: Copy"),
+    PartialEq(bound = "Hash: PartialEq"),
+    Default(bound = "")
+)]
+pub struct CheckMortalityParams<Hash> {
+    #[derivative(Default(value = "Era::Immortal"))]
+    era: Era,
+    checkpoint: Option<Hash>,
+}
+
+impl<Hash> CheckMortalityParams<Hash> {
+    /// Set the [`Era`] that the transaction is valid for, and the hash of the block that the
+    /// era begins at. See [`super::BaseExtrinsicParamsBuilder::era()`] for more details.
+    pub fn new(era: Era, checkpoint: Hash) -> Self {
+        CheckMortalityParams {
+            era,
+            checkpoint: Some(checkpoint),
+        }
+    }
+}
+
+impl<Index, Hash: Encode + Debug + 'static> ExtrinsicParams<Index, Hash>
+    for CheckMortality<Hash>
+{
+    type OtherParams = CheckMortalityParams<Hash>;
+
+    fn new(
+        _spec_version: u32,
+        _tx_version: u32,
+        _nonce: Index,
+        genesis_hash: Hash,
+        _metadata: &Metadata,
+        other_params: Self::OtherParams,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        Ok(CheckMortality {
+            era: other_params.era,
+            // Immortal transactions are checked against the genesis hash.
+            checkpoint: other_params.checkpoint.unwrap_or(genesis_hash),
+        })
+    }
+
+    fn encode_extra_to(&self, v: &mut Vec<u8>) {
+        self.era.encode_to(v);
+    }
+
+    fn encode_additional_to(&self, v: &mut Vec<u8>) {
+        self.checkpoint.encode_to(v);
+    }
+}
+
+impl<Index, Hash: Encode + Debug + 'static> SignedExtension<Index, Hash>
+    for CheckMortality<Hash>
+{
+    const IDENTIFIER: &'static str = "CheckMortality";
+
+    // Older runtimes call this `CheckEra`.
+    fn matches(identifier: &str) -> bool {
+        identifier == "CheckMortality" || identifier == "CheckEra"
+    }
+}
+
+/// The `ChargeTransactionPayment` signed extension, which sends a tip to the block
+/// author along with the transaction.
+#[derive(Clone, Debug)]
+pub struct ChargeTransactionPayment(PlainTip);
+
+impl<Index, Hash> ExtrinsicParams<Index, Hash> for ChargeTransactionPayment {
+    type OtherParams = PlainTip;
+
+    fn new(
+        _spec_version: u32,
+        _tx_version: u32,
+        _nonce: Index,
+        _genesis_hash: Hash,
+        _metadata: &Metadata,
+        tip: Self::OtherParams,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        Ok(ChargeTransactionPayment(tip))
+    }
+
+    fn encode_extra_to(&self, v: &mut Vec<u8>) {
+        self.0.encode_to(v);
+    }
+
+    fn encode_additional_to(&self, _v: &mut Vec<u8>) {}
+}
+
+impl<Index, Hash> SignedExtension<Index, Hash> for ChargeTransactionPayment {
+    const IDENTIFIER: &'static str = "ChargeTransactionPayment";
+}
+
+/// The `ChargeAssetTxPayment` signed extension, which sends a tip to the block author
+/// along with the transaction, optionally paid in some asset.
+#[derive(Clone, Debug)]
+pub struct ChargeAssetTxPayment(AssetTip);
+
+impl<Index, Hash> ExtrinsicParams<Index, Hash> for ChargeAssetTxPayment {
+    type OtherParams = AssetTip;
+
+    fn new(
+        _spec_version: u32,
+        _tx_version: u32,
+        _nonce: Index,
+        _genesis_hash: Hash,
+        _metadata: &Metadata,
+        tip: Self::OtherParams,
+    ) -> Result<Self, ExtrinsicParamsError> {
+        Ok(ChargeAssetTxPayment(tip))
+    }
+
+    fn encode_extra_to(&self, v: &mut Vec<u8>) {
+        self.0.encode_to(v);
+    }
+
+    fn encode_additional_to(&self, _v: &mut Vec<u8>) {}
+}
+
+impl<Index, Hash> SignedExtension<Index, Hash> for ChargeAssetTxPayment {
+    const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+}
+
+/// A type of tip, which is paid via some signed extension. This allows
+/// [`super::BaseExtrinsicParams`] to be generic over the shape of the tip.
+pub trait Tip: Debug + 'static {
+    /// The signed extension which pays this tip.
+    type Extension;
+}
+
+impl Tip for PlainTip {
+    type Extension = ChargeTransactionPayment;
+}
+
+impl Tip for AssetTip {
+    type Extension = ChargeAssetTxPayment;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        tx::PolkadotExtrinsicParamsBuilder,
+        PolkadotConfig,
+        SubstrateConfig,
+    };
+    use frame_metadata::{
+        RuntimeMetadata,
+        RuntimeMetadataPrefixed,
+    };
+    use sp_core::H256;
+
+    type Params = <PolkadotConfig as Config>::ExtrinsicParams;
+
+    // Load the polkadot metadata, adjusting the list of signed extensions as we go.
+    fn metadata(
+        f: impl FnOnce(&mut Vec<SignedExtensionMetadata<PortableForm>>),
+    ) -> Metadata {
+        let bytes = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let mut meta: RuntimeMetadataPrefixed =
+            codec::Decode::decode(&mut &bytes[..]).unwrap();
+        match &mut meta.1 {
+            RuntimeMetadata::V14(v14) => f(&mut v14.extrinsic.signed_extensions),
+            _ => panic!("expected V14 metadata"),
+        }
+        meta.try_into().unwrap()
+    }
+
+    // Copy an existing signed extension, giving it a new identifier.
+    fn renamed(
+        exts: &[SignedExtensionMetadata<PortableForm>],
+        from: &str,
+        to: &str,
+    ) -> SignedExtensionMetadata<PortableForm> {
+        let mut ext = exts
+            .iter()
+            .find(|ext| ext.identifier == from)
+            .unwrap()
+            .clone();
+        ext.identifier = to.to_owned();
+        ext
+    }
+
+    fn encode(metadata: &Metadata) -> Result<(Vec<u8>, Vec<u8>), ExtrinsicParamsError> {
+        let genesis = H256::repeat_byte(1);
+        let other_params = PolkadotExtrinsicParamsBuilder::new().tip(PlainTip::new(4));
+        let params = Params::new(1, 2, 3, genesis, metadata, other_params)?;
+        let mut extra = Vec::new();
+        let mut additional = Vec::new();
+        params.encode_extra_to(&mut extra);
+        params.encode_additional_to(&mut additional);
+        Ok((extra, additional))
+    }
+
+    #[test]
+    fn encodes_extensions_in_metadata_order() {
+        let genesis = H256::repeat_byte(1);
+
+        let (extra, additional) = encode(&metadata(|_| ())).unwrap();
+        assert_eq!(
+            extra,
+            (Era::Immortal, Compact(3u64), PlainTip::new(4)).encode()
+        );
+        assert_eq!(additional, (1u32, 2u32, genesis, genesis).encode());
+
+        let (extra, additional) = encode(&metadata(|exts| exts.reverse())).unwrap();
+        assert_eq!(
+            extra,
+            (PlainTip::new(4), Compact(3u64), Era::Immortal).encode()
+        );
+        assert_eq!(additional, (genesis, genesis, 2u32, 1u32).encode());
+    }
+
+    #[test]
+    fn unknown_extensions_must_be_empty() {
+        // `CheckWeight` has nothing to encode, so an unknown copy of it is fine.
+        let meta = metadata(|exts| {
+            let ext = renamed(exts, "CheckWeight", "CheckSomethingElse");
+            exts.push(ext);
+        });
+        assert!(encode(&meta).is_ok());
+
+        // An unknown extension with some data to encode is an error.
+        let meta = metadata(|exts| {
+            let ext = renamed(exts, "ChargeTransactionPayment", "CheckMetadataHash");
+            exts.push(ext);
+        });
+        assert_eq!(
+            encode(&meta).unwrap_err(),
+            ExtrinsicParamsError::UnknownSignedExtension("CheckMetadataHash".into())
+        );
+    }
+
+    #[test]
+    fn validates_extrinsic_params_against_metadata() {
+        let meta = metadata(|_| ());
+        assert_eq!(validate_extrinsic_params::<PolkadotConfig>(&meta), Ok(()));
+
+        // Substrate expects to pay tips in assets, which Polkadot doesn't support.
+        assert_eq!(
+            validate_extrinsic_params::<SubstrateConfig>(&meta),
+            Err(ExtrinsicParamsError::IncompatibleSignedExtensions {
+                missing: vec!["ChargeTransactionPayment".into()],
+                unused: vec!["ChargeAssetTxPayment".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_check_era_in_place_of_check_mortality() {
+        let meta = metadata(|exts| {
+            let ext = exts
+                .iter_mut()
+                .find(|ext| ext.identifier == "CheckMortality")
+                .unwrap();
+            ext.identifier = "CheckEra".to_owned();
+        });
+        assert_eq!(validate_extrinsic_params::<PolkadotConfig>(&meta), Ok(()));
+        assert_eq!(encode(&meta).unwrap(), encode(&metadata(|_| ())).unwrap());
+
+        // If the node doesn't ask for it, it's reported as unused by its usual name.
+        let meta = metadata(|exts| {
+            let ext = renamed(exts, "ChargeTransactionPayment", "CheckMetadataHash");
+            exts.push(ext);
+            exts.retain(|ext| ext.identifier != "CheckMortality");
+        });
+        assert_eq!(
+            validate_extrinsic_params::<PolkadotConfig>(&meta),
+            Err(ExtrinsicParamsError::IncompatibleSignedExtensions {
+                missing: vec!["CheckMetadataHash".into()],
+                unused: vec!["CheckMortality".into()],
+            })
+        );
+    }
+}
@@ -5,17 +5,27 @@
 //! A library to **sub**mit e**xt**rinsics to a
 //! [substrate](https://github.com/paritytech/substrate) node via RPC.
 
-use crate::Config;
+use crate::{
+    error::Error,
+    Config,
+};
 use sp_core::Pair;
 use sp_runtime::traits::{
     IdentifyAccount,
     Verify,
 };
+use std::{
+    future::Future,
+    pin::Pin,
+};
 
 /// Signing transactions requires a [`Signer`]. This is responsible for
 /// providing the "from" account that the transaction is being signed by,
 /// as well as actually signing a SCALE encoded payload. Optionally, a
 /// signer can also provide the nonce for the transaction to use.
+///
+/// Every [`Signer`] is also an [`AsyncSigner`], which is what most [`super::TxClient`] methods
+/// accept; [`super::TxClient::create_signed_with_nonce()`] signs with a [`Signer`] directly.
 pub trait Signer<T: Config> {
     /// Optionally returns a nonce.
     fn nonce(&self) -> Option<T::Index>;
@@ -33,6 +43,53 @@ pub trait Signer<T: Config> {
     fn sign(&self, signer_payload: &[u8]) -> T::Signature;
 }
 
+/// The future returned from [`AsyncSigner::sign()`].
+pub type SignerFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, Error>> + Send + 'a>>;
+
+/// Like [`Signer`], except that signing is asynchronous and may fail. This allows payloads
+/// to be signed somewhere else, for instance by a KMS, an HSM daemon or a separate signing
+/// process (see [`super::UnixSocketSigner`]).
+///
+/// This is what most [`super::TxClient`] methods accept when signing transactions. Every
+/// [`Signer`] implements this, signing payloads immediately.
+pub trait AsyncSigner<T: Config> {
+    /// Optionally returns a nonce.
+    fn nonce(&self) -> Option<T::Index>;
+
+    /// Return the "from" account ID.
+    fn account_id(&self) -> &T::AccountId;
+
+    /// Return the "from" address.
+    fn address(&self) -> T::Address;
+
+    /// Takes a signer payload for an extrinsic, and returns a signature based on it.
+    fn sign<'a>(&'a self, signer_payload: &'a [u8]) -> SignerFuture<'a, T::Signature>;
+}
+
+impl<T, S> AsyncSigner<T> for S
+where
+    T: Config,
+    S: Signer<T> + Sync,
+{
+    fn nonce(&self) -> Option<T::Index> {
+        Signer::nonce(self)
+    }
+
+    fn account_id(&self) -> &T::AccountId {
+        Signer::account_id(self)
+    }
+
+    fn address(&self) -> T::Address {
+        Signer::address(self)
+    }
+
+    fn sign<'a>(&'a self, signer_payload: &'a [u8]) -> SignerFuture<'a, T::Signature> {
+        let signature = Signer::sign(self, signer_payload);
+        Box::pin(async move { Ok(signature) })
+    }
+}
+
 /// A [`Signer`] implementation that can be constructed from an [`Pair`].
 #[derive(Clone, Debug)]
 pub struct PairSigner<T: Config, P: Pair> {
@@ -8,9 +8,31 @@ use crate::{
         OfflineClientT,
         OnlineClientT,
     },
-    error::Error,
+    error::{
+        BlockError,
+        Error,
+        ExtrinsicParamsError,
+        ExtrinsicVerificationError,
+    },
+    rpc::{
+        DispatchClass,
+        InclusionFee,
+        Weight,
+    },
     tx::{
+        signed_extensions::is_type_empty,
+        AddressAccountId,
+        AsyncSigner,
+        CheckGenesis,
+        CheckMortality,
+        CheckMortalityParams,
+        CheckSpecVersion,
+        CheckTxVersion,
+        DecodedExtrinsic,
+        Era,
         ExtrinsicParams,
+        NonceManager,
+        SignedExtension,
         Signer,
         TxProgress,
     },
@@ -22,11 +44,18 @@ use crate::{
 };
 use codec::{
     Compact,
+    Decode,
+    DecodeAll,
     Encode,
 };
 use derivative::Derivative;
 use sp_runtime::{
-    traits::Hash,
+    traits::{
+        Hash,
+        Header,
+        IdentifyAccount,
+        Verify,
+    },
     ApplyExtrinsicResult,
 };
 
@@ -79,14 +108,53 @@ impl<T: Config, C: OfflineClientT<T>> TxClient<T, C> {
         Ok(bytes)
     }
 
-    /// Creates a raw signed extrinsic, without submitting it.
-    pub async fn create_signed_with_nonce<Call>(
+    /// Creates an unsigned extrinsic, without submitting it. This is useful for calls
+    /// which the runtime validates without a signature (via `ValidateUnsigned`), such as
+    /// `im_online` heartbeats or claims.
+    pub fn create_unsigned<Call>(
+        &self,
+        call: &Call,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Call: TxPayload,
+    {
+        // 1. Validate this call against the current node metadata if the call comes
+        // with a hash allowing us to do so.
+        self.validate(call)?;
+
+        // 2. Encode extrinsic, which is compatible with the Encode impl for
+        //    UncheckedExtrinsic (protocol version 4) when no signature is present.
+        let extrinsic = {
+            let mut encoded_inner = Vec::new();
+            // transaction protocol version (4), without the "is signed" bit.
+            4u8.encode_to(&mut encoded_inner);
+            // and now, call data
+            call.encode_call_data(&self.client.metadata(), &mut encoded_inner)?;
+            prefix_with_len(encoded_inner)
+        };
+
+        Ok(SubmittableExtrinsic {
+            client: self.client.clone(),
+            encoded: Encoded(extrinsic),
+            marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Decode an extrinsic from its SCALE encoded bytes (for instance, those from
+    /// [`SubmittableExtrinsic::encoded()`] or from a block), using the current metadata.
+    pub fn decode_extrinsic(&self, bytes: &[u8]) -> Result<DecodedExtrinsic<T>, Error> {
+        DecodedExtrinsic::decode(bytes, &self.client.metadata())
+    }
+
+    /// Creates the parts of a signed extrinsic which don't depend on the signer. The
+    /// returned [`PartialExtrinsic`] hands back the payload which needs to be signed, and can
+    /// be turned into a [`SubmittableExtrinsic`] once a signature for it has been obtained.
+    pub fn create_partial_signed_with_nonce<Call>(
         &self,
         call: &Call,
-        signer: &(dyn Signer<T> + Send + Sync),
         account_nonce: T::Index,
         other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
-    ) -> Result<SignedSubmittableExtrinsic<T, C>, Error>
+    ) -> Result<PartialExtrinsic<T, C>, Error>
     where
         Call: TxPayload,
     {
@@ -95,7 +163,7 @@ impl<T: Config, C: OfflineClientT<T>> TxClient<T, C> {
         self.validate(call)?;
 
         // 2. SCALE encode call data to bytes (pallet u8, call u8, call params).
-        let call_data = Encoded(self.call_data(call)?);
+        let call_data = self.call_data(call)?;
 
         // 3. Construct our custom additional/extra params.
         let additional_and_extra_params = {
@@ -106,8 +174,9 @@ impl<T: Config, C: OfflineClientT<T>> TxClient<T, C> {
                 runtime.transaction_version,
                 account_nonce,
                 self.client.genesis_hash(),
+                &self.client.metadata(),
                 other_params,
-            )
+            )?
         };
 
         tracing::debug!(
@@ -115,67 +184,219 @@ impl<T: Config, C: OfflineClientT<T>> TxClient<T, C> {
             additional_and_extra_params
         );
 
-        // 4. Construct signature. This is compatible with the Encode impl
-        //    for SignedPayload (which is this payload of bytes that we'd like)
-        //    to sign. See:
-        //    https://github.com/paritytech/substrate/blob/9a6d706d8db00abb6ba183839ec98ecd9924b1f8/primitives/runtime/src/generic/unchecked_extrinsic.rs#L215)
-        let signature = {
-            let mut bytes = Vec::new();
-            call_data.encode_to(&mut bytes);
-            additional_and_extra_params.encode_extra_to(&mut bytes);
-            additional_and_extra_params.encode_additional_to(&mut bytes);
-            if bytes.len() > 256 {
-                signer.sign(&sp_core::blake2_256(&bytes))
-            } else {
-                signer.sign(&bytes)
-            }
-        };
-
-        tracing::debug!("tx signature: {}", hex::encode(signature.encode()));
-
-        // 5. Encode extrinsic, now that we have the parts we need. This is compatible
-        //    with the Encode impl for UncheckedExtrinsic (protocol version 4).
-        let extrinsic = {
-            let mut encoded_inner = Vec::new();
-            // "is signed" + transaction protocol version (4)
-            (0b10000000 + 4u8).encode_to(&mut encoded_inner);
-            // from address for signature
-            signer.address().encode_to(&mut encoded_inner);
-            // the signature bytes
-            signature.encode_to(&mut encoded_inner);
-            // attach custom extra params
-            additional_and_extra_params.encode_extra_to(&mut encoded_inner);
-            // and now, call data
-            call_data.encode_to(&mut encoded_inner);
-            // now, prefix byte length:
-            let len = Compact(
-                u32::try_from(encoded_inner.len())
-                    .expect("extrinsic size expected to be <4GB"),
-            );
-            let mut encoded = Vec::new();
-            len.encode_to(&mut encoded);
-            encoded.extend(encoded_inner);
-            encoded
-        };
+        let mut extra = Vec::new();
+        let mut additional = Vec::new();
+        additional_and_extra_params.encode_extra_to(&mut extra);
+        additional_and_extra_params.encode_additional_to(&mut additional);
 
-        // Wrap in Encoded to ensure that any more "encode" calls leave it in the right state.
-        // maybe we can just return the raw bytes..
-        Ok(SignedSubmittableExtrinsic {
+        Ok(PartialExtrinsic {
             client: self.client.clone(),
-            encoded: Encoded(extrinsic),
+            call_data,
+            extra,
+            additional,
             marker: std::marker::PhantomData,
         })
     }
+
+    /// Creates a raw signed extrinsic, without submitting it.
+    pub fn create_signed_with_nonce<Call>(
+        &self,
+        call: &Call,
+        signer: &(dyn Signer<T> + Send + Sync),
+        account_nonce: T::Index,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Call: TxPayload,
+    {
+        let partial =
+            self.create_partial_signed_with_nonce(call, account_nonce, other_params)?;
+        let signature = signer.sign(&partial.signer_payload());
+        Ok(partial.sign_with_address_and_signature(&signer.address(), &signature))
+    }
+
+    /// Creates a raw signed extrinsic, without submitting it, as with
+    /// [`TxClient::create_signed_with_nonce()`], but using an [`AsyncSigner`].
+    pub async fn create_signed_with_nonce_async<Call>(
+        &self,
+        call: &Call,
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
+        account_nonce: T::Index,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
+    where
+        Call: TxPayload,
+    {
+        self.create_partial_signed_with_nonce(call, account_nonce, other_params)?
+            .sign(signer)
+            .await
+    }
 }
 
 impl<T: Config, C: OnlineClientT<T>> TxClient<T, C> {
+    /// Reconstruct the SCALE encoded "additional" parameters that a signed extrinsic was
+    /// signed with. These aren't a part of the extrinsic, and are instead derived from
+    /// the current runtime version, the genesis hash and the values of the signed
+    /// extensions in the extrinsic, according to the signed extensions that the
+    /// metadata declares.
+    ///
+    /// For mortal extrinsics, the hash of the block that the mortality period began at is
+    /// needed. This is obtained from the node, assuming that the period began at or before
+    /// the block with the hash `at`, and was less than one period before it. If the
+    /// extrinsic was taken from some block, pass the hash of that block here. If `at` is
+    /// `None`, the current best block is used, which is only correct for extrinsics which
+    /// were created recently, such as those which are yet to be submitted.
+    pub async fn additional_params(
+        &self,
+        extrinsic: &DecodedExtrinsic<T>,
+        at: Option<T::Hash>,
+    ) -> Result<Vec<u8>, Error> {
+        let metadata = self.client.metadata();
+        let types = metadata.types();
+
+        let extra = &mut extrinsic
+            .signed_extensions_bytes()
+            .ok_or(ExtrinsicVerificationError::NotSigned)?;
+        let mut additional = Vec::new();
+        for ext in &metadata.runtime_metadata().extrinsic.signed_extensions {
+            let ext_start = *extra;
+            scale_decode::decode(
+                extra,
+                ext.ty.id(),
+                types,
+                scale_decode::visitor::IgnoreVisitor,
+            )?;
+            let ext_bytes = &ext_start[..ext_start.len() - extra.len()];
+
+            // Only these signed extensions have "additional" data to reconstruct.
+            let id = &ext.identifier;
+            if <CheckSpecVersion as SignedExtension<T::Index, T::Hash>>::matches(id) {
+                self.encode_additional::<CheckSpecVersion>((), &mut additional)?;
+            } else if <CheckTxVersion as SignedExtension<T::Index, T::Hash>>::matches(id) {
+                self.encode_additional::<CheckTxVersion>((), &mut additional)?;
+            } else if <CheckGenesis<T::Hash> as SignedExtension<T::Index, T::Hash>>::matches(
+                id,
+            ) {
+                self.encode_additional::<CheckGenesis<T::Hash>>((), &mut additional)?;
+            } else if <CheckMortality<T::Hash> as SignedExtension<T::Index, T::Hash>>::matches(
+                id,
+            ) {
+                let era = Era::decode(&mut &*ext_bytes)?;
+                let params = match era {
+                    Era::Immortal => CheckMortalityParams::default(),
+                    Era::Mortal(..) => {
+                        let checkpoint = self.mortality_checkpoint(era, at).await?;
+                        CheckMortalityParams::new(era, checkpoint)
+                    }
+                };
+                self.encode_additional::<CheckMortality<T::Hash>>(params, &mut additional)?;
+            } else if !is_type_empty(ext.additional_signed.id(), types)? {
+                return Err(ExtrinsicParamsError::UnknownSignedExtension(
+                    ext.identifier.clone(),
+                )
+                .into())
+            }
+        }
+        Ok(additional)
+    }
+
+    // Encode the "additional" data of some signed extension, as it would be
+    // encoded when signing an extrinsic with the given parameters now.
+    fn encode_additional<E: SignedExtension<T::Index, T::Hash>>(
+        &self,
+        other_params: E::OtherParams,
+        v: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        let runtime = self.client.runtime_version();
+        let ext = E::new(
+            runtime.spec_version,
+            runtime.transaction_version,
+            Default::default(),
+            self.client.genesis_hash(),
+            &self.client.metadata(),
+            other_params,
+        )?;
+        ext.encode_additional_to(v);
+        Ok(())
+    }
+
+    /// Decode an extrinsic from its SCALE encoded bytes (see [`TxClient::decode_extrinsic()`]),
+    /// and check that it's signed, and that its signature is valid (see
+    /// [`TxClient::additional_params()`] and [`DecodedExtrinsic::verify_signature()`]).
+    ///
+    /// If the extrinsic is mortal and was taken from some block, `at` should be the hash of
+    /// that block. If `at` is `None`, the extrinsic is assumed to be recent enough that its
+    /// mortality period began at or before the current best block.
+    pub async fn verify_extrinsic(
+        &self,
+        bytes: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<DecodedExtrinsic<T>, Error>
+    where
+        T::Signature: Decode,
+        T::Address: AddressAccountId<T::AccountId>,
+        <T::Signature as Verify>::Signer: IdentifyAccount<AccountId = T::AccountId>,
+    {
+        let extrinsic = self.decode_extrinsic(bytes)?;
+        if !extrinsic.is_signed() {
+            return Err(ExtrinsicVerificationError::NotSigned.into())
+        }
+        let additional = self.additional_params(&extrinsic, at).await?;
+        extrinsic.verify_signature(&additional)?;
+        Ok(extrinsic)
+    }
+
+    // Find the hash of the block that a mortal era began at, given some block at or
+    // after that point (or the current best block if none is given).
+    async fn mortality_checkpoint(
+        &self,
+        era: Era,
+        at: Option<T::Hash>,
+    ) -> Result<T::Hash, Error> {
+        let current = match (self.client.backend().header_at(at).await?, at) {
+            (Some(header), _) => (*header.number()).into(),
+            (None, Some(hash)) => {
+                return Err(BlockError::BlockHashNotFound(format!("{:?}", hash)).into())
+            }
+            (None, None) => 0,
+        };
+        let birth = era.birth(current);
+        self.client
+            .rpc()
+            .block_hash(Some(birth.into()))
+            .await?
+            .ok_or_else(|| {
+                ExtrinsicVerificationError::MortalityCheckpointNotFound(birth).into()
+            })
+    }
+
+    /// Creates the parts of a signed extrinsic which don't depend on the signer, obtaining
+    /// the nonce of the given account from the node. See
+    /// [`TxClient::create_partial_signed_with_nonce()`].
+    pub async fn create_partial_signed<Call>(
+        &self,
+        call: &Call,
+        account_id: &T::AccountId,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Result<PartialExtrinsic<T, C>, Error>
+    where
+        Call: TxPayload,
+    {
+        let account_nonce = self
+            .client
+            .rpc()
+            .system_account_next_index(account_id)
+            .await?;
+        self.create_partial_signed_with_nonce(call, account_nonce, other_params)
+    }
+
     /// Creates a raw signed extrinsic, without submitting it.
     pub async fn create_signed<Call>(
         &self,
         call: &Call,
-        signer: &(dyn Signer<T> + Send + Sync),
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
         other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
-    ) -> Result<SignedSubmittableExtrinsic<T, C>, Error>
+    ) -> Result<SubmittableExtrinsic<T, C>, Error>
     where
         Call: TxPayload,
     {
@@ -189,7 +410,7 @@ impl<T: Config, C: OnlineClientT<T>> TxClient<T, C> {
                 .await?
         };
 
-        self.create_signed_with_nonce(call, signer, account_nonce, other_params)
+        self.create_signed_with_nonce_async(call, signer, account_nonce, other_params)
             .await
     }
 
@@ -201,7 +422,7 @@ impl<T: Config, C: OnlineClientT<T>> TxClient<T, C> {
     pub async fn sign_and_submit_then_watch_default<Call>(
         &self,
         call: &Call,
-        signer: &(dyn Signer<T> + Send + Sync),
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
     ) -> Result<TxProgress<T, C>, Error>
     where
         Call: TxPayload,
@@ -218,7 +439,7 @@ impl<T: Config, C: OnlineClientT<T>> TxClient<T, C> {
     pub async fn sign_and_submit_then_watch<Call>(
         &self,
         call: &Call,
-        signer: &(dyn Signer<T> + Send + Sync),
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
         other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
     ) -> Result<TxProgress<T, C>, Error>
     where
@@ -243,7 +464,7 @@ impl<T: Config, C: OnlineClientT<T>> TxClient<T, C> {
     pub async fn sign_and_submit_default<Call>(
         &self,
         call: &Call,
-        signer: &(dyn Signer<T> + Send + Sync),
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
     ) -> Result<T::Hash, Error>
     where
         Call: TxPayload,
@@ -263,7 +484,7 @@ impl<T: Config, C: OnlineClientT<T>> TxClient<T, C> {
     pub async fn sign_and_submit<Call>(
         &self,
         call: &Call,
-        signer: &(dyn Signer<T> + Send + Sync),
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
         other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
     ) -> Result<T::Hash, Error>
     where
@@ -274,16 +495,244 @@ impl<T: Config, C: OnlineClientT<T>> TxClient<T, C> {
             .submit()
             .await
     }
+
+    /// Creates and signs an extrinsic using a nonce handed out by the given [`NonceManager`],
+    /// and submits it to the chain. Any nonce that the signer provides is ignored.
+    ///
+    /// If the extrinsic can't be created or submitted, the nonce is handed back to the
+    /// [`NonceManager`]. The returned [`TxProgress`] reports the statuses it sees to the
+    /// [`NonceManager`] too, so that nonces for transactions that are dropped are rolled
+    /// back, and nonces are obtained from the node again after transactions are invalid or
+    /// usurped (see [`NonceManager::observe_status()`]).
+    pub async fn sign_and_submit_then_watch_with_nonce_manager<Call>(
+        &self,
+        call: &Call,
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
+        nonces: &NonceManager<T>,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Result<TxProgress<T, C>, Error>
+    where
+        Call: TxPayload,
+    {
+        let account_id = signer.account_id();
+        let nonce = nonces.next_nonce(&self.client, account_id).await?;
+        let ext = self
+            .create_signed_with_nonce_async(call, signer, nonce, other_params)
+            .await
+            .inspect_err(|_| nonces.rollback(account_id, nonce))?;
+        let progress = ext.submit_and_watch().await.inspect_err(|_| {
+            // The node may have seen the extrinsic before the error, so we can't
+            // be sure that the nonce is unused.
+            nonces.resync(account_id);
+        })?;
+        Ok(progress.with_nonce_manager(nonces.clone(), account_id, nonce))
+    }
+
+    /// Creates and signs an extrinsic using a nonce handed out by the given [`NonceManager`],
+    /// and submits it to the chain for block inclusion. Any nonce that the signer provides
+    /// is ignored.
+    ///
+    /// If the extrinsic can't be created or submitted, the nonce is handed back to the
+    /// [`NonceManager`]. Use [`TxClient::sign_and_submit_then_watch_with_nonce_manager()`]
+    /// to also hand back the nonces of transactions which are later dropped.
+    ///
+    /// Returns `Ok` with the extrinsic hash if it is valid extrinsic.
+    pub async fn sign_and_submit_with_nonce_manager<Call>(
+        &self,
+        call: &Call,
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
+        nonces: &NonceManager<T>,
+        other_params: <T::ExtrinsicParams as ExtrinsicParams<T::Index, T::Hash>>::OtherParams,
+    ) -> Result<T::Hash, Error>
+    where
+        Call: TxPayload,
+    {
+        let account_id = signer.account_id();
+        let nonce = nonces.next_nonce(&self.client, account_id).await?;
+        let ext = self
+            .create_signed_with_nonce_async(call, signer, nonce, other_params)
+            .await
+            .inspect_err(|_| nonces.rollback(account_id, nonce))?;
+        ext.submit().await.inspect_err(|_| {
+            // The node may have seen the extrinsic before the error, so we can't
+            // be sure that the nonce is unused.
+            nonces.resync(account_id);
+        })
+    }
+}
+
+// The bytes which are signed: the call data, extra and additional params, or a hash
+// of these if they're longer than 256 bytes.
+pub(crate) fn signer_payload(
+    call_data: &[u8],
+    extra: &[u8],
+    additional: &[u8],
+) -> Vec<u8> {
+    let mut bytes = call_data.to_vec();
+    bytes.extend_from_slice(extra);
+    bytes.extend_from_slice(additional);
+    if bytes.len() > 256 {
+        sp_core::blake2_256(&bytes).to_vec()
+    } else {
+        bytes
+    }
 }
 
-/// This represents an extrinsic that has been signed and is ready to submit.
-pub struct SignedSubmittableExtrinsic<T, C> {
+// Prefix the encoded extrinsic with its compact encoded byte length.
+fn prefix_with_len(encoded_inner: Vec<u8>) -> Vec<u8> {
+    let len = Compact(
+        u32::try_from(encoded_inner.len()).expect("extrinsic size expected to be <4GB"),
+    );
+    let mut encoded = Vec::new();
+    len.encode_to(&mut encoded);
+    encoded.extend(encoded_inner);
+    encoded
+}
+
+/// This represents an extrinsic which is waiting to be signed. It's obtained via
+/// [`TxClient::create_partial_signed()`] or [`TxClient::create_partial_signed_with_nonce()`].
+///
+/// This allows signing to happen elsewhere, for instance on an offline device: hand the
+/// bytes from [`PartialExtrinsic::signer_payload()`] to whatever is doing the signing, and
+/// then hand the resulting signature to [`PartialExtrinsic::sign_with_address_and_signature()`]
+/// in order to obtain an extrinsic which can be submitted.
+///
+/// If signing happens in another process, [`PartialExtrinsic::encode()`] hands back the bytes
+/// needed to construct the same [`PartialExtrinsic`] there using [`PartialExtrinsic::decode()`].
+pub struct PartialExtrinsic<T, C> {
+    client: C,
+    call_data: Vec<u8>,
+    extra: Vec<u8>,
+    additional: Vec<u8>,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<T, C> PartialExtrinsic<T, C>
+where
+    T: Config,
+    C: OfflineClientT<T>,
+{
+    /// Construct a [`PartialExtrinsic`] from the SCALE encoded call data and the SCALE encoded
+    /// "signed extra" and "additional" parameters, as handed back from
+    /// [`PartialExtrinsic::call_data()`], [`PartialExtrinsic::extra()`] and
+    /// [`PartialExtrinsic::additional()`].
+    pub fn from_parts(
+        client: C,
+        call_data: Vec<u8>,
+        extra: Vec<u8>,
+        additional: Vec<u8>,
+    ) -> Self {
+        PartialExtrinsic {
+            client,
+            call_data,
+            extra,
+            additional,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Construct a [`PartialExtrinsic`] from the bytes handed back from
+    /// [`PartialExtrinsic::encode()`].
+    pub fn decode(client: C, bytes: &[u8]) -> Result<Self, Error> {
+        let (call_data, extra, additional) =
+            <(Vec<u8>, Vec<u8>, Vec<u8>)>::decode_all(&mut &*bytes)?;
+        Ok(PartialExtrinsic::from_parts(
+            client, call_data, extra, additional,
+        ))
+    }
+
+    /// SCALE encode the call data and the "signed extra" and "additional" parameters, so
+    /// that this can be sent elsewhere and reconstructed using [`PartialExtrinsic::decode()`].
+    pub fn encode(&self) -> Vec<u8> {
+        (&self.call_data, &self.extra, &self.additional).encode()
+    }
+
+    /// Returns the SCALE encoded call data.
+    pub fn call_data(&self) -> &[u8] {
+        &self.call_data
+    }
+
+    /// Returns the SCALE encoded "signed extra" parameters, which are a part of the
+    /// extrinsic once it's signed.
+    pub fn extra(&self) -> &[u8] {
+        &self.extra
+    }
+
+    /// Returns the SCALE encoded "additional" parameters, which are signed but are not
+    /// a part of the extrinsic.
+    pub fn additional(&self) -> &[u8] {
+        &self.additional
+    }
+
+    /// Returns the exact bytes which need to be signed. This is the call data followed by
+    /// the "signed extra" and "additional" parameters, or the blake2_256 hash of these if
+    /// they add up to more than 256 bytes. This is compatible with the Encode impl for
+    /// `SignedPayload`. See:
+    /// <https://github.com/paritytech/substrate/blob/9a6d706d8db00abb6ba183839ec98ecd9924b1f8/primitives/runtime/src/generic/unchecked_extrinsic.rs#L215>
+    pub fn signer_payload(&self) -> Vec<u8> {
+        signer_payload(&self.call_data, &self.extra, &self.additional)
+    }
+
+    /// Sign the [`PartialExtrinsic::signer_payload()`] with the given signer, returning an
+    /// extrinsic which is ready to submit.
+    pub async fn sign(
+        &self,
+        signer: &(dyn AsyncSigner<T> + Send + Sync),
+    ) -> Result<SubmittableExtrinsic<T, C>, Error> {
+        let signature = signer.sign(&self.signer_payload()).await?;
+        Ok(self.sign_with_address_and_signature(&signer.address(), &signature))
+    }
+
+    /// Assemble an extrinsic which is ready to submit, given the address of the account
+    /// which signed it and a signature of the [`PartialExtrinsic::signer_payload()`].
+    /// The signature is not checked, and so if it's not valid, the node will reject
+    /// the extrinsic.
+    pub fn sign_with_address_and_signature(
+        &self,
+        address: &T::Address,
+        signature: &T::Signature,
+    ) -> SubmittableExtrinsic<T, C> {
+        tracing::debug!("tx signature: {}", hex::encode(signature.encode()));
+
+        // Encode extrinsic, now that we have the parts we need. This is compatible
+        // with the Encode impl for UncheckedExtrinsic (protocol version 4).
+        let extrinsic = {
+            let mut encoded_inner = Vec::new();
+            // "is signed" + transaction protocol version (4)
+            (0b10000000 + 4u8).encode_to(&mut encoded_inner);
+            // from address for signature
+            address.encode_to(&mut encoded_inner);
+            // the signature bytes
+            signature.encode_to(&mut encoded_inner);
+            // attach custom extra params
+            encoded_inner.extend_from_slice(&self.extra);
+            // and now, call data
+            encoded_inner.extend_from_slice(&self.call_data);
+            prefix_with_len(encoded_inner)
+        };
+
+        SubmittableExtrinsic {
+            client: self.client.clone(),
+            encoded: Encoded(extrinsic),
+            marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// This represents an extrinsic that is ready to submit. It's obtained via either
+/// [`TxClient::create_signed()`] or [`TxClient::create_unsigned()`].
+pub struct SubmittableExtrinsic<T, C> {
     client: C,
     encoded: Encoded,
     marker: std::marker::PhantomData<T>,
 }
 
-impl<T, C> SignedSubmittableExtrinsic<T, C>
+/// The name that [`SubmittableExtrinsic`] used to go by, back when it could only be
+/// obtained by signing an extrinsic.
+#[deprecated(since = "0.23.0", note = "Use `SubmittableExtrinsic` instead")]
+pub type SignedSubmittableExtrinsic<T, C> = SubmittableExtrinsic<T, C>;
+
+impl<T, C> SubmittableExtrinsic<T, C>
 where
     T: Config,
     C: OnlineClientT<T>,
@@ -324,8 +773,376 @@ where
         self.client.rpc().dry_run(self.encoded(), at).await
     }
 
+    /// Ask the node what it would charge to include this extrinsic in a block, without
+    /// submitting it. Any tip is not included in the fee.
+    ///
+    /// Returns `Ok` with a [`PartialFee`], which contains the weight and class of the
+    /// extrinsic alongside the fee and its breakdown.
+    pub async fn partial_fee(&self, at: Option<T::Hash>) -> Result<PartialFee, Error> {
+        let rpc = self.client.rpc();
+        let (info, details) = futures::future::try_join(
+            rpc.payment_query_info(self.encoded(), at),
+            rpc.payment_query_fee_details(self.encoded(), at),
+        )
+        .await?;
+        Ok(PartialFee {
+            weight: info.weight,
+            class: info.class,
+            partial_fee: info.partial_fee,
+            inclusion_fee: details.inclusion_fee,
+        })
+    }
+
     /// Returns the SCALE encoded extrinsic bytes.
     pub fn encoded(&self) -> &[u8] {
         &self.encoded.0
     }
 }
+
+/// The fee that the node expects to charge for an extrinsic, as returned from
+/// [`SubmittableExtrinsic::partial_fee()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialFee {
+    /// The weight of the extrinsic.
+    pub weight: Weight,
+    /// The dispatch class of the extrinsic.
+    pub class: DispatchClass,
+    /// The fee, not including any tip.
+    pub partial_fee: u128,
+    /// A breakdown of the fee. This is `None` if the extrinsic pays no fee, for
+    /// instance because it's unsigned.
+    pub inclusion_fee: Option<InclusionFee>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        dynamic::Value,
+        rpc::MockNode,
+        tx::{
+            PairSigner,
+            PlainTip,
+            PolkadotExtrinsicParamsBuilder,
+        },
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use sp_core::{
+        ed25519,
+        sr25519,
+        Pair,
+    };
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn unsigned_extrinsics_can_be_submitted() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+        let ext = api.tx().create_unsigned(&tx).unwrap();
+
+        // A compact length prefix, then the version byte without the signed bit,
+        // and then the call data itself.
+        let call_data = api.tx().call_data(&tx).unwrap();
+        let mut expected = vec![(call_data.len() as u8 + 1) << 2, 4];
+        expected.extend(call_data);
+        assert_eq!(ext.encoded(), &expected[..]);
+
+        ext.submit().await.unwrap();
+        let block_hash = node.produce_block();
+
+        let block = api.blocks().at(Some(block_hash)).await.unwrap();
+        let extrinsics = block.extrinsics().unwrap();
+        let ext = extrinsics.iter().next().unwrap().unwrap();
+        assert!(!ext.is_signed());
+        assert_eq!(ext.pallet_name(), "System");
+        assert_eq!(ext.variant_name(), "remark");
+    }
+
+    #[tokio::test]
+    async fn partial_extrinsics_can_be_signed_elsewhere() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        // ed25519 signatures are deterministic, so we can compare the results.
+        let pair = ed25519::Pair::from_seed(&[1; 32]);
+        let signer = PairSigner::<PolkadotConfig, _>::new(pair.clone());
+
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+        let partial = api
+            .tx()
+            .create_partial_signed(&tx, signer.account_id(), Default::default())
+            .await
+            .unwrap();
+
+        // Short payloads are signed as they are.
+        let payload = partial.signer_payload();
+        assert!(payload.starts_with(partial.call_data()));
+        assert!(payload.len() <= 256);
+
+        let signature = pair.sign(&payload).into();
+        let address = signer.account_id().clone().into();
+        let ext = partial.sign_with_address_and_signature(&address, &signature);
+        let expected = api
+            .tx()
+            .create_signed_with_nonce(&tx, &signer, 0, Default::default())
+            .unwrap();
+        assert_eq!(ext.encoded(), expected.encoded());
+        let expected = api
+            .tx()
+            .create_signed_with_nonce_async(&tx, &signer, 0, Default::default())
+            .await
+            .unwrap();
+        assert_eq!(ext.encoded(), expected.encoded());
+
+        // Long payloads are hashed before they are signed.
+        let long_tx =
+            crate::dynamic::tx("System", "remark", vec![Value::from_bytes([0; 300])]);
+        let partial = api
+            .tx()
+            .create_partial_signed_with_nonce(&long_tx, 0, Default::default())
+            .unwrap();
+        let mut bytes = partial.call_data().to_vec();
+        (Era::Immortal, Compact(0u32), PlainTip::new(0)).encode_to(&mut bytes);
+        let genesis = api.genesis_hash();
+        let version = api.runtime_version();
+        let (spec, tx) = (version.spec_version, version.transaction_version);
+        (spec, tx, genesis, genesis).encode_to(&mut bytes);
+        assert_eq!(
+            partial.signer_payload(),
+            sp_core::blake2_256(&bytes).to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn partial_extrinsics_can_be_exported_and_imported() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+        let pair = ed25519::Pair::from_seed(&[1; 32]);
+        let signer = PairSigner::<PolkadotConfig, _>::new(pair.clone());
+
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+        let partial = api
+            .tx()
+            .create_partial_signed(&tx, signer.account_id(), Default::default())
+            .await
+            .unwrap();
+        let bytes = partial.encode();
+
+        // Sign the payload without access to the node..
+        let cold =
+            PartialExtrinsic::<PolkadotConfig, _>::decode(api.offline(), &bytes).unwrap();
+        assert_eq!(cold.call_data(), partial.call_data());
+        assert_eq!(cold.extra(), partial.extra());
+        assert_eq!(cold.additional(), partial.additional());
+        let signature = pair.sign(&cold.signer_payload()).into();
+
+        // ..and then assemble the extrinsic and submit it.
+        let address = signer.account_id().clone().into();
+        let ext = PartialExtrinsic::<PolkadotConfig, _>::decode(api.clone(), &bytes)
+            .unwrap()
+            .sign_with_address_and_signature(&address, &signature);
+        let expected = api
+            .tx()
+            .create_signed_with_nonce(&tx, &signer, 0, Default::default())
+            .unwrap();
+        assert_eq!(ext.encoded(), expected.encoded());
+        ext.submit().await.unwrap();
+        assert_eq!(node.pending_extrinsics(), vec![ext.encoded().to_vec()]);
+
+        // Trailing bytes aren't accepted.
+        let mut bytes = bytes;
+        bytes.push(0);
+        assert!(
+            PartialExtrinsic::<PolkadotConfig, _>::decode(api.offline(), &bytes).is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn extrinsics_can_be_decoded_and_verified() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        for _ in 0..5 {
+            node.produce_block();
+        }
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+        let signer =
+            PairSigner::<PolkadotConfig, _>::new(sr25519::Pair::from_seed(&[1; 32]));
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+
+        // An immortal extrinsic.
+        let ext = api
+            .tx()
+            .create_signed_with_nonce(&tx, &signer, 2, Default::default())
+            .unwrap();
+        let decoded = api
+            .tx()
+            .verify_extrinsic(ext.encoded(), None)
+            .await
+            .unwrap();
+        assert_eq!(decoded.version(), 4);
+        assert_eq!(decoded.pallet_name(), "System");
+        assert_eq!(decoded.variant_name(), "remark");
+        assert_eq!(decoded.call_bytes(), &api.tx().call_data(&tx).unwrap()[..]);
+        assert_eq!(
+            decoded.address().unwrap(),
+            Some(signer.account_id().clone().into())
+        );
+
+        // A mortal extrinsic, whose mortality period began at block 4.
+        let checkpoint = node.block_hash(4).unwrap();
+        let params =
+            PolkadotExtrinsicParamsBuilder::new().era(Era::mortal(8, 4), checkpoint);
+        let ext = api
+            .tx()
+            .create_signed_with_nonce(&tx, &signer, 2, params)
+            .unwrap();
+        api.tx()
+            .verify_extrinsic(ext.encoded(), None)
+            .await
+            .unwrap();
+
+        // Once the chain has moved on a period, the checkpoint has to be worked out
+        // from the block that the extrinsic was included in.
+        let included_in = node.produce_block();
+        for _ in 0..8 {
+            node.produce_block();
+        }
+        api.tx()
+            .verify_extrinsic(ext.encoded(), Some(included_in))
+            .await
+            .unwrap();
+        assert!(matches!(
+            api.tx().verify_extrinsic(ext.encoded(), None).await,
+            Err(Error::ExtrinsicVerification(
+                ExtrinsicVerificationError::InvalidSignature
+            ))
+        ));
+
+        // Changing the call invalidates the signature.
+        let mut tampered = ext.encoded().to_vec();
+        *tampered.last_mut().unwrap() = b'o';
+        assert!(matches!(
+            api.tx().verify_extrinsic(&tampered, None).await,
+            Err(Error::ExtrinsicVerification(
+                ExtrinsicVerificationError::InvalidSignature
+            ))
+        ));
+
+        // As does getting the length prefix wrong.
+        let mut input = ext.encoded();
+        let len = <Compact<u32>>::decode(&mut input).unwrap().0;
+        let wrong_length = [Compact(len - 1).encode(), input.to_vec()].concat();
+        assert!(matches!(
+            api.tx().verify_extrinsic(&wrong_length, None).await,
+            Err(Error::Block(BlockError::WrongLength { expected, actual, .. }))
+                if expected + 1 == actual
+        ));
+
+        // Unsigned extrinsics can be decoded, but not verified.
+        let ext = api.tx().create_unsigned(&tx).unwrap();
+        let decoded = api.tx().decode_extrinsic(ext.encoded()).unwrap();
+        assert!(!decoded.is_signed());
+        assert!(matches!(
+            api.tx().verify_extrinsic(ext.encoded(), None).await,
+            Err(Error::ExtrinsicVerification(
+                ExtrinsicVerificationError::NotSigned
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn partial_fees_can_be_queried() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let weight = Weight {
+            ref_time: 152_822_000,
+            proof_size: 3593,
+        };
+        let fee = InclusionFee {
+            base_fee: 1_000_000_000,
+            len_fee: 1_440_000,
+            adjusted_weight_fee: 152_822_000,
+        };
+        node.set_fees(weight, fee);
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node))
+            .await
+            .unwrap();
+
+        let signer =
+            PairSigner::<PolkadotConfig, _>::new(sr25519::Pair::from_seed(&[1; 32]));
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+        let ext = api
+            .tx()
+            .create_signed_with_nonce(&tx, &signer, 0, Default::default())
+            .unwrap();
+        assert_eq!(
+            ext.partial_fee(None).await.unwrap(),
+            PartialFee {
+                weight,
+                class: DispatchClass::Normal,
+                partial_fee: 1_154_262_000,
+                inclusion_fee: Some(fee),
+            }
+        );
+
+        // Unsigned extrinsics pay no fee.
+        let ext = api.tx().create_unsigned(&tx).unwrap();
+        let partial_fee = ext.partial_fee(None).await.unwrap();
+        assert_eq!(partial_fee.partial_fee, 0);
+        assert_eq!(partial_fee.inclusion_fee, None);
+    }
+
+    #[tokio::test]
+    async fn nonce_manager_nonces_are_used() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        let signer =
+            PairSigner::<PolkadotConfig, _>::new(sr25519::Pair::from_seed(&[1; 32]));
+        node.set_account_nonce(signer.account_id(), 3);
+        let nonces = NonceManager::new();
+
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes(b"hi")]);
+        let tx_client = api.tx();
+        futures::future::try_join_all((0..3).map(|_| {
+            tx_client.sign_and_submit_with_nonce_manager(
+                &tx,
+                &signer,
+                &nonces,
+                Default::default(),
+            )
+        }))
+        .await
+        .unwrap();
+        let block_hash = node.produce_block();
+
+        let block = api.blocks().at(Some(block_hash)).await.unwrap();
+        let extrinsics = block.extrinsics().unwrap();
+        let mut used = Vec::new();
+        for ext in extrinsics.iter() {
+            let ext = ext.unwrap();
+            let extra = ext.signed_extensions_bytes().unwrap();
+            // The extra params start with an immortal era (a single byte) and then the nonce.
+            used.push(<Compact<u32>>::decode(&mut &extra[1..]).unwrap().0);
+        }
+        used.sort();
+        assert_eq!(used, vec![3, 4, 5]);
+    }
+}
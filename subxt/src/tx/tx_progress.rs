@@ -7,43 +7,38 @@
 use std::task::Poll;
 
 use crate::{
+    blocks::ExtrinsicEvents,
     client::OnlineClientT,
     error::{
-        DispatchError,
         Error,
+        RpcError,
         TransactionError,
     },
-    events::{
-        self,
-        EventDetails,
-        Events,
-        EventsClient,
-        Phase,
-        StaticEvent,
+    events::EventsClient,
+    rpc::{
+        Subscription,
+        SubstrateTxStatus,
     },
-    rpc::SubstrateTxStatus,
+    tx::NonceManager,
     Config,
 };
+use codec::Encode;
 use derivative::Derivative;
 use futures::{
     Stream,
     StreamExt,
 };
-use jsonrpsee::core::{
-    client::Subscription as RpcSubscription,
-    Error as RpcError,
-};
 use sp_runtime::traits::Hash;
 
-pub use sp_runtime::traits::SignedExtension;
-
 /// This struct represents a subscription to the progress of some transaction.
 #[derive(Derivative)]
 #[derivative(Debug(bound = "C: std::fmt::Debug"))]
 pub struct TxProgress<T: Config, C> {
-    sub: Option<RpcSubscription<SubstrateTxStatus<T::Hash, T::Hash>>>,
+    sub: Option<Subscription<SubstrateTxStatus<T::Hash, T::Hash>>>,
     ext_hash: T::Hash,
     client: C,
+    // If the nonce came from a NonceManager, it's told about the statuses we see.
+    nonce: Option<(NonceManager<T>, Vec<u8>, T::Index)>,
 }
 
 // The above type is not `Unpin` by default unless the generic param `T` is,
@@ -54,7 +49,7 @@ impl<T: Config, C> Unpin for TxProgress<T, C> {}
 impl<T: Config, C> TxProgress<T, C> {
     /// Instantiate a new [`TxProgress`] from a custom subscription.
     pub fn new(
-        sub: RpcSubscription<SubstrateTxStatus<T::Hash, T::Hash>>,
+        sub: Subscription<SubstrateTxStatus<T::Hash, T::Hash>>,
         client: C,
         ext_hash: T::Hash,
     ) -> Self {
@@ -62,9 +57,22 @@ impl<T: Config, C> TxProgress<T, C> {
             sub: Some(sub),
             client,
             ext_hash,
+            nonce: None,
         }
     }
 
+    // Report the statuses of this transaction to the given NonceManager, which handed out
+    // the nonce it was signed with.
+    pub(crate) fn with_nonce_manager(
+        mut self,
+        nonces: NonceManager<T>,
+        account_id: &T::AccountId,
+        nonce: T::Index,
+    ) -> Self {
+        self.nonce = Some((nonces, account_id.encode(), nonce));
+        self
+    }
+
     /// Return the hash of the extrinsic.
     pub fn extrinsic_hash(&self) -> T::Hash {
         self.ext_hash
@@ -103,7 +111,7 @@ impl<T: Config, C: OnlineClientT<T>> TxProgress<T, C> {
                 _ => continue,
             }
         }
-        Err(RpcError::Custom("RPC subscription dropped".into()).into())
+        Err(RpcError::SubscriptionDropped.into())
     }
 
     /// Wait for the transaction to be finalized, and return a [`TxInBlock`]
@@ -129,7 +137,7 @@ impl<T: Config, C: OnlineClientT<T>> TxProgress<T, C> {
                 _ => continue,
             }
         }
-        Err(RpcError::Custom("RPC subscription dropped".into()).into())
+        Err(RpcError::SubscriptionDropped.into())
     }
 
     /// Wait for the transaction to be finalized, and for the transaction events to indicate
@@ -161,47 +169,52 @@ impl<T: Config, C: OnlineClientT<T>> Stream for TxProgress<T, C> {
             None => return Poll::Ready(None),
         };
 
-        sub.poll_next_unpin(cx)
-            .map_err(|e| e.into())
-            .map_ok(|status| {
-                match status {
-                    SubstrateTxStatus::Future => TxStatus::Future,
-                    SubstrateTxStatus::Ready => TxStatus::Ready,
-                    SubstrateTxStatus::Broadcast(peers) => TxStatus::Broadcast(peers),
-                    SubstrateTxStatus::InBlock(hash) => {
-                        TxStatus::InBlock(TxInBlock::new(
-                            hash,
-                            self.ext_hash,
-                            self.client.clone(),
-                        ))
-                    }
-                    SubstrateTxStatus::Retracted(hash) => TxStatus::Retracted(hash),
-                    SubstrateTxStatus::Usurped(hash) => TxStatus::Usurped(hash),
-                    SubstrateTxStatus::Dropped => TxStatus::Dropped,
-                    SubstrateTxStatus::Invalid => TxStatus::Invalid,
-                    // Only the following statuses are actually considered "final" (see the substrate
-                    // docs on `TxStatus`). Basically, either the transaction makes it into a
-                    // block, or we eventually give up on waiting for it to make it into a block.
-                    // Even `Dropped`/`Invalid`/`Usurped` transactions might make it into a block eventually.
-                    //
-                    // As an example, a transaction that is `Invalid` on one node due to having the wrong
-                    // nonce might still be valid on some fork on another node which ends up being finalized.
-                    // Equally, a transaction `Dropped` from one node may still be in the transaction pool,
-                    // and make it into a block, on another node. Likewise with `Usurped`.
-                    SubstrateTxStatus::FinalityTimeout(hash) => {
-                        self.sub = None;
-                        TxStatus::FinalityTimeout(hash)
-                    }
-                    SubstrateTxStatus::Finalized(hash) => {
-                        self.sub = None;
-                        TxStatus::Finalized(TxInBlock::new(
-                            hash,
-                            self.ext_hash,
-                            self.client.clone(),
-                        ))
-                    }
+        let status = sub.poll_next_unpin(cx).map_ok(|status| {
+            match status {
+                SubstrateTxStatus::Future => TxStatus::Future,
+                SubstrateTxStatus::Ready => TxStatus::Ready,
+                SubstrateTxStatus::Broadcast(peers) => TxStatus::Broadcast(peers),
+                SubstrateTxStatus::InBlock(hash) => {
+                    TxStatus::InBlock(TxInBlock::new(
+                        hash,
+                        self.ext_hash,
+                        self.client.clone(),
+                    ))
                 }
-            })
+                SubstrateTxStatus::Retracted(hash) => TxStatus::Retracted(hash),
+                SubstrateTxStatus::Usurped(hash) => TxStatus::Usurped(hash),
+                SubstrateTxStatus::Dropped => TxStatus::Dropped,
+                SubstrateTxStatus::Invalid => TxStatus::Invalid,
+                // Only the following statuses are actually considered "final" (see the substrate
+                // docs on `TxStatus`). Basically, either the transaction makes it into a
+                // block, or we eventually give up on waiting for it to make it into a block.
+                // Even `Dropped`/`Invalid`/`Usurped` transactions might make it into a block eventually.
+                //
+                // As an example, a transaction that is `Invalid` on one node due to having the wrong
+                // nonce might still be valid on some fork on another node which ends up being finalized.
+                // Equally, a transaction `Dropped` from one node may still be in the transaction pool,
+                // and make it into a block, on another node. Likewise with `Usurped`.
+                SubstrateTxStatus::FinalityTimeout(hash) => {
+                    self.sub = None;
+                    TxStatus::FinalityTimeout(hash)
+                }
+                SubstrateTxStatus::Finalized(hash) => {
+                    self.sub = None;
+                    TxStatus::Finalized(TxInBlock::new(
+                        hash,
+                        self.ext_hash,
+                        self.client.clone(),
+                    ))
+                }
+            }
+        });
+
+        if let (Poll::Ready(Some(Ok(status))), Some((nonces, key, nonce))) =
+            (&status, &self.nonce)
+        {
+            nonces.observe_status_key(key, *nonce, status);
+        }
+        status
     }
 }
 
@@ -345,13 +358,8 @@ impl<T: Config, C: OnlineClientT<T>> TxInBlock<T, C> {
         let events = self.fetch_events().await?;
 
         // Try to find any errors; return the first one we encounter.
-        for ev in events.iter() {
-            let ev = ev?;
-            if ev.pallet_name() == "System" && ev.variant_name() == "ExtrinsicFailed" {
-                let dispatch_error =
-                    DispatchError::decode_from(ev.field_bytes(), &self.client.metadata());
-                return Err(dispatch_error.into())
-            }
+        if let Some(dispatch_error) = events.dispatch_error()? {
+            return Err(dispatch_error.into())
         }
 
         Ok(events)
@@ -364,17 +372,17 @@ impl<T: Config, C: OnlineClientT<T>> TxInBlock<T, C> {
     /// **Note:** This has to download block details from the node and decode events
     /// from them.
     pub async fn fetch_events(&self) -> Result<TxEvents<T>, Error> {
-        let block = self
+        let body = self
             .client
-            .rpc()
-            .block(Some(self.block_hash))
+            .backend()
+            .block_body_at(self.block_hash)
             .await?
             .ok_or(Error::Transaction(TransactionError::BlockHashNotFound))?;
 
-        let extrinsic_idx = block.block.extrinsics
+        let extrinsic_idx = body
             .iter()
             .position(|ext| {
-                let hash = T::Hashing::hash_of(ext);
+                let hash = T::Hashing::hash(ext);
                 hash == self.ext_hash
             })
             // If we successfully obtain the block hash we think contains our
@@ -385,77 +393,10 @@ impl<T: Config, C: OnlineClientT<T>> TxInBlock<T, C> {
             .at(Some(self.block_hash))
             .await?;
 
-        Ok(TxEvents {
-            ext_hash: self.ext_hash,
-            ext_idx: extrinsic_idx as u32,
-            events,
-        })
+        Ok(TxEvents::new(self.ext_hash, extrinsic_idx as u32, events))
     }
 }
 
 /// This represents the events related to our transaction.
 /// We can iterate over the events, or look for a specific one.
-#[derive(Derivative)]
-#[derivative(Debug(bound = ""))]
-pub struct TxEvents<T: Config> {
-    ext_hash: T::Hash,
-    ext_idx: u32,
-    events: Events<T>,
-}
-
-impl<T: Config> TxEvents<T> {
-    /// Return the hash of the block that the transaction has made it into.
-    pub fn block_hash(&self) -> T::Hash {
-        self.events.block_hash()
-    }
-
-    /// Return the hash of the extrinsic.
-    pub fn extrinsic_hash(&self) -> T::Hash {
-        self.ext_hash
-    }
-
-    /// Return all of the events in the block that the transaction made it into.
-    pub fn all_events_in_block(&self) -> &events::Events<T> {
-        &self.events
-    }
-
-    /// Iterate over all of the raw events associated with this transaction.
-    ///
-    /// This works in the same way that [`events::Events::iter()`] does, with the
-    /// exception that it filters out events not related to the submitted extrinsic.
-    pub fn iter(&self) -> impl Iterator<Item = Result<EventDetails, Error>> + '_ {
-        self.events.iter().filter(|ev| {
-            ev.as_ref()
-                .map(|ev| ev.phase() == Phase::ApplyExtrinsic(self.ext_idx))
-                .unwrap_or(true) // Keep any errors.
-        })
-    }
-
-    /// Find all of the transaction events matching the event type provided as a generic parameter.
-    ///
-    /// This works in the same way that [`events::Events::find()`] does, with the
-    /// exception that it filters out events not related to the submitted extrinsic.
-    pub fn find<Ev: StaticEvent>(&self) -> impl Iterator<Item = Result<Ev, Error>> + '_ {
-        self.iter().filter_map(|ev| {
-            ev.and_then(|ev| ev.as_event::<Ev>().map_err(Into::into))
-                .transpose()
-        })
-    }
-
-    /// Iterate through the transaction events using metadata to dynamically decode and skip
-    /// them, and return the first event found which decodes to the provided `Ev` type.
-    ///
-    /// This works in the same way that [`events::Events::find_first()`] does, with the
-    /// exception that it ignores events not related to the submitted extrinsic.
-    pub fn find_first<Ev: StaticEvent>(&self) -> Result<Option<Ev>, Error> {
-        self.find::<Ev>().next().transpose()
-    }
-
-    /// Find an event in those associated with this transaction. Returns true if it was found.
-    ///
-    /// This works in the same way that [`events::Events::has()`] does, with the
-    /// exception that it ignores events not related to the submitted extrinsic.
-    pub fn has<Ev: StaticEvent>(&self) -> Result<bool, Error> {
-        Ok(self.find::<Ev>().next().transpose()?.is_some())
-    }
-}
+pub type TxEvents<T> = ExtrinsicEvents<T>;
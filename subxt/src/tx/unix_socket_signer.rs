@@ -0,0 +1,336 @@
+// Copyright 2019-2022 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+//! A simple [`AsyncSigner`] which asks a separate signing process to sign payloads over a
+//! Unix socket, alongside a [`UnixSocketSignerServer`] which can be used to sign them.
+//!
+//! The protocol is deliberately minimal: for each payload to sign, the client connects to
+//! the socket, and writes the length of the payload as a little endian `u32` followed by the
+//! payload itself. The server replies with the length of the SCALE encoded signature as a
+//! little endian `u32`, followed by the encoded signature, and then closes the connection.
+//! Messages longer than 256 bytes are rejected.
+
+use crate::{
+    config::{
+        SubstrateConfig,
+        WithExtrinsicParams,
+    },
+    error::Error,
+    tx::{
+        AsyncSigner,
+        ExtrinsicParams,
+        Signer,
+        SignerFuture,
+    },
+    Config,
+};
+use codec::{
+    Decode,
+    Encode,
+};
+use std::{
+    io::{
+        Read,
+        Write,
+    },
+    os::unix::net::{
+        UnixListener,
+        UnixStream,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+/// An [`AsyncSigner`] which hands each payload to a signing process listening on a
+/// Unix socket, for instance a [`UnixSocketSignerServer`]. The private keys never need
+/// to be held by this process.
+///
+/// This implements [`AsyncSigner`] for the configs provided by this crate, and for any
+/// [`WithExtrinsicParams`]. To use it with some other config, wrap that config in
+/// [`WithExtrinsicParams`] along with its existing `ExtrinsicParams`.
+#[derive(Clone, Debug)]
+pub struct UnixSocketSigner<T: Config> {
+    path: PathBuf,
+    account_id: T::AccountId,
+    nonce: Option<T::Index>,
+}
+
+impl<T: Config> UnixSocketSigner<T> {
+    /// Create a new [`UnixSocketSigner`] which signs on behalf of the given account,
+    /// using the signing process listening on the socket at `path`.
+    pub fn new(path: impl Into<PathBuf>, account_id: T::AccountId) -> Self {
+        Self {
+            path: path.into(),
+            account_id,
+            nonce: None,
+        }
+    }
+
+    /// Sets the nonce to a new value. By default, the nonce will
+    /// be retrieved from the node. Setting one here will override that.
+    pub fn set_nonce(&mut self, nonce: T::Index) {
+        self.nonce = Some(nonce);
+    }
+
+    /// Increment the nonce.
+    pub fn increment_nonce(&mut self) {
+        self.nonce = self.nonce.map(|nonce| nonce + 1u32.into());
+    }
+}
+
+impl<T> UnixSocketSigner<T>
+where
+    T: Config,
+    T::Signature: Decode,
+{
+    fn sign_remotely<'a>(
+        &'a self,
+        signer_payload: &'a [u8],
+    ) -> SignerFuture<'a, T::Signature> {
+        let path = self.path.clone();
+        let payload = signer_payload.to_vec();
+        Box::pin(async move {
+            // The socket is blocking, so talk to it from another thread to avoid
+            // blocking whichever executor we're running on.
+            let (tx, rx) = futures::channel::oneshot::channel();
+            std::thread::spawn(move || {
+                let _ = tx.send(request_signature(&path, &payload));
+            });
+            let signature = rx.await.map_err(|_| {
+                Error::Signer("the signing thread stopped unexpectedly".into())
+            })??;
+            Ok(T::Signature::decode(&mut &*signature)?)
+        })
+    }
+}
+
+// Every `Signer` is an `AsyncSigner`, and other crates are free to implement `Signer`
+// for a `UnixSocketSigner<TheirConfig>`, so we can't implement `AsyncSigner` for every
+// `UnixSocketSigner<T>`. Instead, we implement it for the configs defined in this crate.
+// Any other config can be used by wrapping it in `WithExtrinsicParams`.
+macro_rules! impl_async_signer {
+    ({ $($generics:tt)* } $config:ty $(where $($bounds:tt)*)?) => {
+        impl<$($generics)*> AsyncSigner<$config> for UnixSocketSigner<$config>
+        $(where $($bounds)*)?
+        {
+            fn nonce(&self) -> Option<<$config as Config>::Index> {
+                self.nonce
+            }
+
+            fn account_id(&self) -> &<$config as Config>::AccountId {
+                &self.account_id
+            }
+
+            fn address(&self) -> <$config as Config>::Address {
+                self.account_id.clone().into()
+            }
+
+            fn sign<'a>(
+                &'a self,
+                signer_payload: &'a [u8],
+            ) -> SignerFuture<'a, <$config as Config>::Signature> {
+                self.sign_remotely(signer_payload)
+            }
+        }
+    };
+    ($config:ty) => {
+        impl_async_signer!({} $config);
+    };
+}
+
+impl_async_signer!(SubstrateConfig);
+#[cfg(feature = "ethereum")]
+impl_async_signer!(crate::config::EthereumConfig);
+impl_async_signer!(
+    { T: Config, E: ExtrinsicParams<T::Index, T::Hash> } WithExtrinsicParams<T, E>
+    where
+        T::AccountId: Into<T::Address> + Clone,
+        T::Signature: Decode,
+);
+
+/// Signs payloads handed to it over a Unix socket by a [`UnixSocketSigner`], using the
+/// [`Signer`] that it was given. Requests are handled one at a time on a background
+/// thread, which stops when this is dropped.
+pub struct UnixSocketSignerServer {
+    path: PathBuf,
+    stopped: Arc<AtomicBool>,
+}
+
+impl UnixSocketSignerServer {
+    /// Listen for signing requests on a new socket at `path`, and sign them with the
+    /// given [`Signer`].
+    pub fn new<T, S>(path: impl Into<PathBuf>, signer: S) -> Result<Self, Error>
+    where
+        T: Config,
+        S: Signer<T> + Send + 'static,
+    {
+        let path = path.into();
+        let listener = UnixListener::bind(&path)?;
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let stopped2 = stopped.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped2.load(Ordering::SeqCst) {
+                    break
+                }
+                if let Ok(stream) = stream {
+                    if let Err(e) = serve_request(stream, &signer) {
+                        tracing::warn!("failed to handle signing request: {}", e);
+                    }
+                }
+            }
+        });
+
+        Ok(Self { path, stopped })
+    }
+
+    /// The path of the socket that requests are listened for on.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for UnixSocketSignerServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wake the background thread up so that it notices that it should stop.
+        let _ = UnixStream::connect(&self.path);
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl std::fmt::Debug for UnixSocketSignerServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnixSocketSignerServer")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+fn request_signature(path: &Path, payload: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut stream = UnixStream::connect(path)?;
+    write_message(&mut stream, payload)?;
+    Ok(read_message(&mut stream)?)
+}
+
+fn serve_request<T: Config>(
+    mut stream: UnixStream,
+    signer: &impl Signer<T>,
+) -> std::io::Result<()> {
+    let payload = read_message(&mut stream)?;
+    let signature = signer.sign(&payload).encode();
+    write_message(&mut stream, &signature)
+}
+
+fn write_message(stream: &mut UnixStream, bytes: &[u8]) -> std::io::Result<()> {
+    let len = u32::try_from(bytes.len())
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, "too long"))?;
+    stream.write_all(&len.to_le_bytes())?;
+    stream.write_all(bytes)
+}
+
+// Payloads longer than 256 bytes are hashed before being signed, and encoded signatures
+// are far smaller than that, so no valid message will be any longer than this. Checking
+// this stops a misbehaving peer from making us allocate up to 4GiB.
+const MAX_MESSAGE_LEN: usize = 256;
+
+fn read_message(stream: &mut UnixStream) -> std::io::Result<Vec<u8>> {
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    if len > MAX_MESSAGE_LEN {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "message of {len} bytes is longer than the maximum of {MAX_MESSAGE_LEN}"
+            ),
+        ))
+    }
+    let mut bytes = vec![0u8; len];
+    stream.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        rpc::MockNode,
+        tx::PairSigner,
+        OnlineClient,
+        PolkadotConfig,
+    };
+    use sp_core::{
+        sr25519,
+        Pair,
+    };
+    use sp_runtime::traits::Verify;
+
+    fn socket_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("subxt-{}-{}.sock", name, std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn signs_over_a_unix_socket() {
+        let pair = sr25519::Pair::from_seed(&[1; 32]);
+        let pair_signer = PairSigner::<PolkadotConfig, _>::new(pair);
+        let account_id = pair_signer.account_id().clone();
+
+        let server =
+            UnixSocketSignerServer::new(socket_path("sign"), pair_signer).unwrap();
+        let signer = UnixSocketSigner::<PolkadotConfig>::new(server.path(), account_id);
+
+        let signature = signer.sign(b"hello").await.unwrap();
+        assert!(signature.verify(&b"hello"[..], signer.account_id()));
+    }
+
+    #[tokio::test]
+    async fn extrinsics_can_be_signed_over_a_unix_socket() {
+        let metadata = include_bytes!("../../../artifacts/polkadot_metadata.scale");
+        let node = MockNode::<PolkadotConfig>::new(metadata.to_vec());
+        let api = OnlineClient::<PolkadotConfig>::from_rpc_client(Arc::new(node.clone()))
+            .await
+            .unwrap();
+
+        let pair_signer =
+            PairSigner::<PolkadotConfig, _>::new(sr25519::Pair::from_seed(&[2; 32]));
+        let account_id = pair_signer.account_id().clone();
+        let server = UnixSocketSignerServer::new(socket_path("tx"), pair_signer).unwrap();
+        let signer = UnixSocketSigner::<PolkadotConfig>::new(server.path(), account_id);
+
+        let tx = crate::dynamic::tx(
+            "System",
+            "remark",
+            vec![crate::dynamic::Value::from_bytes(b"hi")],
+        );
+        api.tx()
+            .sign_and_submit_default(&tx, &signer)
+            .await
+            .unwrap();
+
+        let pending = node.pending_extrinsics();
+        assert_eq!(pending.len(), 1);
+    }
+
+    #[test]
+    fn rejects_overly_long_messages() {
+        let (mut a, mut b) = UnixStream::pair().unwrap();
+        a.write_all(&u32::MAX.to_le_bytes()).unwrap();
+        let err = read_message(&mut b).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        write_message(&mut a, &[1; MAX_MESSAGE_LEN]).unwrap();
+        assert_eq!(read_message(&mut b).unwrap(), vec![1; MAX_MESSAGE_LEN]);
+    }
+}
@@ -96,3 +96,160 @@ unsafe impl<T> Sync for PhantomDataSendSync<T> {}
 /// with collections like BTreeMap. This has the same type params
 /// as `BTreeMap` which allows us to easily swap the two during codegen.
 pub type KeyedVec<K, V> = Vec<(K, V)>;
+
+/// A 20 byte account ID, as used by Ethereum-compatible chains such as those built
+/// using Frontier (for instance Moonbeam). These addresses are the last 20 bytes of
+/// the keccak-256 hash of the account's uncompressed secp256k1 public key.
+///
+/// This is [`std::fmt::Display`]ed and parsed as a `0x` prefixed hex string, using the
+/// mixed-case checksum from EIP-55 when displayed.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Encode,
+    Decode,
+    scale_info::TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct AccountId20(pub [u8; 20]);
+
+impl AccountId20 {
+    /// The address of the account which the given ECDSA public key belongs to.
+    #[cfg(feature = "ethereum")]
+    pub fn from_public(public: &sp_core::ecdsa::Public) -> Self {
+        let uncompressed = secp256k1::PublicKey::from_slice(public.as_ref())
+            .expect("an ecdsa::Public is always a valid compressed public key; qed")
+            .serialize_uncompressed();
+        // The first byte just tags the key as being uncompressed.
+        let hash = sp_core::keccak_256(&uncompressed[1..]);
+        let mut account_id = [0u8; 20];
+        account_id.copy_from_slice(&hash[12..]);
+        AccountId20(account_id)
+    }
+
+    /// The address as a `0x` prefixed hex string, using the mixed-case checksum
+    /// described in EIP-55.
+    pub fn checksum(&self) -> String {
+        let hex_address = hex::encode(self.0);
+        let hash = sp_core::keccak_256(hex_address.as_bytes());
+
+        let mut checksum = String::with_capacity(42);
+        checksum.push_str("0x");
+        for (idx, c) in hex_address.chars().enumerate() {
+            // Uppercase letters whose corresponding nibble of the hash is 8 or more.
+            let nibble = if idx % 2 == 0 {
+                hash[idx / 2] >> 4
+            } else {
+                hash[idx / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                checksum.push(c.to_ascii_uppercase());
+            } else {
+                checksum.push(c);
+            }
+        }
+        checksum
+    }
+}
+
+impl AsRef<[u8]> for AccountId20 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 20]> for AccountId20 {
+    fn from(bytes: [u8; 20]) -> Self {
+        AccountId20(bytes)
+    }
+}
+
+impl From<sp_core::H160> for AccountId20 {
+    fn from(h160: sp_core::H160) -> Self {
+        AccountId20(h160.0)
+    }
+}
+
+impl From<AccountId20> for sp_core::H160 {
+    fn from(account_id: AccountId20) -> Self {
+        sp_core::H160(account_id.0)
+    }
+}
+
+impl std::fmt::Display for AccountId20 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.checksum())
+    }
+}
+
+impl std::str::FromStr for AccountId20 {
+    type Err = hex::FromHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut account_id = [0u8; 20];
+        hex::decode_to_slice(s.trim_start_matches("0x"), &mut account_id)?;
+        Ok(AccountId20(account_id))
+    }
+}
+
+/// A secp256k1 signature of the keccak-256 hash of a payload, as used by
+/// Ethereum-compatible chains alongside [`AccountId20`]. Verifying it recovers the
+/// public key of the signer, and compares the address derived from that with the
+/// expected [`AccountId20`].
+#[cfg(feature = "ethereum")]
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
+pub struct EthereumSignature(pub sp_core::ecdsa::Signature);
+
+#[cfg(feature = "ethereum")]
+impl From<sp_core::ecdsa::Signature> for EthereumSignature {
+    fn from(signature: sp_core::ecdsa::Signature) -> Self {
+        EthereumSignature(signature)
+    }
+}
+
+#[cfg(feature = "ethereum")]
+impl sp_runtime::traits::Verify for EthereumSignature {
+    type Signer = EthereumPublic;
+
+    fn verify<L: sp_runtime::traits::Lazy<[u8]>>(
+        &self,
+        mut msg: L,
+        signer: &AccountId20,
+    ) -> bool {
+        let hash = sp_core::keccak_256(msg.get());
+        match self.0.recover_prehashed(&hash) {
+            Some(public) => &AccountId20::from_public(&public) == signer,
+            None => false,
+        }
+    }
+}
+
+/// The ECDSA public key which signs an [`EthereumSignature`], which identifies the
+/// [`AccountId20`] that it belongs to.
+#[cfg(feature = "ethereum")]
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
+pub struct EthereumPublic(pub sp_core::ecdsa::Public);
+
+#[cfg(feature = "ethereum")]
+impl From<sp_core::ecdsa::Public> for EthereumPublic {
+    fn from(public: sp_core::ecdsa::Public) -> Self {
+        EthereumPublic(public)
+    }
+}
+
+#[cfg(feature = "ethereum")]
+impl sp_runtime::traits::IdentifyAccount for EthereumPublic {
+    type AccountId = AccountId20;
+
+    fn into_account(self) -> AccountId20 {
+        AccountId20::from_public(&self.0)
+    }
+}
@@ -149,6 +149,10 @@ pub mod api {
             pub struct FillBlock {
                 pub ratio: runtime_types::sp_arithmetic::per_things::Perbill,
             }
+            impl ::subxt::blocks::StaticExtrinsic for FillBlock {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "fill_block";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -157,6 +161,10 @@ pub mod api {
             pub struct Remark {
                 pub remark: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Remark {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "remark";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -166,6 +174,10 @@ pub mod api {
             pub struct SetHeapPages {
                 pub pages: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHeapPages {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "set_heap_pages";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -174,6 +186,10 @@ pub mod api {
             pub struct SetCode {
                 pub code: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetCode {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "set_code";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -182,6 +198,10 @@ pub mod api {
             pub struct SetCodeWithoutChecks {
                 pub code: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetCodeWithoutChecks {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "set_code_without_checks";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -193,6 +213,10 @@ pub mod api {
                     ::std::vec::Vec<::core::primitive::u8>,
                 )>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetStorage {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "set_storage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -201,6 +225,10 @@ pub mod api {
             pub struct KillStorage {
                 pub keys: ::std::vec::Vec<::std::vec::Vec<::core::primitive::u8>>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for KillStorage {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "kill_storage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -210,6 +238,10 @@ pub mod api {
                 pub prefix: ::std::vec::Vec<::core::primitive::u8>,
                 pub subkeys: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for KillPrefix {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "kill_prefix";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -218,6 +250,10 @@ pub mod api {
             pub struct RemarkWithEvent {
                 pub remark: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemarkWithEvent {
+                const PALLET: &'static str = "System";
+                const CALL: &'static str = "remark_with_event";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "A dispatch that will fill the block weight up to the given ratio."]
@@ -508,6 +544,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -539,6 +576,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -560,6 +598,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -585,6 +624,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -606,6 +646,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -628,6 +669,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -652,6 +694,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -676,6 +719,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -702,6 +746,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -723,6 +768,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -744,6 +790,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -767,6 +814,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -801,6 +849,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -822,6 +871,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -855,6 +905,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -890,6 +941,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -913,6 +965,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -934,6 +987,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -956,6 +1010,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -979,6 +1034,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "System",
@@ -1142,6 +1198,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Schedule {
+                const PALLET: &'static str = "Scheduler";
+                const CALL: &'static str = "schedule";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1151,6 +1211,10 @@ pub mod api {
                 pub when: ::core::primitive::u32,
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Cancel {
+                const PALLET: &'static str = "Scheduler";
+                const CALL: &'static str = "cancel";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1171,6 +1235,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ScheduleNamed {
+                const PALLET: &'static str = "Scheduler";
+                const CALL: &'static str = "schedule_named";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1179,6 +1247,10 @@ pub mod api {
             pub struct CancelNamed {
                 pub id: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CancelNamed {
+                const PALLET: &'static str = "Scheduler";
+                const CALL: &'static str = "cancel_named";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1198,6 +1270,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ScheduleAfter {
+                const PALLET: &'static str = "Scheduler";
+                const CALL: &'static str = "schedule_after";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1218,6 +1294,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ScheduleNamedAfter {
+                const PALLET: &'static str = "Scheduler";
+                const CALL: &'static str = "schedule_named_after";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Anonymously schedule a task."]
@@ -1461,7 +1541,7 @@ pub mod api {
             use super::runtime_types;
             pub struct StorageApi;
             impl StorageApi {
-                #[doc = " Items to be executed, indexed by the block number that they should be executed on."]                pub fn agenda (& self , _0 : impl :: std :: borrow :: Borrow < :: core :: primitive :: u32 > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < :: core :: option :: Option < runtime_types :: pallet_scheduler :: ScheduledV3 < runtime_types :: frame_support :: traits :: schedule :: MaybeHashed < runtime_types :: polkadot_runtime :: Call , :: subxt :: ext :: sp_core :: H256 > , :: core :: primitive :: u32 , runtime_types :: polkadot_runtime :: OriginCaller , :: subxt :: ext :: sp_core :: crypto :: AccountId32 > > > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " Items to be executed, indexed by the block number that they should be executed on."]                pub fn agenda (& self , _0 : impl :: std :: borrow :: Borrow < :: core :: primitive :: u32 > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < :: core :: option :: Option < runtime_types :: pallet_scheduler :: ScheduledV3 < runtime_types :: frame_support :: traits :: schedule :: MaybeHashed < runtime_types :: polkadot_runtime :: Call , :: subxt :: ext :: sp_core :: H256 > , :: core :: primitive :: u32 , runtime_types :: polkadot_runtime :: OriginCaller , :: subxt :: ext :: sp_core :: crypto :: AccountId32 > > > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (:: core :: primitive :: u32 ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Scheduler",
                         "Agenda",
@@ -1477,7 +1557,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " Items to be executed, indexed by the block number that they should be executed on."]                pub fn agenda_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < :: core :: option :: Option < runtime_types :: pallet_scheduler :: ScheduledV3 < runtime_types :: frame_support :: traits :: schedule :: MaybeHashed < runtime_types :: polkadot_runtime :: Call , :: subxt :: ext :: sp_core :: H256 > , :: core :: primitive :: u32 , runtime_types :: polkadot_runtime :: OriginCaller , :: subxt :: ext :: sp_core :: crypto :: AccountId32 > > > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " Items to be executed, indexed by the block number that they should be executed on."]                pub fn agenda_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < :: core :: option :: Option < runtime_types :: pallet_scheduler :: ScheduledV3 < runtime_types :: frame_support :: traits :: schedule :: MaybeHashed < runtime_types :: polkadot_runtime :: Call , :: subxt :: ext :: sp_core :: H256 > , :: core :: primitive :: u32 , runtime_types :: polkadot_runtime :: OriginCaller , :: subxt :: ext :: sp_core :: crypto :: AccountId32 > > > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (:: core :: primitive :: u32 ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Scheduler",
                         "Agenda",
@@ -1502,6 +1582,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::std::vec::Vec<::core::primitive::u8>,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Scheduler",
@@ -1529,6 +1610,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::std::vec::Vec<::core::primitive::u8>,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Scheduler",
@@ -1603,6 +1685,10 @@ pub mod api {
             pub struct NotePreimage {
                 pub bytes: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for NotePreimage {
+                const PALLET: &'static str = "Preimage";
+                const CALL: &'static str = "note_preimage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1611,6 +1697,10 @@ pub mod api {
             pub struct UnnotePreimage {
                 pub hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for UnnotePreimage {
+                const PALLET: &'static str = "Preimage";
+                const CALL: &'static str = "unnote_preimage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1619,6 +1709,10 @@ pub mod api {
             pub struct RequestPreimage {
                 pub hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RequestPreimage {
+                const PALLET: &'static str = "Preimage";
+                const CALL: &'static str = "request_preimage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1627,6 +1721,10 @@ pub mod api {
             pub struct UnrequestPreimage {
                 pub hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for UnrequestPreimage {
+                const PALLET: &'static str = "Preimage";
+                const CALL: &'static str = "unrequest_preimage";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Register a preimage on-chain."]
@@ -1769,6 +1867,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Preimage",
@@ -1798,6 +1897,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Preimage",
@@ -1824,6 +1924,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Preimage",
@@ -1852,6 +1953,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Preimage",
@@ -1893,6 +1995,10 @@ pub mod api {
                 >,
                 pub key_owner_proof: runtime_types::sp_session::MembershipProof,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReportEquivocation {
+                const PALLET: &'static str = "Babe";
+                const CALL: &'static str = "report_equivocation";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1910,6 +2016,10 @@ pub mod api {
                 >,
                 pub key_owner_proof: runtime_types::sp_session::MembershipProof,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReportEquivocationUnsigned {
+                const PALLET: &'static str = "Babe";
+                const CALL: &'static str = "report_equivocation_unsigned";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -1919,6 +2029,10 @@ pub mod api {
                 pub config:
                     runtime_types::sp_consensus_babe::digests::NextConfigDescriptor,
             }
+            impl ::subxt::blocks::StaticExtrinsic for PlanConfigChange {
+                const PALLET: &'static str = "Babe";
+                const CALL: &'static str = "plan_config_change";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Report authority equivocation/misbehavior. This method will verify"]
@@ -2012,6 +2126,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2025,7 +2140,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " Current epoch authorities."]                pub fn authorities (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < (runtime_types :: sp_consensus_babe :: app :: Public , :: core :: primitive :: u64 ,) > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " Current epoch authorities."]                pub fn authorities (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < (runtime_types :: sp_consensus_babe :: app :: Public , :: core :: primitive :: u64 ,) > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
                         "Authorities",
@@ -2049,6 +2164,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2072,6 +2188,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2102,6 +2219,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2125,6 +2243,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2146,6 +2265,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2159,7 +2279,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " Next epoch authorities."]                pub fn next_authorities (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < (runtime_types :: sp_consensus_babe :: app :: Public , :: core :: primitive :: u64 ,) > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " Next epoch authorities."]                pub fn next_authorities (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < (runtime_types :: sp_consensus_babe :: app :: Public , :: core :: primitive :: u64 ,) > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
                         "NextAuthorities",
@@ -2188,6 +2308,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2214,6 +2335,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2242,6 +2364,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2268,6 +2391,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2294,6 +2418,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2322,6 +2447,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2347,6 +2473,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2371,6 +2498,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2395,6 +2523,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Babe",
@@ -2491,6 +2620,10 @@ pub mod api {
                 #[codec(compact)]
                 pub now: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Set {
+                const PALLET: &'static str = "Timestamp";
+                const CALL: &'static str = "set";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Set the current time."]
@@ -2539,6 +2672,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Timestamp",
@@ -2560,6 +2694,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Timestamp",
@@ -2619,6 +2754,10 @@ pub mod api {
             pub struct Claim {
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Claim {
+                const PALLET: &'static str = "Indices";
+                const CALL: &'static str = "claim";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -2628,6 +2767,10 @@ pub mod api {
                 pub new: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Transfer {
+                const PALLET: &'static str = "Indices";
+                const CALL: &'static str = "transfer";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -2637,6 +2780,10 @@ pub mod api {
             pub struct Free {
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Free {
+                const PALLET: &'static str = "Indices";
+                const CALL: &'static str = "free";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -2647,6 +2794,10 @@ pub mod api {
                 pub index: ::core::primitive::u32,
                 pub freeze: ::core::primitive::bool,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceTransfer {
+                const PALLET: &'static str = "Indices";
+                const CALL: &'static str = "force_transfer";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -2656,6 +2807,10 @@ pub mod api {
             pub struct Freeze {
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Freeze {
+                const PALLET: &'static str = "Indices";
+                const CALL: &'static str = "freeze";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Assign an previously unassigned index."]
@@ -2902,6 +3057,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Indices",
@@ -2930,6 +3086,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Indices",
@@ -2990,6 +3147,10 @@ pub mod api {
                 #[codec(compact)]
                 pub value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Transfer {
+                const PALLET: &'static str = "Balances";
+                const CALL: &'static str = "transfer";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -3005,6 +3166,10 @@ pub mod api {
                 #[codec(compact)]
                 pub new_reserved: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetBalance {
+                const PALLET: &'static str = "Balances";
+                const CALL: &'static str = "set_balance";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -3022,6 +3187,10 @@ pub mod api {
                 #[codec(compact)]
                 pub value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceTransfer {
+                const PALLET: &'static str = "Balances";
+                const CALL: &'static str = "force_transfer";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -3035,6 +3204,10 @@ pub mod api {
                 #[codec(compact)]
                 pub value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for TransferKeepAlive {
+                const PALLET: &'static str = "Balances";
+                const CALL: &'static str = "transfer_keep_alive";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -3047,6 +3220,10 @@ pub mod api {
                 >,
                 pub keep_alive: ::core::primitive::bool,
             }
+            impl ::subxt::blocks::StaticExtrinsic for TransferAll {
+                const PALLET: &'static str = "Balances";
+                const CALL: &'static str = "transfer_all";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -3059,6 +3236,10 @@ pub mod api {
                 >,
                 pub amount: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceUnreserve {
+                const PALLET: &'static str = "Balances";
+                const CALL: &'static str = "force_unreserve";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Transfer some liquid free balance to another account."]
@@ -3425,6 +3606,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
@@ -3474,6 +3656,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
@@ -3525,6 +3708,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
@@ -3539,7 +3723,7 @@ pub mod api {
                     )
                 }
                 #[doc = " Any liquidity locks on some account balances."]
-                #[doc = " NOTE: Should only be accessed when setting, changing and freeing a lock."]                pub fn locks (& self , _0 : impl :: std :: borrow :: Borrow < :: subxt :: ext :: sp_core :: crypto :: AccountId32 > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < runtime_types :: pallet_balances :: BalanceLock < :: core :: primitive :: u128 > > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " NOTE: Should only be accessed when setting, changing and freeing a lock."]                pub fn locks (& self , _0 : impl :: std :: borrow :: Borrow < :: subxt :: ext :: sp_core :: crypto :: AccountId32 > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < runtime_types :: pallet_balances :: BalanceLock < :: core :: primitive :: u128 > > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (:: subxt :: ext :: sp_core :: crypto :: AccountId32 ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
                         "Locks",
@@ -3556,7 +3740,7 @@ pub mod api {
                     )
                 }
                 #[doc = " Any liquidity locks on some account balances."]
-                #[doc = " NOTE: Should only be accessed when setting, changing and freeing a lock."]                pub fn locks_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < runtime_types :: pallet_balances :: BalanceLock < :: core :: primitive :: u128 > > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " NOTE: Should only be accessed when setting, changing and freeing a lock."]                pub fn locks_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < runtime_types :: pallet_balances :: BalanceLock < :: core :: primitive :: u128 > > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (:: subxt :: ext :: sp_core :: crypto :: AccountId32 ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
                         "Locks",
@@ -3585,6 +3769,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
@@ -3616,6 +3801,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
@@ -3641,6 +3827,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Balances",
@@ -3731,6 +3918,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TransactionPayment",
@@ -3753,6 +3941,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TransactionPayment",
@@ -3833,6 +4022,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetUncles {
+                const PALLET: &'static str = "Authorship";
+                const CALL: &'static str = "set_uncles";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Provide a set of uncles."]
@@ -3879,6 +4072,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Authorship",
@@ -3902,6 +4096,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Authorship",
@@ -3923,6 +4118,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Authorship",
@@ -3988,6 +4184,10 @@ pub mod api {
                     ::subxt::ext::sp_core::crypto::AccountId32,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Bond {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "bond";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -3997,6 +4197,10 @@ pub mod api {
                 #[codec(compact)]
                 pub max_additional: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for BondExtra {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "bond_extra";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4006,6 +4210,10 @@ pub mod api {
                 #[codec(compact)]
                 pub value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Unbond {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "unbond";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -4015,6 +4223,10 @@ pub mod api {
             pub struct WithdrawUnbonded {
                 pub num_slashing_spans: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for WithdrawUnbonded {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "withdraw_unbonded";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4023,6 +4235,10 @@ pub mod api {
             pub struct Validate {
                 pub prefs: runtime_types::pallet_staking::ValidatorPrefs,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Validate {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "validate";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4036,12 +4252,20 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Nominate {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "nominate";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct Chill;
+            impl ::subxt::blocks::StaticExtrinsic for Chill {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "chill";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4052,6 +4276,10 @@ pub mod api {
                     ::subxt::ext::sp_core::crypto::AccountId32,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetPayee {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "set_payee";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4063,6 +4291,10 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetController {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "set_controller";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4072,6 +4304,10 @@ pub mod api {
                 #[codec(compact)]
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetValidatorCount {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "set_validator_count";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4081,6 +4317,10 @@ pub mod api {
                 #[codec(compact)]
                 pub additional: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for IncreaseValidatorCount {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "increase_validator_count";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4089,18 +4329,30 @@ pub mod api {
             pub struct ScaleValidatorCount {
                 pub factor: runtime_types::sp_arithmetic::per_things::Percent,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ScaleValidatorCount {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "scale_validator_count";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct ForceNoEras;
+            impl ::subxt::blocks::StaticExtrinsic for ForceNoEras {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "force_no_eras";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct ForceNewEra;
+            impl ::subxt::blocks::StaticExtrinsic for ForceNewEra {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "force_new_era";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4110,6 +4362,10 @@ pub mod api {
                 pub invulnerables:
                     ::std::vec::Vec<::subxt::ext::sp_core::crypto::AccountId32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetInvulnerables {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "set_invulnerables";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4119,12 +4375,20 @@ pub mod api {
                 pub stash: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub num_slashing_spans: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceUnstake {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "force_unstake";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct ForceNewEraAlways;
+            impl ::subxt::blocks::StaticExtrinsic for ForceNewEraAlways {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "force_new_era_always";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4134,6 +4398,10 @@ pub mod api {
                 pub era: ::core::primitive::u32,
                 pub slash_indices: ::std::vec::Vec<::core::primitive::u32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CancelDeferredSlash {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "cancel_deferred_slash";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4143,6 +4411,10 @@ pub mod api {
                 pub validator_stash: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub era: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for PayoutStakers {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "payout_stakers";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4152,6 +4424,10 @@ pub mod api {
                 #[codec(compact)]
                 pub value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Rebond {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "rebond";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4163,6 +4439,10 @@ pub mod api {
                 #[codec(compact)]
                 pub era_items_deleted: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHistoryDepth {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "set_history_depth";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4172,6 +4452,10 @@ pub mod api {
                 pub stash: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub num_slashing_spans: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReapStash {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "reap_stash";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4185,6 +4469,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Kick {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "kick";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4216,6 +4504,10 @@ pub mod api {
                         runtime_types::sp_arithmetic::per_things::Perbill,
                     >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetStakingConfigs {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "set_staking_configs";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4224,6 +4516,10 @@ pub mod api {
             pub struct ChillOther {
                 pub controller: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ChillOther {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "chill_other";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -4232,6 +4528,10 @@ pub mod api {
             pub struct ForceApplyMinCommission {
                 pub validator_stash: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceApplyMinCommission {
+                const PALLET: &'static str = "Staking";
+                const CALL: &'static str = "force_apply_min_commission";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Take the origin account as a stash and lock up `value` of its balance. `controller` will"]
@@ -5224,6 +5524,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5245,6 +5546,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5266,6 +5568,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5291,6 +5594,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5315,6 +5619,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5341,6 +5646,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5362,6 +5668,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5383,6 +5690,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5408,6 +5716,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5432,6 +5741,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5458,6 +5768,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5484,6 +5795,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5512,6 +5824,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5536,6 +5849,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5562,6 +5876,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5583,6 +5898,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5606,6 +5922,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5645,6 +5962,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5686,6 +6004,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5707,6 +6026,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5730,6 +6050,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5754,6 +6075,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5780,6 +6102,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5805,6 +6128,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5832,6 +6156,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5865,6 +6190,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5905,6 +6231,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5918,6 +6245,42 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " Exposure of validator at era."]
+                #[doc = ""]
+                #[doc = " This is keyed first by the era index to allow bulk deletion and then the stash account."]
+                #[doc = ""]
+                #[doc = " Is it removed after `HISTORY_DEPTH` eras."]
+                #[doc = " If stakers hasn't been set or has been removed then empty exposure is returned."]
+                pub fn eras_stakers_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<
+                        runtime_types::pallet_staking::Exposure<
+                            ::subxt::ext::sp_core::crypto::AccountId32,
+                            ::core::primitive::u128,
+                        >,
+                    >,
+                    (),
+                    ::subxt::storage::address::Yes,
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "Staking",
+                        "ErasStakers",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            192u8, 50u8, 152u8, 151u8, 92u8, 180u8, 206u8, 15u8, 139u8,
+                            210u8, 128u8, 65u8, 92u8, 253u8, 43u8, 35u8, 139u8, 171u8,
+                            73u8, 185u8, 32u8, 78u8, 20u8, 197u8, 154u8, 90u8, 233u8,
+                            231u8, 23u8, 22u8, 187u8, 156u8,
+                        ],
+                    )
+                }
                 #[doc = " Clipped Exposure of validator at era."]
                 #[doc = ""]
                 #[doc = " This is similar to [`ErasStakers`] but number of nominators exposed is reduced to the"]
@@ -5943,6 +6306,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -5988,6 +6352,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6001,6 +6366,47 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " Clipped Exposure of validator at era."]
+                #[doc = ""]
+                #[doc = " This is similar to [`ErasStakers`] but number of nominators exposed is reduced to the"]
+                #[doc = " `T::MaxNominatorRewardedPerValidator` biggest stakers."]
+                #[doc = " (Note: the field `total` and `own` of the exposure remains unchanged)."]
+                #[doc = " This is used to limit the i/o cost for the nominator payout."]
+                #[doc = ""]
+                #[doc = " This is keyed fist by the era index to allow bulk deletion and then the stash account."]
+                #[doc = ""]
+                #[doc = " Is it removed after `HISTORY_DEPTH` eras."]
+                #[doc = " If stakers hasn't been set or has been removed then empty exposure is returned."]
+                pub fn eras_stakers_clipped_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<
+                        runtime_types::pallet_staking::Exposure<
+                            ::subxt::ext::sp_core::crypto::AccountId32,
+                            ::core::primitive::u128,
+                        >,
+                    >,
+                    (),
+                    ::subxt::storage::address::Yes,
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "Staking",
+                        "ErasStakersClipped",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            43u8, 159u8, 113u8, 223u8, 122u8, 169u8, 98u8, 153u8, 26u8,
+                            55u8, 71u8, 119u8, 174u8, 48u8, 158u8, 45u8, 214u8, 26u8,
+                            136u8, 215u8, 46u8, 161u8, 185u8, 17u8, 174u8, 204u8, 206u8,
+                            246u8, 49u8, 87u8, 134u8, 169u8,
+                        ],
+                    )
+                }
                 #[doc = " Similar to `ErasStakers`, this holds the preferences of validators."]
                 #[doc = ""]
                 #[doc = " This is keyed first by the era index to allow bulk deletion and then the stash account."]
@@ -6017,6 +6423,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6053,6 +6460,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6066,30 +6474,63 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " The total validator era payout for the last `HISTORY_DEPTH` eras."]
+                #[doc = " Similar to `ErasStakers`, this holds the preferences of validators."]
                 #[doc = ""]
-                #[doc = " Eras that haven't finished yet or has been removed doesn't have reward."]
-                pub fn eras_validator_reward(
+                #[doc = " This is keyed first by the era index to allow bulk deletion and then the stash account."]
+                #[doc = ""]
+                #[doc = " Is it removed after `HISTORY_DEPTH` eras."]
+                pub fn eras_validator_prefs_iter1(
                     &self,
                     _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
                 ) -> ::subxt::storage::address::StaticStorageAddress<
-                    ::subxt::metadata::DecodeStaticType<::core::primitive::u128>,
-                    ::subxt::storage::address::Yes,
+                    ::subxt::metadata::DecodeStaticType<
+                        runtime_types::pallet_staking::ValidatorPrefs,
+                    >,
                     (),
                     ::subxt::storage::address::Yes,
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
-                        "ErasValidatorReward",
+                        "ErasValidatorPrefs",
                         vec![::subxt::storage::address::StorageMapKey::new(
                             _0.borrow(),
                             ::subxt::storage::address::StorageHasher::Twox64Concat,
                         )],
                         [
-                            87u8, 80u8, 156u8, 123u8, 107u8, 77u8, 203u8, 37u8, 231u8,
-                            84u8, 124u8, 155u8, 227u8, 212u8, 212u8, 179u8, 84u8, 161u8,
-                            223u8, 255u8, 254u8, 107u8, 52u8, 89u8, 98u8, 169u8, 136u8,
-                            241u8, 104u8, 3u8, 244u8, 161u8,
+                            6u8, 196u8, 209u8, 138u8, 252u8, 18u8, 203u8, 86u8, 129u8,
+                            62u8, 4u8, 56u8, 234u8, 114u8, 141u8, 136u8, 127u8, 224u8,
+                            142u8, 89u8, 150u8, 33u8, 31u8, 50u8, 140u8, 108u8, 124u8,
+                            77u8, 188u8, 102u8, 230u8, 174u8,
+                        ],
+                    )
+                }
+                #[doc = " The total validator era payout for the last `HISTORY_DEPTH` eras."]
+                #[doc = ""]
+                #[doc = " Eras that haven't finished yet or has been removed doesn't have reward."]
+                pub fn eras_validator_reward(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<::core::primitive::u128>,
+                    ::subxt::storage::address::Yes,
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "Staking",
+                        "ErasValidatorReward",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            87u8, 80u8, 156u8, 123u8, 107u8, 77u8, 203u8, 37u8, 231u8,
+                            84u8, 124u8, 155u8, 227u8, 212u8, 212u8, 179u8, 84u8, 161u8,
+                            223u8, 255u8, 254u8, 107u8, 52u8, 89u8, 98u8, 169u8, 136u8,
+                            241u8, 104u8, 3u8, 244u8, 161u8,
                         ],
                     )
                 }
@@ -6103,6 +6544,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6130,6 +6572,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6159,6 +6602,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6182,6 +6626,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6207,6 +6652,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6230,6 +6676,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6255,6 +6702,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6277,6 +6725,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6306,6 +6755,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6337,6 +6787,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6363,6 +6814,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6390,6 +6842,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6424,6 +6877,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6437,6 +6891,36 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " All slashing events on validators, mapped by era to the highest slash proportion"]
+                #[doc = " and slash value of the era."]
+                pub fn validator_slash_in_era_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<(
+                        runtime_types::sp_arithmetic::per_things::Perbill,
+                        ::core::primitive::u128,
+                    )>,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "Staking",
+                        "ValidatorSlashInEra",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            237u8, 80u8, 3u8, 237u8, 9u8, 40u8, 212u8, 15u8, 251u8,
+                            196u8, 85u8, 29u8, 27u8, 151u8, 98u8, 122u8, 189u8, 147u8,
+                            205u8, 40u8, 202u8, 194u8, 158u8, 96u8, 138u8, 16u8, 116u8,
+                            71u8, 140u8, 163u8, 121u8, 197u8,
+                        ],
+                    )
+                }
                 #[doc = " All slashing events on nominators, mapped by era to the highest slash value of the era."]
                 pub fn nominator_slash_in_era(
                     &self,
@@ -6447,6 +6931,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6477,6 +6962,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6490,6 +6976,32 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " All slashing events on nominators, mapped by era to the highest slash value of the era."]
+                pub fn nominator_slash_in_era_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<::core::primitive::u128>,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "Staking",
+                        "NominatorSlashInEra",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            249u8, 85u8, 170u8, 41u8, 179u8, 194u8, 180u8, 12u8, 53u8,
+                            101u8, 80u8, 96u8, 166u8, 71u8, 239u8, 23u8, 153u8, 19u8,
+                            152u8, 38u8, 138u8, 136u8, 221u8, 200u8, 18u8, 165u8, 26u8,
+                            228u8, 195u8, 199u8, 62u8, 4u8,
+                        ],
+                    )
+                }
                 #[doc = " Slashing spans for stash accounts."]
                 pub fn slashing_spans(
                     &self,
@@ -6501,6 +7013,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6527,6 +7040,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6555,6 +7069,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6584,6 +7099,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6605,6 +7121,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6628,6 +7145,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6662,6 +7180,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6688,6 +7207,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6713,6 +7233,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Staking",
@@ -6892,6 +7413,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Offences",
@@ -6927,6 +7449,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Offences",
@@ -6952,6 +7475,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    ([::core::primitive::u8; 16usize], ::std::vec::Vec<::core::primitive::u8>),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Offences",
@@ -6984,6 +7508,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    ([::core::primitive::u8; 16usize], ::std::vec::Vec<::core::primitive::u8>),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Offences",
@@ -6997,6 +7522,34 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " A vector of reports of the same kind that happened at the same time slot."]
+                pub fn concurrent_reports_index_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<[::core::primitive::u8; 16usize]>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<
+                        ::std::vec::Vec<::subxt::ext::sp_core::H256>,
+                    >,
+                    (),
+                    ::subxt::storage::address::Yes,
+                    ::subxt::storage::address::Yes,
+                    ([::core::primitive::u8; 16usize], ::std::vec::Vec<::core::primitive::u8>),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "Offences",
+                        "ConcurrentReportsIndex",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            106u8, 21u8, 104u8, 5u8, 4u8, 66u8, 28u8, 70u8, 161u8, 195u8,
+                            238u8, 28u8, 69u8, 241u8, 221u8, 113u8, 140u8, 103u8, 181u8,
+                            143u8, 60u8, 177u8, 13u8, 129u8, 224u8, 149u8, 77u8, 32u8,
+                            75u8, 74u8, 101u8, 65u8,
+                        ],
+                    )
+                }
                 #[doc = " Enumerates all reports of a kind along with the time they happened."]
                 #[doc = ""]
                 #[doc = " All reports are sorted by the time of offence."]
@@ -7013,6 +7566,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    ([::core::primitive::u8; 16usize],),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Offences",
@@ -7044,6 +7598,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    ([::core::primitive::u8; 16usize],),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Offences",
@@ -7081,12 +7636,20 @@ pub mod api {
                 pub keys: runtime_types::polkadot_runtime::SessionKeys,
                 pub proof: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetKeys {
+                const PALLET: &'static str = "Session";
+                const CALL: &'static str = "set_keys";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct PurgeKeys;
+            impl ::subxt::blocks::StaticExtrinsic for PurgeKeys {
+                const PALLET: &'static str = "Session";
+                const CALL: &'static str = "purge_keys";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Sets the session key(s) of the function caller to `keys`."]
@@ -7185,6 +7748,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7206,6 +7770,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7228,6 +7793,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7255,6 +7821,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7282,6 +7849,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7306,6 +7874,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7332,6 +7901,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7357,6 +7927,10 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        runtime_types::sp_core::crypto::KeyTypeId,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    ),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7383,6 +7957,10 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        runtime_types::sp_core::crypto::KeyTypeId,
+                        ::std::vec::Vec<::core::primitive::u8>,
+                    ),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Session",
@@ -7421,6 +7999,10 @@ pub mod api {
                 >,
                 pub key_owner_proof: runtime_types::sp_session::MembershipProof,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReportEquivocation {
+                const PALLET: &'static str = "Grandpa";
+                const CALL: &'static str = "report_equivocation";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -7435,6 +8017,10 @@ pub mod api {
                 >,
                 pub key_owner_proof: runtime_types::sp_session::MembershipProof,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReportEquivocationUnsigned {
+                const PALLET: &'static str = "Grandpa";
+                const CALL: &'static str = "report_equivocation_unsigned";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -7444,6 +8030,10 @@ pub mod api {
                 pub delay: ::core::primitive::u32,
                 pub best_finalized_block_number: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for NoteStalled {
+                const PALLET: &'static str = "Grandpa";
+                const CALL: &'static str = "note_stalled";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Report voter equivocation/misbehavior. This method will verify the"]
@@ -7597,6 +8187,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Grandpa",
@@ -7622,6 +8213,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Grandpa",
@@ -7643,6 +8235,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Grandpa",
@@ -7667,6 +8260,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Grandpa",
@@ -7689,6 +8283,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Grandpa",
@@ -7714,6 +8309,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Grandpa",
@@ -7741,6 +8337,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Grandpa",
@@ -7799,6 +8396,10 @@ pub mod api {
                 pub signature:
                     runtime_types::pallet_im_online::sr25519::app_sr25519::Signature,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Heartbeat {
+                const PALLET: &'static str = "ImOnline";
+                const CALL: &'static str = "heartbeat";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "# <weight>"]
@@ -7905,6 +8506,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ImOnline",
@@ -7918,7 +8520,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " The current set of keys that may issue a heartbeat."]                pub fn keys (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < runtime_types :: pallet_im_online :: sr25519 :: app_sr25519 :: Public > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " The current set of keys that may issue a heartbeat."]                pub fn keys (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: weak_bounded_vec :: WeakBoundedVec < runtime_types :: pallet_im_online :: sr25519 :: app_sr25519 :: Public > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ImOnline",
                         "Keys",
@@ -7946,6 +8548,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ImOnline",
@@ -7981,6 +8584,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ImOnline",
@@ -7994,6 +8598,37 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " For each session index, we keep a mapping of `SessionIndex` and `AuthIndex` to"]
+                #[doc = " `WrapperOpaque<BoundedOpaqueNetworkState>`."]
+                pub fn received_heartbeats_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<
+                        runtime_types::frame_support::traits::misc::WrapperOpaque<
+                            runtime_types::pallet_im_online::BoundedOpaqueNetworkState,
+                        >,
+                    >,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::core::primitive::u32),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "ImOnline",
+                        "ReceivedHeartbeats",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            233u8, 128u8, 140u8, 233u8, 55u8, 146u8, 172u8, 54u8, 54u8,
+                            57u8, 141u8, 106u8, 168u8, 59u8, 147u8, 253u8, 119u8, 48u8,
+                            50u8, 251u8, 242u8, 109u8, 251u8, 2u8, 136u8, 80u8, 146u8,
+                            121u8, 180u8, 219u8, 245u8, 37u8,
+                        ],
+                    )
+                }
                 #[doc = " For each session index, we keep a mapping of `ValidatorId<T>` to the"]
                 #[doc = " number of blocks authored by the given authority."]
                 pub fn authored_blocks(
@@ -8005,6 +8640,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ImOnline",
@@ -8036,6 +8672,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ImOnline",
@@ -8049,6 +8686,33 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " For each session index, we keep a mapping of `ValidatorId<T>` to the"]
+                #[doc = " number of blocks authored by the given authority."]
+                pub fn authored_blocks_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<::core::primitive::u32>,
+                    (),
+                    ::subxt::storage::address::Yes,
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::subxt::ext::sp_core::crypto::AccountId32),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "ImOnline",
+                        "AuthoredBlocks",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            50u8, 4u8, 242u8, 240u8, 247u8, 184u8, 114u8, 245u8, 233u8,
+                            170u8, 24u8, 197u8, 18u8, 245u8, 8u8, 28u8, 33u8, 115u8,
+                            166u8, 245u8, 221u8, 223u8, 56u8, 144u8, 33u8, 139u8, 10u8,
+                            227u8, 228u8, 223u8, 103u8, 151u8,
+                        ],
+                    )
+                }
             }
         }
         pub mod constants {
@@ -8100,6 +8764,10 @@ pub mod api {
                 #[codec(compact)]
                 pub value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Propose {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "propose";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8111,6 +8779,10 @@ pub mod api {
                 #[codec(compact)]
                 pub seconds_upper_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Second {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "second";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8123,6 +8795,10 @@ pub mod api {
                     ::core::primitive::u128,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Vote {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "vote";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -8132,6 +8808,10 @@ pub mod api {
             pub struct EmergencyCancel {
                 pub ref_index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for EmergencyCancel {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "emergency_cancel";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8140,6 +8820,10 @@ pub mod api {
             pub struct ExternalPropose {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ExternalPropose {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "external_propose";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8148,6 +8832,10 @@ pub mod api {
             pub struct ExternalProposeMajority {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ExternalProposeMajority {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "external_propose_majority";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8156,6 +8844,10 @@ pub mod api {
             pub struct ExternalProposeDefault {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ExternalProposeDefault {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "external_propose_default";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8166,6 +8858,10 @@ pub mod api {
                 pub voting_period: ::core::primitive::u32,
                 pub delay: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for FastTrack {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "fast_track";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8174,6 +8870,10 @@ pub mod api {
             pub struct VetoExternal {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for VetoExternal {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "veto_external";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8183,6 +8883,10 @@ pub mod api {
                 #[codec(compact)]
                 pub ref_index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CancelReferendum {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "cancel_referendum";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -8192,6 +8896,10 @@ pub mod api {
             pub struct CancelQueued {
                 pub which: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CancelQueued {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "cancel_queued";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8202,18 +8910,30 @@ pub mod api {
                 pub conviction: runtime_types::pallet_democracy::conviction::Conviction,
                 pub balance: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Delegate {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "delegate";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct Undelegate;
+            impl ::subxt::blocks::StaticExtrinsic for Undelegate {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "undelegate";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct ClearPublicProposals;
+            impl ::subxt::blocks::StaticExtrinsic for ClearPublicProposals {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "clear_public_proposals";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8222,6 +8942,10 @@ pub mod api {
             pub struct NotePreimage {
                 pub encoded_proposal: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for NotePreimage {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "note_preimage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8230,6 +8954,10 @@ pub mod api {
             pub struct NotePreimageOperational {
                 pub encoded_proposal: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for NotePreimageOperational {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "note_preimage_operational";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8238,6 +8966,10 @@ pub mod api {
             pub struct NoteImminentPreimage {
                 pub encoded_proposal: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for NoteImminentPreimage {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "note_imminent_preimage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8246,6 +8978,10 @@ pub mod api {
             pub struct NoteImminentPreimageOperational {
                 pub encoded_proposal: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for NoteImminentPreimageOperational {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "note_imminent_preimage_operational";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8256,6 +8992,10 @@ pub mod api {
                 #[codec(compact)]
                 pub proposal_len_upper_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReapPreimage {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "reap_preimage";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8264,6 +9004,10 @@ pub mod api {
             pub struct Unlock {
                 pub target: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Unlock {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "unlock";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -8273,6 +9017,10 @@ pub mod api {
             pub struct RemoveVote {
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveVote {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "remove_vote";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8282,6 +9030,10 @@ pub mod api {
                 pub target: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveOtherVote {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "remove_other_vote";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8291,6 +9043,10 @@ pub mod api {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
                 pub index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for EnactProposal {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "enact_proposal";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8300,6 +9056,10 @@ pub mod api {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
                 pub maybe_ref_index: ::core::option::Option<::core::primitive::u32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Blacklist {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "blacklist";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -8309,6 +9069,10 @@ pub mod api {
                 #[codec(compact)]
                 pub prop_index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CancelProposal {
+                const PALLET: &'static str = "Democracy";
+                const CALL: &'static str = "cancel_proposal";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Propose a sensitive action to be taken."]
@@ -9308,6 +10072,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9335,6 +10100,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9362,6 +10128,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9391,6 +10158,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9420,6 +10188,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9451,6 +10220,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9472,6 +10242,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9494,6 +10265,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9524,6 +10296,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9556,6 +10329,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9587,6 +10361,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9620,6 +10395,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9642,6 +10418,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9669,6 +10446,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9695,6 +10473,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9723,6 +10502,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9745,6 +10525,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9769,6 +10550,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -9794,6 +10576,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Democracy",
@@ -10035,6 +10818,10 @@ pub mod api {
                     ::core::option::Option<::subxt::ext::sp_core::crypto::AccountId32>,
                 pub old_count: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMembers {
+                const PALLET: &'static str = "Council";
+                const CALL: &'static str = "set_members";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10045,6 +10832,10 @@ pub mod api {
                 #[codec(compact)]
                 pub length_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Execute {
+                const PALLET: &'static str = "Council";
+                const CALL: &'static str = "execute";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10057,6 +10848,10 @@ pub mod api {
                 #[codec(compact)]
                 pub length_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Propose {
+                const PALLET: &'static str = "Council";
+                const CALL: &'static str = "propose";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10068,6 +10863,10 @@ pub mod api {
                 pub index: ::core::primitive::u32,
                 pub approve: ::core::primitive::bool,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Vote {
+                const PALLET: &'static str = "Council";
+                const CALL: &'static str = "vote";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10082,6 +10881,10 @@ pub mod api {
                 #[codec(compact)]
                 pub length_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Close {
+                const PALLET: &'static str = "Council";
+                const CALL: &'static str = "close";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10090,6 +10893,10 @@ pub mod api {
             pub struct DisapproveProposal {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for DisapproveProposal {
+                const PALLET: &'static str = "Council";
+                const CALL: &'static str = "disapprove_proposal";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Set the collective's membership."]
@@ -10482,6 +11289,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10506,6 +11314,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10532,6 +11341,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10559,6 +11369,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10588,6 +11399,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10609,6 +11421,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10632,6 +11445,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10655,6 +11469,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Council",
@@ -10691,6 +11506,10 @@ pub mod api {
                     ::core::option::Option<::subxt::ext::sp_core::crypto::AccountId32>,
                 pub old_count: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMembers {
+                const PALLET: &'static str = "TechnicalCommittee";
+                const CALL: &'static str = "set_members";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10701,6 +11520,10 @@ pub mod api {
                 #[codec(compact)]
                 pub length_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Execute {
+                const PALLET: &'static str = "TechnicalCommittee";
+                const CALL: &'static str = "execute";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10713,6 +11536,10 @@ pub mod api {
                 #[codec(compact)]
                 pub length_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Propose {
+                const PALLET: &'static str = "TechnicalCommittee";
+                const CALL: &'static str = "propose";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10724,6 +11551,10 @@ pub mod api {
                 pub index: ::core::primitive::u32,
                 pub approve: ::core::primitive::bool,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Vote {
+                const PALLET: &'static str = "TechnicalCommittee";
+                const CALL: &'static str = "vote";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10738,6 +11569,10 @@ pub mod api {
                 #[codec(compact)]
                 pub length_bound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Close {
+                const PALLET: &'static str = "TechnicalCommittee";
+                const CALL: &'static str = "close";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -10746,6 +11581,10 @@ pub mod api {
             pub struct DisapproveProposal {
                 pub proposal_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for DisapproveProposal {
+                const PALLET: &'static str = "TechnicalCommittee";
+                const CALL: &'static str = "disapprove_proposal";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Set the collective's membership."]
@@ -11138,6 +11977,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11162,6 +12002,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11188,6 +12029,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11215,6 +12057,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11244,6 +12087,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11265,6 +12109,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11288,6 +12133,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11311,6 +12157,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalCommittee",
@@ -11345,12 +12192,20 @@ pub mod api {
                 #[codec(compact)]
                 pub value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Vote {
+                const PALLET: &'static str = "PhragmenElection";
+                const CALL: &'static str = "vote";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct RemoveVoter;
+            impl ::subxt::blocks::StaticExtrinsic for RemoveVoter {
+                const PALLET: &'static str = "PhragmenElection";
+                const CALL: &'static str = "remove_voter";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -11360,6 +12215,10 @@ pub mod api {
                 #[codec(compact)]
                 pub candidate_count: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SubmitCandidacy {
+                const PALLET: &'static str = "PhragmenElection";
+                const CALL: &'static str = "submit_candidacy";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -11368,6 +12227,10 @@ pub mod api {
             pub struct RenounceCandidacy {
                 pub renouncing: runtime_types::pallet_elections_phragmen::Renouncing,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RenounceCandidacy {
+                const PALLET: &'static str = "PhragmenElection";
+                const CALL: &'static str = "renounce_candidacy";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -11380,6 +12243,10 @@ pub mod api {
                 >,
                 pub has_replacement: ::core::primitive::bool,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveMember {
+                const PALLET: &'static str = "PhragmenElection";
+                const CALL: &'static str = "remove_member";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -11389,6 +12256,10 @@ pub mod api {
                 pub num_voters: ::core::primitive::u32,
                 pub num_defunct: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CleanDefunctVoters {
+                const PALLET: &'static str = "PhragmenElection";
+                const CALL: &'static str = "clean_defunct_voters";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Vote for a set of candidates for the upcoming round of election. This can be called to"]
@@ -11710,6 +12581,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "PhragmenElection",
@@ -11741,6 +12613,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "PhragmenElection",
@@ -11772,6 +12645,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "PhragmenElection",
@@ -11793,6 +12667,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "PhragmenElection",
@@ -11822,6 +12697,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "PhragmenElection",
@@ -11853,6 +12729,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "PhragmenElection",
@@ -12015,6 +12892,10 @@ pub mod api {
             pub struct AddMember {
                 pub who: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AddMember {
+                const PALLET: &'static str = "TechnicalMembership";
+                const CALL: &'static str = "add_member";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12023,6 +12904,10 @@ pub mod api {
             pub struct RemoveMember {
                 pub who: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveMember {
+                const PALLET: &'static str = "TechnicalMembership";
+                const CALL: &'static str = "remove_member";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12032,6 +12917,10 @@ pub mod api {
                 pub remove: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub add: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SwapMember {
+                const PALLET: &'static str = "TechnicalMembership";
+                const CALL: &'static str = "swap_member";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12040,6 +12929,10 @@ pub mod api {
             pub struct ResetMembers {
                 pub members: ::std::vec::Vec<::subxt::ext::sp_core::crypto::AccountId32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ResetMembers {
+                const PALLET: &'static str = "TechnicalMembership";
+                const CALL: &'static str = "reset_members";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12048,6 +12941,10 @@ pub mod api {
             pub struct ChangeKey {
                 pub new: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ChangeKey {
+                const PALLET: &'static str = "TechnicalMembership";
+                const CALL: &'static str = "change_key";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12056,12 +12953,20 @@ pub mod api {
             pub struct SetPrime {
                 pub who: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetPrime {
+                const PALLET: &'static str = "TechnicalMembership";
+                const CALL: &'static str = "set_prime";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct ClearPrime;
+            impl ::subxt::blocks::StaticExtrinsic for ClearPrime {
+                const PALLET: &'static str = "TechnicalMembership";
+                const CALL: &'static str = "clear_prime";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Add a member `who` to the set."]
@@ -12289,6 +13194,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalMembership",
@@ -12312,6 +13218,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "TechnicalMembership",
@@ -12349,6 +13256,10 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ProposeSpend {
+                const PALLET: &'static str = "Treasury";
+                const CALL: &'static str = "propose_spend";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12358,6 +13269,10 @@ pub mod api {
                 #[codec(compact)]
                 pub proposal_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RejectProposal {
+                const PALLET: &'static str = "Treasury";
+                const CALL: &'static str = "reject_proposal";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12367,6 +13282,10 @@ pub mod api {
                 #[codec(compact)]
                 pub proposal_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ApproveProposal {
+                const PALLET: &'static str = "Treasury";
+                const CALL: &'static str = "approve_proposal";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12380,6 +13299,10 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Spend {
+                const PALLET: &'static str = "Treasury";
+                const CALL: &'static str = "spend";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12389,6 +13312,10 @@ pub mod api {
                 #[codec(compact)]
                 pub proposal_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveApproval {
+                const PALLET: &'static str = "Treasury";
+                const CALL: &'static str = "remove_approval";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Put forward a suggestion for spending. A deposit proportional to the value"]
@@ -12666,6 +13593,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Treasury",
@@ -12693,6 +13621,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Treasury",
@@ -12722,6 +13651,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Treasury",
@@ -12747,6 +13677,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Treasury",
@@ -12917,6 +13848,10 @@ pub mod api {
                 pub ethereum_signature:
                     runtime_types::polkadot_runtime_common::claims::EcdsaSignature,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Claim {
+                const PALLET: &'static str = "Claims";
+                const CALL: &'static str = "claim";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12934,6 +13869,10 @@ pub mod api {
                     runtime_types::polkadot_runtime_common::claims::StatementKind,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for MintClaim {
+                const PALLET: &'static str = "Claims";
+                const CALL: &'static str = "mint_claim";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12945,6 +13884,10 @@ pub mod api {
                     runtime_types::polkadot_runtime_common::claims::EcdsaSignature,
                 pub statement: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ClaimAttest {
+                const PALLET: &'static str = "Claims";
+                const CALL: &'static str = "claim_attest";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12953,6 +13896,10 @@ pub mod api {
             pub struct Attest {
                 pub statement: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Attest {
+                const PALLET: &'static str = "Claims";
+                const CALL: &'static str = "attest";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -12964,6 +13911,10 @@ pub mod api {
                 pub maybe_preclaim:
                     ::core::option::Option<::subxt::ext::sp_core::crypto::AccountId32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for MoveClaim {
+                const PALLET: &'static str = "Claims";
+                const CALL: &'static str = "move_claim";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Make a claim to collect your DOTs."]
@@ -13197,6 +14148,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_runtime_common::claims::EthereumAddress,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13220,6 +14172,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_runtime_common::claims::EthereumAddress,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13240,6 +14193,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13271,6 +14225,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_runtime_common::claims::EthereumAddress,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13302,6 +14257,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_runtime_common::claims::EthereumAddress,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13328,6 +14284,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_runtime_common::claims::EthereumAddress,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13354,6 +14311,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_runtime_common::claims::EthereumAddress,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13378,6 +14336,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13404,6 +14363,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Claims",
@@ -13458,6 +14418,10 @@ pub mod api {
                 Debug,
             )]
             pub struct Vest;
+            impl ::subxt::blocks::StaticExtrinsic for Vest {
+                const PALLET: &'static str = "Vesting";
+                const CALL: &'static str = "vest";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13469,6 +14433,10 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for VestOther {
+                const PALLET: &'static str = "Vesting";
+                const CALL: &'static str = "vest_other";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13484,6 +14452,10 @@ pub mod api {
                     ::core::primitive::u32,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for VestedTransfer {
+                const PALLET: &'static str = "Vesting";
+                const CALL: &'static str = "vested_transfer";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13503,6 +14475,10 @@ pub mod api {
                     ::core::primitive::u32,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceVestedTransfer {
+                const PALLET: &'static str = "Vesting";
+                const CALL: &'static str = "force_vested_transfer";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13512,6 +14488,10 @@ pub mod api {
                 pub schedule1_index: ::core::primitive::u32,
                 pub schedule2_index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for MergeSchedules {
+                const PALLET: &'static str = "Vesting";
+                const CALL: &'static str = "merge_schedules";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Unlock any vested funds of the sender account."]
@@ -13759,6 +14739,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Vesting",
@@ -13790,6 +14771,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Vesting",
@@ -13815,6 +14797,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Vesting",
@@ -13886,6 +14869,10 @@ pub mod api {
             pub struct Batch {
                 pub calls: ::std::vec::Vec<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Batch {
+                const PALLET: &'static str = "Utility";
+                const CALL: &'static str = "batch";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13895,6 +14882,10 @@ pub mod api {
                 pub index: ::core::primitive::u16,
                 pub call: ::std::boxed::Box<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AsDerivative {
+                const PALLET: &'static str = "Utility";
+                const CALL: &'static str = "as_derivative";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13903,6 +14894,10 @@ pub mod api {
             pub struct BatchAll {
                 pub calls: ::std::vec::Vec<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for BatchAll {
+                const PALLET: &'static str = "Utility";
+                const CALL: &'static str = "batch_all";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13913,6 +14908,10 @@ pub mod api {
                     ::std::boxed::Box<runtime_types::polkadot_runtime::OriginCaller>,
                 pub call: ::std::boxed::Box<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for DispatchAs {
+                const PALLET: &'static str = "Utility";
+                const CALL: &'static str = "dispatch_as";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -13921,6 +14920,10 @@ pub mod api {
             pub struct ForceBatch {
                 pub calls: ::std::vec::Vec<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceBatch {
+                const PALLET: &'static str = "Utility";
+                const CALL: &'static str = "force_batch";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Send a batch of dispatch calls."]
@@ -14203,6 +15206,10 @@ pub mod api {
             pub struct AddRegistrar {
                 pub account: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AddRegistrar {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "add_registrar";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14213,6 +15220,10 @@ pub mod api {
                     runtime_types::pallet_identity::types::IdentityInfo,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetIdentity {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "set_identity";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14224,12 +15235,20 @@ pub mod api {
                     runtime_types::pallet_identity::types::Data,
                 )>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetSubs {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "set_subs";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct ClearIdentity;
+            impl ::subxt::blocks::StaticExtrinsic for ClearIdentity {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "clear_identity";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14241,6 +15260,10 @@ pub mod api {
                 #[codec(compact)]
                 pub max_fee: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RequestJudgement {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "request_judgement";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -14250,6 +15273,10 @@ pub mod api {
             pub struct CancelRequest {
                 pub reg_index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CancelRequest {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "cancel_request";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14261,6 +15288,10 @@ pub mod api {
                 #[codec(compact)]
                 pub fee: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetFee {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "set_fee";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14271,6 +15302,10 @@ pub mod api {
                 pub index: ::core::primitive::u32,
                 pub new: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetAccountId {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "set_account_id";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14283,6 +15318,10 @@ pub mod api {
                     runtime_types::pallet_identity::types::IdentityField,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetFields {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "set_fields";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14299,6 +15338,10 @@ pub mod api {
                     ::core::primitive::u128,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ProvideJudgement {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "provide_judgement";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14310,6 +15353,10 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for KillIdentity {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "kill_identity";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14322,6 +15369,10 @@ pub mod api {
                 >,
                 pub data: runtime_types::pallet_identity::types::Data,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AddSub {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "add_sub";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14334,6 +15385,10 @@ pub mod api {
                 >,
                 pub data: runtime_types::pallet_identity::types::Data,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RenameSub {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "rename_sub";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -14345,12 +15400,20 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveSub {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "remove_sub";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct QuitSub;
+            impl ::subxt::blocks::StaticExtrinsic for QuitSub {
+                const PALLET: &'static str = "Identity";
+                const CALL: &'static str = "quit_sub";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Add a registrar to the system."]
@@ -15010,6 +16073,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Identity",
@@ -15040,6 +16104,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Identity",
@@ -15066,6 +16131,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Identity",
@@ -15094,6 +16160,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Identity",
@@ -15125,6 +16192,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Identity",
@@ -15158,6 +16226,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Identity",
@@ -15191,6 +16260,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Identity",
@@ -15338,6 +16408,10 @@ pub mod api {
                     ::core::option::Option<runtime_types::polkadot_runtime::ProxyType>,
                 pub call: ::std::boxed::Box<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Proxy {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "proxy";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15348,6 +16422,10 @@ pub mod api {
                 pub proxy_type: runtime_types::polkadot_runtime::ProxyType,
                 pub delay: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AddProxy {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "add_proxy";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15358,12 +16436,20 @@ pub mod api {
                 pub proxy_type: runtime_types::polkadot_runtime::ProxyType,
                 pub delay: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveProxy {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "remove_proxy";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct RemoveProxies;
+            impl ::subxt::blocks::StaticExtrinsic for RemoveProxies {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "remove_proxies";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15374,6 +16460,10 @@ pub mod api {
                 pub delay: ::core::primitive::u32,
                 pub index: ::core::primitive::u16,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Anonymous {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "anonymous";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15388,6 +16478,10 @@ pub mod api {
                 #[codec(compact)]
                 pub ext_index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for KillAnonymous {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "kill_anonymous";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15397,6 +16491,10 @@ pub mod api {
                 pub real: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub call_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Announce {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "announce";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15406,6 +16504,10 @@ pub mod api {
                 pub real: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub call_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RemoveAnnouncement {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "remove_announcement";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15415,6 +16517,10 @@ pub mod api {
                 pub delegate: ::subxt::ext::sp_core::crypto::AccountId32,
                 pub call_hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RejectAnnouncement {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "reject_announcement";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -15427,6 +16533,10 @@ pub mod api {
                     ::core::option::Option<runtime_types::polkadot_runtime::ProxyType>,
                 pub call: ::std::boxed::Box<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ProxyAnnounced {
+                const PALLET: &'static str = "Proxy";
+                const CALL: &'static str = "proxy_announced";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Dispatch the given `call` from an account that the sender is authorised for through"]
@@ -15910,6 +17020,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Proxy",
@@ -15944,6 +17055,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Proxy",
@@ -15975,6 +17087,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Proxy",
@@ -16008,6 +17121,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Proxy",
@@ -16163,6 +17277,10 @@ pub mod api {
                     ::std::vec::Vec<::subxt::ext::sp_core::crypto::AccountId32>,
                 pub call: ::std::boxed::Box<runtime_types::polkadot_runtime::Call>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AsMultiThreshold1 {
+                const PALLET: &'static str = "Multisig";
+                const CALL: &'static str = "as_multi_threshold_1";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16181,6 +17299,10 @@ pub mod api {
                 pub store_call: ::core::primitive::bool,
                 pub max_weight: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AsMulti {
+                const PALLET: &'static str = "Multisig";
+                const CALL: &'static str = "as_multi";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16196,6 +17318,10 @@ pub mod api {
                 pub call_hash: [::core::primitive::u8; 32usize],
                 pub max_weight: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ApproveAsMulti {
+                const PALLET: &'static str = "Multisig";
+                const CALL: &'static str = "approve_as_multi";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16209,6 +17335,10 @@ pub mod api {
                     runtime_types::pallet_multisig::Timepoint<::core::primitive::u32>,
                 pub call_hash: [::core::primitive::u8; 32usize],
             }
+            impl ::subxt::blocks::StaticExtrinsic for CancelAsMulti {
+                const PALLET: &'static str = "Multisig";
+                const CALL: &'static str = "cancel_as_multi";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Immediately dispatch a multi-signature call using a single approval from the caller."]
@@ -16542,6 +17672,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32, [::core::primitive::u8; 32usize]),
                 > {
                     :: subxt :: storage :: address :: StaticStorageAddress :: new ("Multisig" , "Multisigs" , vec ! [:: subxt :: storage :: address :: StorageMapKey :: new (_0 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Twox64Concat) , :: subxt :: storage :: address :: StorageMapKey :: new (_1 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Blake2_128Concat)] , [145u8 , 78u8 , 57u8 , 171u8 , 199u8 , 158u8 , 226u8 , 250u8 , 224u8 , 133u8 , 45u8 , 251u8 , 202u8 , 22u8 , 171u8 , 132u8 , 229u8 , 110u8 , 248u8 , 233u8 , 38u8 , 2u8 , 247u8 , 140u8 , 150u8 , 103u8 , 211u8 , 209u8 , 160u8 , 158u8 , 23u8 , 215u8 ,])
                 }
@@ -16559,6 +17690,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32, [::core::primitive::u8; 32usize]),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Multisig",
@@ -16572,6 +17704,38 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " The set of open multisig operations."]
+                pub fn multisigs_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::subxt::ext::sp_core::crypto::AccountId32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<
+                        runtime_types::pallet_multisig::Multisig<
+                            ::core::primitive::u32,
+                            ::core::primitive::u128,
+                            ::subxt::ext::sp_core::crypto::AccountId32,
+                        >,
+                    >,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32, [::core::primitive::u8; 32usize]),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "Multisig",
+                        "Multisigs",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            145u8, 78u8, 57u8, 171u8, 199u8, 158u8, 226u8, 250u8, 224u8,
+                            133u8, 45u8, 251u8, 202u8, 22u8, 171u8, 132u8, 229u8, 110u8,
+                            248u8, 233u8, 38u8, 2u8, 247u8, 140u8, 150u8, 103u8, 211u8,
+                            209u8, 160u8, 158u8, 23u8, 215u8,
+                        ],
+                    )
+                }
                 pub fn calls(
                     &self,
                     _0: impl ::std::borrow::Borrow<[::core::primitive::u8; 32usize]>,
@@ -16586,6 +17750,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    ([::core::primitive::u8; 32usize],),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Multisig",
@@ -16615,6 +17780,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    ([::core::primitive::u8; 32usize],),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Multisig",
@@ -16713,6 +17879,10 @@ pub mod api {
                 pub value: ::core::primitive::u128,
                 pub description: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ProposeBounty {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "propose_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16722,6 +17892,10 @@ pub mod api {
                 #[codec(compact)]
                 pub bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ApproveBounty {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "approve_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16737,6 +17911,10 @@ pub mod api {
                 #[codec(compact)]
                 pub fee: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ProposeCurator {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "propose_curator";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16746,6 +17924,10 @@ pub mod api {
                 #[codec(compact)]
                 pub bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for UnassignCurator {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "unassign_curator";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16755,6 +17937,10 @@ pub mod api {
                 #[codec(compact)]
                 pub bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AcceptCurator {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "accept_curator";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16768,6 +17954,10 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AwardBounty {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "award_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16777,6 +17967,10 @@ pub mod api {
                 #[codec(compact)]
                 pub bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ClaimBounty {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "claim_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16786,6 +17980,10 @@ pub mod api {
                 #[codec(compact)]
                 pub bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CloseBounty {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "close_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -16796,6 +17994,10 @@ pub mod api {
                 pub bounty_id: ::core::primitive::u32,
                 pub remark: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ExtendBountyExpiry {
+                const PALLET: &'static str = "Bounties";
+                const CALL: &'static str = "extend_bounty_expiry";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Propose a new bounty."]
@@ -17171,6 +18373,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Bounties",
@@ -17199,6 +18402,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Bounties",
@@ -17229,6 +18433,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Bounties",
@@ -17255,6 +18460,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Bounties",
@@ -17283,6 +18489,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Bounties",
@@ -17308,6 +18515,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Bounties",
@@ -17514,6 +18722,10 @@ pub mod api {
                 pub value: ::core::primitive::u128,
                 pub description: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AddChildBounty {
+                const PALLET: &'static str = "ChildBounties";
+                const CALL: &'static str = "add_child_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -17531,6 +18743,10 @@ pub mod api {
                 #[codec(compact)]
                 pub fee: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ProposeCurator {
+                const PALLET: &'static str = "ChildBounties";
+                const CALL: &'static str = "propose_curator";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -17542,6 +18758,10 @@ pub mod api {
                 #[codec(compact)]
                 pub child_bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AcceptCurator {
+                const PALLET: &'static str = "ChildBounties";
+                const CALL: &'static str = "accept_curator";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -17553,6 +18773,10 @@ pub mod api {
                 #[codec(compact)]
                 pub child_bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for UnassignCurator {
+                const PALLET: &'static str = "ChildBounties";
+                const CALL: &'static str = "unassign_curator";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -17568,6 +18792,10 @@ pub mod api {
                     (),
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AwardChildBounty {
+                const PALLET: &'static str = "ChildBounties";
+                const CALL: &'static str = "award_child_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -17579,6 +18807,10 @@ pub mod api {
                 #[codec(compact)]
                 pub child_bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ClaimChildBounty {
+                const PALLET: &'static str = "ChildBounties";
+                const CALL: &'static str = "claim_child_bounty";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -17590,6 +18822,10 @@ pub mod api {
                 #[codec(compact)]
                 pub child_bounty_id: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CloseChildBounty {
+                const PALLET: &'static str = "ChildBounties";
+                const CALL: &'static str = "close_child_bounty";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Add a new child-bounty."]
@@ -17966,6 +19202,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -17989,6 +19226,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18014,6 +19252,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18043,6 +19282,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18079,6 +19319,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18092,6 +19333,38 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " Child bounties that have been added."]
+                pub fn child_bounties_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<
+                        runtime_types::pallet_child_bounties::ChildBounty<
+                            ::subxt::ext::sp_core::crypto::AccountId32,
+                            ::core::primitive::u128,
+                            ::core::primitive::u32,
+                        >,
+                    >,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, ::core::primitive::u32),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "ChildBounties",
+                        "ChildBounties",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            66u8, 132u8, 251u8, 223u8, 216u8, 52u8, 162u8, 150u8, 229u8,
+                            239u8, 219u8, 182u8, 211u8, 228u8, 181u8, 46u8, 243u8, 151u8,
+                            111u8, 235u8, 105u8, 40u8, 39u8, 10u8, 245u8, 113u8, 78u8,
+                            116u8, 219u8, 186u8, 165u8, 91u8,
+                        ],
+                    )
+                }
                 #[doc = " The description of each child-bounty."]
                 pub fn child_bounty_descriptions(
                     &self,
@@ -18105,6 +19378,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18133,6 +19407,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18155,6 +19430,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18179,6 +19455,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ChildBounties",
@@ -18252,6 +19529,10 @@ pub mod api {
                 pub reason: ::std::vec::Vec<::core::primitive::u8>,
                 pub who: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReportAwesome {
+                const PALLET: &'static str = "Tips";
+                const CALL: &'static str = "report_awesome";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18260,6 +19541,10 @@ pub mod api {
             pub struct RetractTip {
                 pub hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for RetractTip {
+                const PALLET: &'static str = "Tips";
+                const CALL: &'static str = "retract_tip";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18271,6 +19556,10 @@ pub mod api {
                 #[codec(compact)]
                 pub tip_value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for TipNew {
+                const PALLET: &'static str = "Tips";
+                const CALL: &'static str = "tip_new";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18281,6 +19570,10 @@ pub mod api {
                 #[codec(compact)]
                 pub tip_value: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Tip {
+                const PALLET: &'static str = "Tips";
+                const CALL: &'static str = "tip";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18289,6 +19582,10 @@ pub mod api {
             pub struct CloseTip {
                 pub hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for CloseTip {
+                const PALLET: &'static str = "Tips";
+                const CALL: &'static str = "close_tip";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18297,6 +19594,10 @@ pub mod api {
             pub struct SlashTip {
                 pub hash: ::subxt::ext::sp_core::H256,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SlashTip {
+                const PALLET: &'static str = "Tips";
+                const CALL: &'static str = "slash_tip";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Report something `reason` that deserves a tip and claim any eventual the finder's fee."]
@@ -18613,6 +19914,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Tips",
@@ -18646,6 +19948,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Tips",
@@ -18671,6 +19974,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Tips",
@@ -18698,6 +20002,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Tips",
@@ -18823,6 +20128,10 @@ pub mod api {
                 Debug,
             )]
             pub struct SubmitUnsigned { pub raw_solution : :: std :: boxed :: Box < runtime_types :: pallet_election_provider_multi_phase :: RawSolution < runtime_types :: polkadot_runtime :: NposCompactSolution16 > > , pub witness : runtime_types :: pallet_election_provider_multi_phase :: SolutionOrSnapshotSize , }
+            impl ::subxt::blocks::StaticExtrinsic for SubmitUnsigned {
+                const PALLET: &'static str = "ElectionProviderMultiPhase";
+                const CALL: &'static str = "submit_unsigned";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18833,6 +20142,10 @@ pub mod api {
                     runtime_types::sp_npos_elections::ElectionScore,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMinimumUntrustedScore {
+                const PALLET: &'static str = "ElectionProviderMultiPhase";
+                const CALL: &'static str = "set_minimum_untrusted_score";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18846,6 +20159,10 @@ pub mod api {
                     >,
                 )>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetEmergencyElectionResult {
+                const PALLET: &'static str = "ElectionProviderMultiPhase";
+                const CALL: &'static str = "set_emergency_election_result";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18858,6 +20175,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Submit {
+                const PALLET: &'static str = "ElectionProviderMultiPhase";
+                const CALL: &'static str = "submit";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -18867,6 +20188,10 @@ pub mod api {
                 pub maybe_max_voters: ::core::option::Option<::core::primitive::u32>,
                 pub maybe_max_targets: ::core::option::Option<::core::primitive::u32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for GovernanceFallback {
+                const PALLET: &'static str = "ElectionProviderMultiPhase";
+                const CALL: &'static str = "governance_fallback";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Submit a solution for the unsigned phase."]
@@ -19125,6 +20450,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
@@ -19150,6 +20476,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
@@ -19163,7 +20490,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " Current best solution, signed or unsigned, queued to be returned upon `elect`."]                pub fn queued_solution (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: ReadySolution < :: subxt :: ext :: sp_core :: crypto :: AccountId32 > > , :: subxt :: storage :: address :: Yes , () , () >{
+                #[doc = " Current best solution, signed or unsigned, queued to be returned upon `elect`."]                pub fn queued_solution (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: ReadySolution < :: subxt :: ext :: sp_core :: crypto :: AccountId32 > > , :: subxt :: storage :: address :: Yes , () , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
                         "QueuedSolution",
@@ -19178,7 +20505,7 @@ pub mod api {
                 }
                 #[doc = " Snapshot data of the round."]
                 #[doc = ""]
-                #[doc = " This is created at the beginning of the signed phase and cleared upon calling `elect`."]                pub fn snapshot (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: RoundSnapshot > , :: subxt :: storage :: address :: Yes , () , () >{
+                #[doc = " This is created at the beginning of the signed phase and cleared upon calling `elect`."]                pub fn snapshot (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: RoundSnapshot > , :: subxt :: storage :: address :: Yes , () , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
                         "Snapshot",
@@ -19201,6 +20528,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
@@ -19216,7 +20544,7 @@ pub mod api {
                 }
                 #[doc = " The metadata of the [`RoundSnapshot`]"]
                 #[doc = ""]
-                #[doc = " Only exists when [`Snapshot`] is present."]                pub fn snapshot_metadata (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: SolutionOrSnapshotSize > , :: subxt :: storage :: address :: Yes , () , () >{
+                #[doc = " Only exists when [`Snapshot`] is present."]                pub fn snapshot_metadata (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: SolutionOrSnapshotSize > , :: subxt :: storage :: address :: Yes , () , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
                         "SnapshotMetadata",
@@ -19245,6 +20573,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
@@ -19263,7 +20592,7 @@ pub mod api {
                 #[doc = ""]
                 #[doc = " We never need to process more than a single signed submission at a time. Signed submissions"]
                 #[doc = " can be quite large, so we're willing to pay the cost of multiple database accesses to access"]
-                #[doc = " them one at a time instead of reading and decoding all of them at once."]                pub fn signed_submission_indices (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: bounded_btree_map :: BoundedBTreeMap < runtime_types :: sp_npos_elections :: ElectionScore , :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " them one at a time instead of reading and decoding all of them at once."]                pub fn signed_submission_indices (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: sp_runtime :: bounded :: bounded_btree_map :: BoundedBTreeMap < runtime_types :: sp_npos_elections :: ElectionScore , :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
                         "SignedSubmissionIndices",
@@ -19282,7 +20611,7 @@ pub mod api {
                 #[doc = " allowing us to keep only a single one in memory at a time."]
                 #[doc = ""]
                 #[doc = " Twox note: the key of the map is an auto-incrementing index which users cannot inspect or"]
-                #[doc = " affect; we shouldn't need a cryptographically secure hasher."]                pub fn signed_submissions_map (& self , _0 : impl :: std :: borrow :: Borrow < :: core :: primitive :: u32 > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: signed :: SignedSubmission < :: subxt :: ext :: sp_core :: crypto :: AccountId32 , :: core :: primitive :: u128 , runtime_types :: polkadot_runtime :: NposCompactSolution16 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " affect; we shouldn't need a cryptographically secure hasher."]                pub fn signed_submissions_map (& self , _0 : impl :: std :: borrow :: Borrow < :: core :: primitive :: u32 > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: signed :: SignedSubmission < :: subxt :: ext :: sp_core :: crypto :: AccountId32 , :: core :: primitive :: u128 , runtime_types :: polkadot_runtime :: NposCompactSolution16 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes , (:: core :: primitive :: u32 ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
                         "SignedSubmissionsMap",
@@ -19304,7 +20633,7 @@ pub mod api {
                 #[doc = " allowing us to keep only a single one in memory at a time."]
                 #[doc = ""]
                 #[doc = " Twox note: the key of the map is an auto-incrementing index which users cannot inspect or"]
-                #[doc = " affect; we shouldn't need a cryptographically secure hasher."]                pub fn signed_submissions_map_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: signed :: SignedSubmission < :: subxt :: ext :: sp_core :: crypto :: AccountId32 , :: core :: primitive :: u128 , runtime_types :: polkadot_runtime :: NposCompactSolution16 > > , () , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " affect; we shouldn't need a cryptographically secure hasher."]                pub fn signed_submissions_map_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: pallet_election_provider_multi_phase :: signed :: SignedSubmission < :: subxt :: ext :: sp_core :: crypto :: AccountId32 , :: core :: primitive :: u128 , runtime_types :: polkadot_runtime :: NposCompactSolution16 > > , () , () , :: subxt :: storage :: address :: Yes , (:: core :: primitive :: u32 ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
                         "SignedSubmissionsMap",
@@ -19330,6 +20659,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ElectionProviderMultiPhase",
@@ -19644,6 +20974,10 @@ pub mod api {
             pub struct Rebag {
                 pub dislocated: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Rebag {
+                const PALLET: &'static str = "VoterList";
+                const CALL: &'static str = "rebag";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -19652,6 +20986,10 @@ pub mod api {
             pub struct PutInFrontOf {
                 pub lighter: ::subxt::ext::sp_core::crypto::AccountId32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for PutInFrontOf {
+                const PALLET: &'static str = "VoterList";
+                const CALL: &'static str = "put_in_front_of";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Declare that some `dislocated` account has, through rewards or penalties, sufficiently"]
@@ -19757,6 +21095,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "VoterList",
@@ -19785,6 +21124,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::crypto::AccountId32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "VoterList",
@@ -19806,6 +21146,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "VoterList",
@@ -19832,6 +21173,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "VoterList",
@@ -19860,6 +21202,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "VoterList",
@@ -19964,6 +21307,10 @@ pub mod api {
             pub struct SetValidationUpgradeCooldown {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetValidationUpgradeCooldown {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_validation_upgrade_cooldown";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -19973,6 +21320,10 @@ pub mod api {
             pub struct SetValidationUpgradeDelay {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetValidationUpgradeDelay {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_validation_upgrade_delay";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -19982,6 +21333,10 @@ pub mod api {
             pub struct SetCodeRetentionPeriod {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetCodeRetentionPeriod {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_code_retention_period";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -19991,6 +21346,10 @@ pub mod api {
             pub struct SetMaxCodeSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxCodeSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_code_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20000,6 +21359,10 @@ pub mod api {
             pub struct SetMaxPovSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxPovSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_pov_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20009,6 +21372,10 @@ pub mod api {
             pub struct SetMaxHeadDataSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxHeadDataSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_head_data_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20018,6 +21385,10 @@ pub mod api {
             pub struct SetParathreadCores {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetParathreadCores {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_parathread_cores";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20027,6 +21398,10 @@ pub mod api {
             pub struct SetParathreadRetries {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetParathreadRetries {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_parathread_retries";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20036,6 +21411,10 @@ pub mod api {
             pub struct SetGroupRotationFrequency {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetGroupRotationFrequency {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_group_rotation_frequency";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20045,6 +21424,10 @@ pub mod api {
             pub struct SetChainAvailabilityPeriod {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetChainAvailabilityPeriod {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_chain_availability_period";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20054,6 +21437,10 @@ pub mod api {
             pub struct SetThreadAvailabilityPeriod {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetThreadAvailabilityPeriod {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_thread_availability_period";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20063,6 +21450,10 @@ pub mod api {
             pub struct SetSchedulingLookahead {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetSchedulingLookahead {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_scheduling_lookahead";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -20071,6 +21462,10 @@ pub mod api {
             pub struct SetMaxValidatorsPerCore {
                 pub new: ::core::option::Option<::core::primitive::u32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxValidatorsPerCore {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_validators_per_core";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -20079,6 +21474,10 @@ pub mod api {
             pub struct SetMaxValidators {
                 pub new: ::core::option::Option<::core::primitive::u32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxValidators {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_validators";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20088,6 +21487,10 @@ pub mod api {
             pub struct SetDisputePeriod {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetDisputePeriod {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_dispute_period";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20097,6 +21500,10 @@ pub mod api {
             pub struct SetDisputePostConclusionAcceptancePeriod {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetDisputePostConclusionAcceptancePeriod {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_dispute_post_conclusion_acceptance_period";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20106,6 +21513,10 @@ pub mod api {
             pub struct SetDisputeMaxSpamSlots {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetDisputeMaxSpamSlots {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_dispute_max_spam_slots";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20115,6 +21526,10 @@ pub mod api {
             pub struct SetDisputeConclusionByTimeOutPeriod {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetDisputeConclusionByTimeOutPeriod {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_dispute_conclusion_by_time_out_period";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20124,6 +21539,10 @@ pub mod api {
             pub struct SetNoShowSlots {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetNoShowSlots {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_no_show_slots";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20133,6 +21552,10 @@ pub mod api {
             pub struct SetNDelayTranches {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetNDelayTranches {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_n_delay_tranches";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20142,6 +21565,10 @@ pub mod api {
             pub struct SetZerothDelayTrancheWidth {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetZerothDelayTrancheWidth {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_zeroth_delay_tranche_width";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20151,6 +21578,10 @@ pub mod api {
             pub struct SetNeededApprovals {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetNeededApprovals {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_needed_approvals";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20160,6 +21591,10 @@ pub mod api {
             pub struct SetRelayVrfModuloSamples {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetRelayVrfModuloSamples {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_relay_vrf_modulo_samples";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20169,6 +21604,10 @@ pub mod api {
             pub struct SetMaxUpwardQueueCount {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxUpwardQueueCount {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_upward_queue_count";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20178,6 +21617,10 @@ pub mod api {
             pub struct SetMaxUpwardQueueSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxUpwardQueueSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_upward_queue_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20187,6 +21630,10 @@ pub mod api {
             pub struct SetMaxDownwardMessageSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxDownwardMessageSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_downward_message_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20196,6 +21643,10 @@ pub mod api {
             pub struct SetUmpServiceTotalWeight {
                 pub new: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetUmpServiceTotalWeight {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_ump_service_total_weight";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20205,6 +21656,10 @@ pub mod api {
             pub struct SetMaxUpwardMessageSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxUpwardMessageSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_upward_message_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20214,6 +21669,10 @@ pub mod api {
             pub struct SetMaxUpwardMessageNumPerCandidate {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMaxUpwardMessageNumPerCandidate {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_max_upward_message_num_per_candidate";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20223,6 +21682,10 @@ pub mod api {
             pub struct SetHrmpOpenRequestTtl {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpOpenRequestTtl {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_open_request_ttl";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20232,6 +21695,10 @@ pub mod api {
             pub struct SetHrmpSenderDeposit {
                 pub new: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpSenderDeposit {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_sender_deposit";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20241,6 +21708,10 @@ pub mod api {
             pub struct SetHrmpRecipientDeposit {
                 pub new: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpRecipientDeposit {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_recipient_deposit";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20250,6 +21721,10 @@ pub mod api {
             pub struct SetHrmpChannelMaxCapacity {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpChannelMaxCapacity {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_channel_max_capacity";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20259,6 +21734,10 @@ pub mod api {
             pub struct SetHrmpChannelMaxTotalSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpChannelMaxTotalSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_channel_max_total_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20268,6 +21747,10 @@ pub mod api {
             pub struct SetHrmpMaxParachainInboundChannels {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpMaxParachainInboundChannels {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_max_parachain_inbound_channels";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20277,6 +21760,10 @@ pub mod api {
             pub struct SetHrmpMaxParathreadInboundChannels {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpMaxParathreadInboundChannels {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_max_parathread_inbound_channels";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20286,6 +21773,10 @@ pub mod api {
             pub struct SetHrmpChannelMaxMessageSize {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpChannelMaxMessageSize {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_channel_max_message_size";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20295,6 +21786,10 @@ pub mod api {
             pub struct SetHrmpMaxParachainOutboundChannels {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpMaxParachainOutboundChannels {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_max_parachain_outbound_channels";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20304,6 +21799,10 @@ pub mod api {
             pub struct SetHrmpMaxParathreadOutboundChannels {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpMaxParathreadOutboundChannels {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_max_parathread_outbound_channels";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20313,6 +21812,10 @@ pub mod api {
             pub struct SetHrmpMaxMessageNumPerCandidate {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetHrmpMaxMessageNumPerCandidate {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_hrmp_max_message_num_per_candidate";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20322,6 +21825,10 @@ pub mod api {
             pub struct SetUmpMaxIndividualWeight {
                 pub new: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetUmpMaxIndividualWeight {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_ump_max_individual_weight";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -20330,6 +21837,10 @@ pub mod api {
             pub struct SetPvfCheckingEnabled {
                 pub new: ::core::primitive::bool,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetPvfCheckingEnabled {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_pvf_checking_enabled";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20339,6 +21850,10 @@ pub mod api {
             pub struct SetPvfVotingTtl {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetPvfVotingTtl {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_pvf_voting_ttl";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -20348,6 +21863,10 @@ pub mod api {
             pub struct SetMinimumValidationUpgradeDelay {
                 pub new: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetMinimumValidationUpgradeDelay {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_minimum_validation_upgrade_delay";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -20356,6 +21875,10 @@ pub mod api {
             pub struct SetBypassConsistencyCheck {
                 pub new: ::core::primitive::bool,
             }
+            impl ::subxt::blocks::StaticExtrinsic for SetBypassConsistencyCheck {
+                const PALLET: &'static str = "Configuration";
+                const CALL: &'static str = "set_bypass_consistency_check";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Set the validation upgrade cooldown."]
@@ -21165,7 +22688,7 @@ pub mod api {
             use super::runtime_types;
             pub struct StorageApi;
             impl StorageApi {
-                #[doc = " The active configuration for the current session."]                pub fn active_config (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: configuration :: HostConfiguration < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " The active configuration for the current session."]                pub fn active_config (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: configuration :: HostConfiguration < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Configuration",
                         "ActiveConfig",
@@ -21184,7 +22707,7 @@ pub mod api {
                 #[doc = " be applied."]
                 #[doc = ""]
                 #[doc = " The list is sorted ascending by session index. Also, this list can only contain at most"]
-                #[doc = " 2 items: for the next session and for the `scheduled_session`."]                pub fn pending_configs (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < (:: core :: primitive :: u32 , runtime_types :: polkadot_runtime_parachains :: configuration :: HostConfiguration < :: core :: primitive :: u32 > ,) > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " 2 items: for the next session and for the `scheduled_session`."]                pub fn pending_configs (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < (:: core :: primitive :: u32 , runtime_types :: polkadot_runtime_parachains :: configuration :: HostConfiguration < :: core :: primitive :: u32 > ,) > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Configuration",
                         "PendingConfigs",
@@ -21206,6 +22729,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Configuration",
@@ -21245,6 +22769,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasShared",
@@ -21271,6 +22796,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasShared",
@@ -21297,6 +22823,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasShared",
@@ -21387,7 +22914,7 @@ pub mod api {
             use super::runtime_types;
             pub struct StorageApi;
             impl StorageApi {
-                #[doc = " The latest bitfield for each validator, referred to by their index in the validator set."]                pub fn availability_bitfields (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_primitives :: v2 :: ValidatorIndex > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: AvailabilityBitfieldRecord < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " The latest bitfield for each validator, referred to by their index in the validator set."]                pub fn availability_bitfields (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_primitives :: v2 :: ValidatorIndex > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: AvailabilityBitfieldRecord < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_primitives :: v2 :: ValidatorIndex ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInclusion",
                         "AvailabilityBitfields",
@@ -21403,7 +22930,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " The latest bitfield for each validator, referred to by their index in the validator set."]                pub fn availability_bitfields_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: AvailabilityBitfieldRecord < :: core :: primitive :: u32 > > , () , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " The latest bitfield for each validator, referred to by their index in the validator set."]                pub fn availability_bitfields_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: AvailabilityBitfieldRecord < :: core :: primitive :: u32 > > , () , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_primitives :: v2 :: ValidatorIndex ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInclusion",
                         "AvailabilityBitfields",
@@ -21416,7 +22943,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " Candidates pending availability by `ParaId`."]                pub fn pending_availability (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: CandidatePendingAvailability < :: subxt :: ext :: sp_core :: H256 , :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " Candidates pending availability by `ParaId`."]                pub fn pending_availability (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: CandidatePendingAvailability < :: subxt :: ext :: sp_core :: H256 , :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInclusion",
                         "PendingAvailability",
@@ -21432,7 +22959,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " Candidates pending availability by `ParaId`."]                pub fn pending_availability_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: CandidatePendingAvailability < :: subxt :: ext :: sp_core :: H256 , :: core :: primitive :: u32 > > , () , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " Candidates pending availability by `ParaId`."]                pub fn pending_availability_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: inclusion :: CandidatePendingAvailability < :: subxt :: ext :: sp_core :: H256 , :: core :: primitive :: u32 > > , () , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInclusion",
                         "PendingAvailability",
@@ -21460,6 +22987,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInclusion",
@@ -21488,6 +23016,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInclusion",
@@ -21525,6 +23054,10 @@ pub mod api {
                     >,
                 >,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Enter {
+                const PALLET: &'static str = "ParaInherent";
+                const CALL: &'static str = "enter";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Enter the paras inherent. This will process bitfields and backed candidates."]
@@ -21568,6 +23101,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInherent",
@@ -21593,6 +23127,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaInherent",
@@ -21635,6 +23170,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaScheduler",
@@ -21651,7 +23187,7 @@ pub mod api {
                 #[doc = " A queue of upcoming claims and which core they should be mapped onto."]
                 #[doc = ""]
                 #[doc = " The number of queued claims is bounded at the `scheduling_lookahead`"]
-                #[doc = " multiplied by the number of parathread multiplexer cores. Reasonably, 10 * 50 = 500."]                pub fn parathread_queue (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: scheduler :: ParathreadClaimQueue > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " multiplied by the number of parathread multiplexer cores. Reasonably, 10 * 50 = 500."]                pub fn parathread_queue (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: scheduler :: ParathreadClaimQueue > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaScheduler",
                         "ParathreadQueue",
@@ -21685,6 +23221,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaScheduler",
@@ -21713,6 +23250,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaScheduler",
@@ -21739,6 +23277,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaScheduler",
@@ -21757,7 +23296,7 @@ pub mod api {
                 #[doc = " Bounded by the number of cores: one for each parachain and parathread multiplexer."]
                 #[doc = ""]
                 #[doc = " The value contained here will not be valid after the end of a block. Runtime APIs should be used to determine scheduled cores/"]
-                #[doc = " for the upcoming block."]                pub fn scheduled (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_runtime_parachains :: scheduler :: CoreAssignment > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " for the upcoming block."]                pub fn scheduled (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_runtime_parachains :: scheduler :: CoreAssignment > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaScheduler",
                         "Scheduled",
@@ -21791,6 +23330,10 @@ pub mod api {
                 pub new_code:
                     runtime_types::polkadot_parachain::primitives::ValidationCode,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceSetCurrentCode {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "force_set_current_code";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -21800,6 +23343,10 @@ pub mod api {
                 pub para: runtime_types::polkadot_parachain::primitives::Id,
                 pub new_head: runtime_types::polkadot_parachain::primitives::HeadData,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceSetCurrentHead {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "force_set_current_head";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -21811,6 +23358,10 @@ pub mod api {
                     runtime_types::polkadot_parachain::primitives::ValidationCode,
                 pub relay_parent_number: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceScheduleCodeUpgrade {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "force_schedule_code_upgrade";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -21820,6 +23371,10 @@ pub mod api {
                 pub para: runtime_types::polkadot_parachain::primitives::Id,
                 pub new_head: runtime_types::polkadot_parachain::primitives::HeadData,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceNoteNewHead {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "force_note_new_head";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -21828,6 +23383,10 @@ pub mod api {
             pub struct ForceQueueAction {
                 pub para: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceQueueAction {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "force_queue_action";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -21837,6 +23396,10 @@ pub mod api {
                 pub validation_code:
                     runtime_types::polkadot_parachain::primitives::ValidationCode,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AddTrustedValidationCode {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "add_trusted_validation_code";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -21846,6 +23409,10 @@ pub mod api {
                 pub validation_code_hash:
                     runtime_types::polkadot_parachain::primitives::ValidationCodeHash,
             }
+            impl ::subxt::blocks::StaticExtrinsic for PokeUnusedValidationCode {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "poke_unused_validation_code";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -21856,6 +23423,10 @@ pub mod api {
                 pub signature:
                     runtime_types::polkadot_primitives::v2::validator_app::Signature,
             }
+            impl ::subxt::blocks::StaticExtrinsic for IncludePvfCheckStatement {
+                const PALLET: &'static str = "Paras";
+                const CALL: &'static str = "include_pvf_check_statement";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Set the storage for the parachain validation code immediately."]
@@ -22154,7 +23725,7 @@ pub mod api {
                 #[doc = " All currently active PVF pre-checking votes."]
                 #[doc = ""]
                 #[doc = " Invariant:"]
-                #[doc = " - There are no PVF pre-checking votes that exists in list but not in the set and vice versa."]                pub fn pvf_active_vote_map (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: ValidationCodeHash > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: PvfCheckActiveVoteState < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " - There are no PVF pre-checking votes that exists in list but not in the set and vice versa."]                pub fn pvf_active_vote_map (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: ValidationCodeHash > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: PvfCheckActiveVoteState < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: ValidationCodeHash ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
                         "PvfActiveVoteMap",
@@ -22173,7 +23744,7 @@ pub mod api {
                 #[doc = " All currently active PVF pre-checking votes."]
                 #[doc = ""]
                 #[doc = " Invariant:"]
-                #[doc = " - There are no PVF pre-checking votes that exists in list but not in the set and vice versa."]                pub fn pvf_active_vote_map_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: PvfCheckActiveVoteState < :: core :: primitive :: u32 > > , () , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " - There are no PVF pre-checking votes that exists in list but not in the set and vice versa."]                pub fn pvf_active_vote_map_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: PvfCheckActiveVoteState < :: core :: primitive :: u32 > > , () , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: ValidationCodeHash ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
                         "PvfActiveVoteMap",
@@ -22186,7 +23757,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " The list of all currently active PVF votes. Auxiliary to `PvfActiveVoteMap`."]                pub fn pvf_active_vote_list (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_parachain :: primitives :: ValidationCodeHash > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " The list of all currently active PVF votes. Auxiliary to `PvfActiveVoteMap`."]                pub fn pvf_active_vote_list (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_parachain :: primitives :: ValidationCodeHash > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
                         "PvfActiveVoteList",
@@ -22213,6 +23784,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22239,6 +23811,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22265,6 +23838,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22291,6 +23865,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22317,6 +23892,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22345,6 +23921,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22373,6 +23950,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22403,6 +23981,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22432,6 +24011,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id, ::core::primitive::u32),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22447,7 +24027,7 @@ pub mod api {
                 }
                 #[doc = " Past code of parachains. The parachains themselves may not be registered anymore,"]
                 #[doc = " but we also keep their code on-chain for the same amount of time as outdated code"]
-                #[doc = " to keep it available for secondary checkers."]                pub fn past_code_meta (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaPastCodeMeta < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " to keep it available for secondary checkers."]                pub fn past_code_meta (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaPastCodeMeta < :: core :: primitive :: u32 > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
                         "PastCodeMeta",
@@ -22465,7 +24045,7 @@ pub mod api {
                 }
                 #[doc = " Past code of parachains. The parachains themselves may not be registered anymore,"]
                 #[doc = " but we also keep their code on-chain for the same amount of time as outdated code"]
-                #[doc = " to keep it available for secondary checkers."]                pub fn past_code_meta_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaPastCodeMeta < :: core :: primitive :: u32 > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " to keep it available for secondary checkers."]                pub fn past_code_meta_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaPastCodeMeta < :: core :: primitive :: u32 > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
                         "PastCodeMeta",
@@ -22496,6 +24076,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22522,6 +24103,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22548,6 +24130,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22576,6 +24159,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22604,6 +24188,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22638,6 +24223,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22672,6 +24258,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22706,6 +24293,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22740,6 +24328,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22768,6 +24357,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22797,6 +24387,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22823,6 +24414,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22851,6 +24443,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22867,7 +24460,7 @@ pub mod api {
                 #[doc = " Upcoming paras instantiation arguments."]
                 #[doc = ""]
                 #[doc = " NOTE that after PVF pre-checking is enabled the para genesis arg will have it's code set"]
-                #[doc = " to empty. Instead, the code will be saved into the storage right away via `CodeByHash`."]                pub fn upcoming_paras_genesis (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaGenesisArgs > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " to empty. Instead, the code will be saved into the storage right away via `CodeByHash`."]                pub fn upcoming_paras_genesis (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaGenesisArgs > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
                         "UpcomingParasGenesis",
@@ -22886,7 +24479,7 @@ pub mod api {
                 #[doc = " Upcoming paras instantiation arguments."]
                 #[doc = ""]
                 #[doc = " NOTE that after PVF pre-checking is enabled the para genesis arg will have it's code set"]
-                #[doc = " to empty. Instead, the code will be saved into the storage right away via `CodeByHash`."]                pub fn upcoming_paras_genesis_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaGenesisArgs > , () , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " to empty. Instead, the code will be saved into the storage right away via `CodeByHash`."]                pub fn upcoming_paras_genesis_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: paras :: ParaGenesisArgs > , () , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
                         "UpcomingParasGenesis",
@@ -22910,6 +24503,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::ValidationCodeHash,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22934,6 +24528,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::ValidationCodeHash,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22963,6 +24558,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::ValidationCodeHash,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -22992,6 +24588,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::ValidationCodeHash,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Paras",
@@ -23047,6 +24644,10 @@ pub mod api {
             pub struct ForceApprove {
                 pub up_to: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceApprove {
+                const PALLET: &'static str = "Initializer";
+                const CALL: &'static str = "force_approve";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Issue a signal to the consensus engine to forcibly act as though all parachain"]
@@ -23089,6 +24690,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Initializer",
@@ -23108,7 +24710,7 @@ pub mod api {
                 #[doc = " the storage."]
                 #[doc = ""]
                 #[doc = " However this is a `Vec` regardless to handle various edge cases that may occur at runtime"]
-                #[doc = " upgrade boundaries or if governance intervenes."]                pub fn buffered_session_changes (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_runtime_parachains :: initializer :: BufferedSessionChange > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " upgrade boundaries or if governance intervenes."]                pub fn buffered_session_changes (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_runtime_parachains :: initializer :: BufferedSessionChange > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Initializer",
                         "BufferedSessionChanges",
@@ -23139,7 +24741,7 @@ pub mod api {
             use super::runtime_types;
             pub struct StorageApi;
             impl StorageApi {
-                #[doc = " The downward messages addressed for a certain para."]                pub fn downward_message_queues (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_core_primitives :: InboundDownwardMessage < :: core :: primitive :: u32 > > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " The downward messages addressed for a certain para."]                pub fn downward_message_queues (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: Id > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_core_primitives :: InboundDownwardMessage < :: core :: primitive :: u32 > > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Dmp",
                         "DownwardMessageQueues",
@@ -23155,7 +24757,7 @@ pub mod api {
                         ],
                     )
                 }
-                #[doc = " The downward messages addressed for a certain para."]                pub fn downward_message_queues_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_core_primitives :: InboundDownwardMessage < :: core :: primitive :: u32 > > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes >{
+                #[doc = " The downward messages addressed for a certain para."]                pub fn downward_message_queues_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_core_primitives :: InboundDownwardMessage < :: core :: primitive :: u32 > > > , () , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: Id ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Dmp",
                         "DownwardMessageQueues",
@@ -23185,6 +24787,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Dmp",
@@ -23215,6 +24818,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Dmp",
@@ -23248,6 +24852,10 @@ pub mod api {
                 pub index: ::core::primitive::u64,
                 pub weight_limit: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ServiceOverweight {
+                const PALLET: &'static str = "Ump";
+                const CALL: &'static str = "service_overweight";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Service a single overweight upward message."]
@@ -23420,6 +25028,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23451,6 +25060,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23488,6 +25098,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23525,6 +25136,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23554,6 +25166,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23581,6 +25194,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23608,6 +25222,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23637,6 +25252,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23659,6 +25275,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Ump",
@@ -23693,6 +25310,10 @@ pub mod api {
                 pub proposed_max_capacity: ::core::primitive::u32,
                 pub proposed_max_message_size: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for HrmpInitOpenChannel {
+                const PALLET: &'static str = "Hrmp";
+                const CALL: &'static str = "hrmp_init_open_channel";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -23701,6 +25322,10 @@ pub mod api {
             pub struct HrmpAcceptOpenChannel {
                 pub sender: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for HrmpAcceptOpenChannel {
+                const PALLET: &'static str = "Hrmp";
+                const CALL: &'static str = "hrmp_accept_open_channel";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -23710,6 +25335,10 @@ pub mod api {
                 pub channel_id:
                     runtime_types::polkadot_parachain::primitives::HrmpChannelId,
             }
+            impl ::subxt::blocks::StaticExtrinsic for HrmpCloseChannel {
+                const PALLET: &'static str = "Hrmp";
+                const CALL: &'static str = "hrmp_close_channel";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -23720,6 +25349,10 @@ pub mod api {
                 pub inbound: ::core::primitive::u32,
                 pub outbound: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceCleanHrmp {
+                const PALLET: &'static str = "Hrmp";
+                const CALL: &'static str = "force_clean_hrmp";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -23729,6 +25362,10 @@ pub mod api {
             pub struct ForceProcessHrmpOpen {
                 pub channels: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceProcessHrmpOpen {
+                const PALLET: &'static str = "Hrmp";
+                const CALL: &'static str = "force_process_hrmp_open";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: CompactAs,
                 :: subxt :: ext :: codec :: Decode,
@@ -23738,6 +25375,10 @@ pub mod api {
             pub struct ForceProcessHrmpClose {
                 pub channels: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceProcessHrmpClose {
+                const PALLET: &'static str = "Hrmp";
+                const CALL: &'static str = "force_process_hrmp_close";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -23748,6 +25389,10 @@ pub mod api {
                     runtime_types::polkadot_parachain::primitives::HrmpChannelId,
                 pub open_requests: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for HrmpCancelOpenRequest {
+                const PALLET: &'static str = "Hrmp";
+                const CALL: &'static str = "hrmp_cancel_open_request";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Initiate opening a channel from a parachain to a given recipient with given channel"]
@@ -23998,7 +25643,7 @@ pub mod api {
                 #[doc = " The set is accompanied by a list for iteration."]
                 #[doc = ""]
                 #[doc = " Invariant:"]
-                #[doc = " - There are no channels that exists in list but not in the set and vice versa."]                pub fn hrmp_open_channel_requests (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: HrmpChannelId > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: hrmp :: HrmpOpenChannelRequest > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " - There are no channels that exists in list but not in the set and vice versa."]                pub fn hrmp_open_channel_requests (& self , _0 : impl :: std :: borrow :: Borrow < runtime_types :: polkadot_parachain :: primitives :: HrmpChannelId > ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: hrmp :: HrmpOpenChannelRequest > , :: subxt :: storage :: address :: Yes , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: HrmpChannelId ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
                         "HrmpOpenChannelRequests",
@@ -24019,7 +25664,7 @@ pub mod api {
                 #[doc = " The set is accompanied by a list for iteration."]
                 #[doc = ""]
                 #[doc = " Invariant:"]
-                #[doc = " - There are no channels that exists in list but not in the set and vice versa."]                pub fn hrmp_open_channel_requests_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: hrmp :: HrmpOpenChannelRequest > , () , () , :: subxt :: storage :: address :: Yes >{
+                #[doc = " - There are no channels that exists in list but not in the set and vice versa."]                pub fn hrmp_open_channel_requests_root (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < runtime_types :: polkadot_runtime_parachains :: hrmp :: HrmpOpenChannelRequest > , () , () , :: subxt :: storage :: address :: Yes , (runtime_types :: polkadot_parachain :: primitives :: HrmpChannelId ,) >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
                         "HrmpOpenChannelRequests",
@@ -24043,6 +25688,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24069,6 +25715,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24095,6 +25742,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24121,6 +25769,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24147,6 +25796,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24177,6 +25827,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::HrmpChannelId,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24207,6 +25858,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::HrmpChannelId,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24231,6 +25883,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24257,6 +25910,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24283,6 +25937,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24311,6 +25966,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::HrmpChannelId,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24339,6 +25995,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::HrmpChannelId,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24379,6 +26036,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24419,6 +26077,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24446,6 +26105,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24473,6 +26133,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24504,6 +26165,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::HrmpChannelId,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24535,6 +26197,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::HrmpChannelId,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24571,6 +26234,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24607,6 +26271,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Hrmp",
@@ -24632,7 +26297,7 @@ pub mod api {
             impl StorageApi {
                 #[doc = " Assignment keys for the current session."]
                 #[doc = " Note that this API is private due to it being prone to 'off-by-one' at session boundaries."]
-                #[doc = " When in doubt, use `Sessions` API instead."]                pub fn assignment_keys_unsafe (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_primitives :: v2 :: assignment_app :: Public > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () >{
+                #[doc = " When in doubt, use `Sessions` API instead."]                pub fn assignment_keys_unsafe (& self ,) -> :: subxt :: storage :: address :: StaticStorageAddress :: < :: subxt :: metadata :: DecodeStaticType < :: std :: vec :: Vec < runtime_types :: polkadot_primitives :: v2 :: assignment_app :: Public > > , :: subxt :: storage :: address :: Yes , :: subxt :: storage :: address :: Yes , () , () >{
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaSessionInfo",
                         "AssignmentKeysUnsafe",
@@ -24653,6 +26318,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaSessionInfo",
@@ -24679,6 +26345,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaSessionInfo",
@@ -24707,6 +26374,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaSessionInfo",
@@ -24731,6 +26399,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaSessionInfo",
@@ -24757,6 +26426,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParaSessionInfo",
@@ -24787,6 +26457,10 @@ pub mod api {
                 Debug,
             )]
             pub struct ForceUnfreeze;
+            impl ::subxt::blocks::StaticExtrinsic for ForceUnfreeze {
+                const PALLET: &'static str = "ParasDisputes";
+                const CALL: &'static str = "force_unfreeze";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 pub fn force_unfreeze(
@@ -24883,6 +26557,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasDisputes",
@@ -24912,6 +26587,10 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        ::core::primitive::u32,
+                        runtime_types::polkadot_core_primitives::CandidateHash,
+                    ),
                 > {
                     :: subxt :: storage :: address :: StaticStorageAddress :: new ("ParasDisputes" , "Disputes" , vec ! [:: subxt :: storage :: address :: StorageMapKey :: new (_0 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Twox64Concat) , :: subxt :: storage :: address :: StorageMapKey :: new (_1 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Blake2_128Concat)] , [192u8 , 238u8 , 255u8 , 67u8 , 169u8 , 86u8 , 99u8 , 243u8 , 228u8 , 88u8 , 142u8 , 138u8 , 183u8 , 117u8 , 82u8 , 22u8 , 163u8 , 30u8 , 175u8 , 247u8 , 50u8 , 204u8 , 12u8 , 171u8 , 57u8 , 189u8 , 151u8 , 191u8 , 196u8 , 89u8 , 94u8 , 165u8 ,])
                 }
@@ -24927,6 +26606,10 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        ::core::primitive::u32,
+                        runtime_types::polkadot_core_primitives::CandidateHash,
+                    ),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasDisputes",
@@ -24940,6 +26623,39 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " All ongoing or concluded disputes for the last several sessions."]
+                pub fn disputes_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<
+                        runtime_types::polkadot_primitives::v2::DisputeState<
+                            ::core::primitive::u32,
+                        >,
+                    >,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (
+                        ::core::primitive::u32,
+                        runtime_types::polkadot_core_primitives::CandidateHash,
+                    ),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "ParasDisputes",
+                        "Disputes",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            192u8, 238u8, 255u8, 67u8, 169u8, 86u8, 99u8, 243u8, 228u8,
+                            88u8, 142u8, 138u8, 183u8, 117u8, 82u8, 22u8, 163u8, 30u8,
+                            175u8, 247u8, 50u8, 204u8, 12u8, 171u8, 57u8, 189u8, 151u8,
+                            191u8, 196u8, 89u8, 94u8, 165u8,
+                        ],
+                    )
+                }
                 #[doc = " All included blocks on the chain, as well as the block number in this chain that"]
                 #[doc = " should be reverted back to if the candidate is disputed and determined to be invalid."]
                 pub fn included(
@@ -24953,6 +26669,10 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        ::core::primitive::u32,
+                        runtime_types::polkadot_core_primitives::CandidateHash,
+                    ),
                 > {
                     :: subxt :: storage :: address :: StaticStorageAddress :: new ("ParasDisputes" , "Included" , vec ! [:: subxt :: storage :: address :: StorageMapKey :: new (_0 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Twox64Concat) , :: subxt :: storage :: address :: StorageMapKey :: new (_1 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Blake2_128Concat)] , [129u8 , 50u8 , 76u8 , 60u8 , 82u8 , 106u8 , 248u8 , 164u8 , 152u8 , 80u8 , 58u8 , 185u8 , 211u8 , 225u8 , 122u8 , 100u8 , 234u8 , 241u8 , 123u8 , 205u8 , 4u8 , 8u8 , 193u8 , 116u8 , 167u8 , 158u8 , 252u8 , 223u8 , 204u8 , 226u8 , 74u8 , 195u8 ,])
                 }
@@ -24965,6 +26685,10 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        ::core::primitive::u32,
+                        runtime_types::polkadot_core_primitives::CandidateHash,
+                    ),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasDisputes",
@@ -24978,6 +26702,36 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " All included blocks on the chain, as well as the block number in this chain that"]
+                #[doc = " should be reverted back to if the candidate is disputed and determined to be invalid."]
+                pub fn included_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<::core::primitive::u32>,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (
+                        ::core::primitive::u32,
+                        runtime_types::polkadot_core_primitives::CandidateHash,
+                    ),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "ParasDisputes",
+                        "Included",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            129u8, 50u8, 76u8, 60u8, 82u8, 106u8, 248u8, 164u8, 152u8,
+                            80u8, 58u8, 185u8, 211u8, 225u8, 122u8, 100u8, 234u8, 241u8,
+                            123u8, 205u8, 4u8, 8u8, 193u8, 116u8, 167u8, 158u8, 252u8,
+                            223u8, 204u8, 226u8, 74u8, 195u8,
+                        ],
+                    )
+                }
                 #[doc = " Maps session indices to a vector indicating the number of potentially-spam disputes"]
                 #[doc = " each validator is participating in. Potentially-spam disputes are remote disputes which have"]
                 #[doc = " fewer than `byzantine_threshold + 1` validators."]
@@ -24993,6 +26747,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasDisputes",
@@ -25023,6 +26778,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasDisputes",
@@ -25049,6 +26805,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "ParasDisputes",
@@ -25084,6 +26841,10 @@ pub mod api {
                 pub validation_code:
                     runtime_types::polkadot_parachain::primitives::ValidationCode,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Register {
+                const PALLET: &'static str = "Registrar";
+                const CALL: &'static str = "register";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -25097,6 +26858,10 @@ pub mod api {
                 pub validation_code:
                     runtime_types::polkadot_parachain::primitives::ValidationCode,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceRegister {
+                const PALLET: &'static str = "Registrar";
+                const CALL: &'static str = "force_register";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -25105,6 +26870,10 @@ pub mod api {
             pub struct Deregister {
                 pub id: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Deregister {
+                const PALLET: &'static str = "Registrar";
+                const CALL: &'static str = "deregister";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -25114,6 +26883,10 @@ pub mod api {
                 pub id: runtime_types::polkadot_parachain::primitives::Id,
                 pub other: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Swap {
+                const PALLET: &'static str = "Registrar";
+                const CALL: &'static str = "swap";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -25122,12 +26895,20 @@ pub mod api {
             pub struct ForceRemoveLock {
                 pub para: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceRemoveLock {
+                const PALLET: &'static str = "Registrar";
+                const CALL: &'static str = "force_remove_lock";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct Reserve;
+            impl ::subxt::blocks::StaticExtrinsic for Reserve {
+                const PALLET: &'static str = "Registrar";
+                const CALL: &'static str = "reserve";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Register head data and validation code for a reserved Para Id."]
@@ -25355,6 +27136,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Registrar",
@@ -25381,6 +27163,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Registrar",
@@ -25413,6 +27196,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Registrar",
@@ -25445,6 +27229,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Registrar",
@@ -25468,6 +27253,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Registrar",
@@ -25545,6 +27331,10 @@ pub mod api {
                 pub period_begin: ::core::primitive::u32,
                 pub period_count: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceLease {
+                const PALLET: &'static str = "Slots";
+                const CALL: &'static str = "force_lease";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -25553,6 +27343,10 @@ pub mod api {
             pub struct ClearAllLeases {
                 pub para: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ClearAllLeases {
+                const PALLET: &'static str = "Slots";
+                const CALL: &'static str = "clear_all_leases";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -25561,6 +27355,10 @@ pub mod api {
             pub struct TriggerOnboard {
                 pub para: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for TriggerOnboard {
+                const PALLET: &'static str = "Slots";
+                const CALL: &'static str = "trigger_onboard";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Just a connect into the `lease_out` call, in case Root wants to force some lease to happen"]
@@ -25713,6 +27511,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Slots",
@@ -25759,6 +27558,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Slots",
@@ -25834,6 +27634,10 @@ pub mod api {
                 #[codec(compact)]
                 pub lease_period_index: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for NewAuction {
+                const PALLET: &'static str = "Auctions";
+                const CALL: &'static str = "new_auction";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -25851,12 +27655,20 @@ pub mod api {
                 #[codec(compact)]
                 pub amount: ::core::primitive::u128,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Bid {
+                const PALLET: &'static str = "Auctions";
+                const CALL: &'static str = "bid";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
                 Debug,
             )]
             pub struct CancelAuction;
+            impl ::subxt::blocks::StaticExtrinsic for CancelAuction {
+                const PALLET: &'static str = "Auctions";
+                const CALL: &'static str = "cancel_auction";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Create a new auction."]
@@ -26070,6 +27882,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Auctions",
@@ -26098,6 +27911,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Auctions",
@@ -26124,6 +27938,10 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        ::subxt::ext::sp_core::crypto::AccountId32,
+                        runtime_types::polkadot_parachain::primitives::Id,
+                    ),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Auctions",
@@ -26149,6 +27967,10 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (
+                        ::subxt::ext::sp_core::crypto::AccountId32,
+                        runtime_types::polkadot_parachain::primitives::Id,
+                    ),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Auctions",
@@ -26179,6 +28001,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Auctions",
@@ -26211,6 +28034,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Auctions",
@@ -26328,6 +28152,10 @@ pub mod api {
                 pub verifier:
                     ::core::option::Option<runtime_types::sp_runtime::MultiSigner>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Create {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "create";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26341,6 +28169,10 @@ pub mod api {
                 pub signature:
                     ::core::option::Option<runtime_types::sp_runtime::MultiSignature>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Contribute {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "contribute";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26351,6 +28183,10 @@ pub mod api {
                 #[codec(compact)]
                 pub index: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Withdraw {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "withdraw";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26360,6 +28196,10 @@ pub mod api {
                 #[codec(compact)]
                 pub index: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Refund {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "refund";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26369,6 +28209,10 @@ pub mod api {
                 #[codec(compact)]
                 pub index: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Dissolve {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "dissolve";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26388,6 +28232,10 @@ pub mod api {
                 pub verifier:
                     ::core::option::Option<runtime_types::sp_runtime::MultiSigner>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Edit {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "edit";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26397,6 +28245,10 @@ pub mod api {
                 pub index: runtime_types::polkadot_parachain::primitives::Id,
                 pub memo: ::std::vec::Vec<::core::primitive::u8>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for AddMemo {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "add_memo";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26405,6 +28257,10 @@ pub mod api {
             pub struct Poke {
                 pub index: runtime_types::polkadot_parachain::primitives::Id,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Poke {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "poke";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -26416,6 +28272,10 @@ pub mod api {
                 pub signature:
                     ::core::option::Option<runtime_types::sp_runtime::MultiSignature>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ContributeAll {
+                const PALLET: &'static str = "Crowdloan";
+                const CALL: &'static str = "contribute_all";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 #[doc = "Create a new crowdloaning campaign for a parachain slot with the given lease period range."]
@@ -26811,6 +28671,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Crowdloan",
@@ -26842,6 +28703,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (runtime_types::polkadot_parachain::primitives::Id,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Crowdloan",
@@ -26868,6 +28730,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Crowdloan",
@@ -26889,6 +28752,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Crowdloan",
@@ -26910,6 +28774,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "Crowdloan",
@@ -27003,6 +28868,10 @@ pub mod api {
                 pub dest: ::std::boxed::Box<runtime_types::xcm::VersionedMultiLocation>,
                 pub message: ::std::boxed::Box<runtime_types::xcm::VersionedXcm>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Send {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "send";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27015,6 +28884,10 @@ pub mod api {
                 pub assets: ::std::boxed::Box<runtime_types::xcm::VersionedMultiAssets>,
                 pub fee_asset_item: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for TeleportAssets {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "teleport_assets";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27027,6 +28900,10 @@ pub mod api {
                 pub assets: ::std::boxed::Box<runtime_types::xcm::VersionedMultiAssets>,
                 pub fee_asset_item: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ReserveTransferAssets {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "reserve_transfer_assets";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27036,6 +28913,10 @@ pub mod api {
                 pub message: ::std::boxed::Box<runtime_types::xcm::VersionedXcm>,
                 pub max_weight: ::core::primitive::u64,
             }
+            impl ::subxt::blocks::StaticExtrinsic for Execute {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "execute";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27047,6 +28928,10 @@ pub mod api {
                 >,
                 pub xcm_version: ::core::primitive::u32,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceXcmVersion {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "force_xcm_version";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27055,6 +28940,10 @@ pub mod api {
             pub struct ForceDefaultXcmVersion {
                 pub maybe_xcm_version: ::core::option::Option<::core::primitive::u32>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceDefaultXcmVersion {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "force_default_xcm_version";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27064,6 +28953,10 @@ pub mod api {
                 pub location:
                     ::std::boxed::Box<runtime_types::xcm::VersionedMultiLocation>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceSubscribeVersionNotify {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "force_subscribe_version_notify";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27073,6 +28966,10 @@ pub mod api {
                 pub location:
                     ::std::boxed::Box<runtime_types::xcm::VersionedMultiLocation>,
             }
+            impl ::subxt::blocks::StaticExtrinsic for ForceUnsubscribeVersionNotify {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "force_unsubscribe_version_notify";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27086,6 +28983,10 @@ pub mod api {
                 pub fee_asset_item: ::core::primitive::u32,
                 pub weight_limit: runtime_types::xcm::v2::WeightLimit,
             }
+            impl ::subxt::blocks::StaticExtrinsic for LimitedReserveTransferAssets {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "limited_reserve_transfer_assets";
+            }
             #[derive(
                 :: subxt :: ext :: codec :: Decode,
                 :: subxt :: ext :: codec :: Encode,
@@ -27099,6 +29000,10 @@ pub mod api {
                 pub fee_asset_item: ::core::primitive::u32,
                 pub weight_limit: runtime_types::xcm::v2::WeightLimit,
             }
+            impl ::subxt::blocks::StaticExtrinsic for LimitedTeleportAssets {
+                const PALLET: &'static str = "XcmPallet";
+                const CALL: &'static str = "limited_teleport_assets";
+            }
             pub struct TransactionApi;
             impl TransactionApi {
                 pub fn send(
@@ -27717,6 +29622,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27743,6 +29649,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27771,6 +29678,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u64,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27796,6 +29704,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27823,6 +29732,7 @@ pub mod api {
                     (),
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
+                    (::subxt::ext::sp_core::H256,),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27845,6 +29755,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27868,6 +29779,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
                 > {
                     :: subxt :: storage :: address :: StaticStorageAddress :: new ("XcmPallet" , "SupportedVersion" , vec ! [:: subxt :: storage :: address :: StorageMapKey :: new (_0 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Twox64Concat) , :: subxt :: storage :: address :: StorageMapKey :: new (_1 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Blake2_128Concat)] , [227u8 , 149u8 , 251u8 , 204u8 , 40u8 , 150u8 , 151u8 , 177u8 , 154u8 , 187u8 , 9u8 , 205u8 , 174u8 , 137u8 , 228u8 , 128u8 , 18u8 , 244u8 , 151u8 , 120u8 , 6u8 , 44u8 , 5u8 , 167u8 , 56u8 , 35u8 , 192u8 , 141u8 , 108u8 , 169u8 , 91u8 , 7u8 ,])
                 }
@@ -27879,6 +29791,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27892,6 +29805,32 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " The Latest versions that we know various locations support."]
+                pub fn supported_version_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<::core::primitive::u32>,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "XcmPallet",
+                        "SupportedVersion",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            227u8, 149u8, 251u8, 204u8, 40u8, 150u8, 151u8, 177u8, 154u8,
+                            187u8, 9u8, 205u8, 174u8, 137u8, 228u8, 128u8, 18u8, 244u8,
+                            151u8, 120u8, 6u8, 44u8, 5u8, 167u8, 56u8, 35u8, 192u8,
+                            141u8, 108u8, 169u8, 91u8, 7u8,
+                        ],
+                    )
+                }
                 #[doc = " All locations that we have requested version notifications from."]
                 pub fn version_notifiers(
                     &self,
@@ -27902,6 +29841,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
                 > {
                     :: subxt :: storage :: address :: StaticStorageAddress :: new ("XcmPallet" , "VersionNotifiers" , vec ! [:: subxt :: storage :: address :: StorageMapKey :: new (_0 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Twox64Concat) , :: subxt :: storage :: address :: StorageMapKey :: new (_1 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Blake2_128Concat)] , [122u8 , 110u8 , 119u8 , 25u8 , 216u8 , 237u8 , 44u8 , 91u8 , 133u8 , 165u8 , 77u8 , 86u8 , 232u8 , 69u8 , 110u8 , 121u8 , 234u8 , 176u8 , 208u8 , 62u8 , 47u8 , 196u8 , 151u8 , 193u8 , 197u8 , 41u8 , 203u8 , 36u8 , 147u8 , 218u8 , 31u8 , 199u8 ,])
                 }
@@ -27913,6 +29853,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27926,6 +29867,32 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " All locations that we have requested version notifications from."]
+                pub fn version_notifiers_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<::core::primitive::u64>,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "XcmPallet",
+                        "VersionNotifiers",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            122u8, 110u8, 119u8, 25u8, 216u8, 237u8, 44u8, 91u8, 133u8,
+                            165u8, 77u8, 86u8, 232u8, 69u8, 110u8, 121u8, 234u8, 176u8,
+                            208u8, 62u8, 47u8, 196u8, 151u8, 193u8, 197u8, 41u8, 203u8,
+                            36u8, 147u8, 218u8, 31u8, 199u8,
+                        ],
+                    )
+                }
                 #[doc = " The target locations that are subscribed to our version changes, as well as the most recent"]
                 #[doc = " of our versions we informed them of."]
                 pub fn version_notify_targets(
@@ -27941,6 +29908,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
                 > {
                     :: subxt :: storage :: address :: StaticStorageAddress :: new ("XcmPallet" , "VersionNotifyTargets" , vec ! [:: subxt :: storage :: address :: StorageMapKey :: new (_0 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Twox64Concat) , :: subxt :: storage :: address :: StorageMapKey :: new (_1 . borrow () , :: subxt :: storage :: address :: StorageHasher :: Blake2_128Concat)] , [255u8 , 223u8 , 137u8 , 192u8 , 243u8 , 162u8 , 26u8 , 237u8 , 4u8 , 29u8 , 179u8 , 75u8 , 5u8 , 145u8 , 11u8 , 149u8 , 164u8 , 202u8 , 14u8 , 18u8 , 244u8 , 36u8 , 209u8 , 1u8 , 21u8 , 0u8 , 191u8 , 79u8 , 126u8 , 160u8 , 149u8 , 58u8 ,])
                 }
@@ -27957,6 +29925,7 @@ pub mod api {
                     (),
                     (),
                     ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -27970,6 +29939,37 @@ pub mod api {
                         ],
                     )
                 }
+                #[doc = " The target locations that are subscribed to our version changes, as well as the most recent"]
+                #[doc = " of our versions we informed them of."]
+                pub fn version_notify_targets_iter1(
+                    &self,
+                    _0: impl ::std::borrow::Borrow<::core::primitive::u32>,
+                ) -> ::subxt::storage::address::StaticStorageAddress<
+                    ::subxt::metadata::DecodeStaticType<(
+                        ::core::primitive::u64,
+                        ::core::primitive::u64,
+                        ::core::primitive::u32,
+                    )>,
+                    (),
+                    (),
+                    ::subxt::storage::address::Yes,
+                    (::core::primitive::u32, runtime_types::xcm::VersionedMultiLocation),
+                > {
+                    ::subxt::storage::address::StaticStorageAddress::new(
+                        "XcmPallet",
+                        "VersionNotifyTargets",
+                        vec![::subxt::storage::address::StorageMapKey::new(
+                            _0.borrow(),
+                            ::subxt::storage::address::StorageHasher::Twox64Concat,
+                        )],
+                        [
+                            255u8, 223u8, 137u8, 192u8, 243u8, 162u8, 26u8, 237u8, 4u8,
+                            29u8, 179u8, 75u8, 5u8, 145u8, 11u8, 149u8, 164u8, 202u8,
+                            14u8, 18u8, 244u8, 36u8, 209u8, 1u8, 21u8, 0u8, 191u8, 79u8,
+                            126u8, 160u8, 149u8, 58u8,
+                        ],
+                    )
+                }
                 #[doc = " Destinations whose latest XCM version we would like to know. Duplicates not allowed, and"]
                 #[doc = " the `u32` counter is the number of times that a send to the destination has been attempted,"]
                 #[doc = " which is used as a prioritization."]
@@ -27985,6 +29985,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     ::subxt::storage::address::Yes,
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
@@ -28008,6 +30009,7 @@ pub mod api {
                     ::subxt::storage::address::Yes,
                     (),
                     (),
+                    (),
                 > {
                     ::subxt::storage::address::StaticStorageAddress::new(
                         "XcmPallet",
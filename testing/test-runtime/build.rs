@@ -12,12 +12,14 @@ use std::{
     },
     path::Path,
     process::Command,
+    sync::Arc,
     thread,
     time,
 };
 use subxt::rpc::{
     self,
-    ClientT,
+    rpc_params,
+    RpcClient,
 };
 
 static SUBSTRATE_BIN_ENV_VAR: &str = "SUBSTRATE_NODE_PATH";
@@ -63,8 +65,12 @@ async fn run() {
             // It might take a while for substrate node that spin up the RPC server.
             // Thus, the connection might get rejected a few times.
             let res = match rpc::ws_client(&format!("ws://localhost:{}", port)).await {
-                Ok(c) => c.request("state_getMetadata", None).await,
-                Err(e) => Err(e),
+                Ok(c) => {
+                    RpcClient::new(Arc::new(c))
+                        .request("state_getMetadata", rpc_params![])
+                        .await
+                }
+                Err(e) => Err(e.into()),
             };
 
             match res {